@@ -0,0 +1,73 @@
+#![allow(non_snake_case)]
+
+mod utilities;
+use utilities::*;
+
+fn main() {
+    println!("*** Comparing date and time parsing for AWS CloudFront logs ***\n");
+    println!("Parses the date and time fields of each line,");
+    println!(
+        "once via the format strings of the date/time crates and once via the fixed-width parser."
+    );
+    divan::main();
+}
+
+#[divan::bench(name = "00 chrono format strings", args = ARGS_NO_COMMENTS)]
+fn chrono_format_strings(inputs: Inputs) -> usize {
+    fn parse(line: &str) -> Option<NaiveDateTime> {
+        let view = LoglineView::try_from(line).ok()?;
+        let date = NaiveDate::parse_from_str(view.date(), CHRONO_DATE_FMT).ok()?;
+        let time = NaiveTime::parse_from_str(view.time(), CHRONO_TIME_FMT).ok()?;
+        Some(NaiveDateTime::new(date, time))
+    }
+
+    inputs
+        .data()
+        .iter()
+        .filter_map(|line| parse(divan::black_box(*line)))
+        .count()
+}
+
+#[divan::bench(name = "01 chrono fixed-width", args = ARGS_NO_COMMENTS)]
+fn chrono_fixed_width(inputs: Inputs) -> usize {
+    fn parse(line: &str) -> Option<NaiveDateTime> {
+        let view = LoglineView::try_from(line).ok()?;
+        view.datetime().ok()
+    }
+
+    inputs
+        .data()
+        .iter()
+        .filter_map(|line| parse(divan::black_box(*line)))
+        .count()
+}
+
+#[divan::bench(name = "02 time format descriptions", args = ARGS_NO_COMMENTS)]
+fn time_format_descriptions(inputs: Inputs) -> usize {
+    fn parse(line: &str) -> Option<OffsetDateTime> {
+        let view = LoglineView::try_from(line).ok()?;
+        let date = Date::parse(view.date(), TIME_DATE_FMT).ok()?;
+        let time = Time::parse(view.time(), TIME_TIME_FMT).ok()?;
+        Some(OffsetDateTime::new_utc(date, time))
+    }
+
+    inputs
+        .data()
+        .iter()
+        .filter_map(|line| parse(divan::black_box(*line)))
+        .count()
+}
+
+#[divan::bench(name = "03 time fixed-width", args = ARGS_NO_COMMENTS)]
+fn time_fixed_width(inputs: Inputs) -> usize {
+    fn parse(line: &str) -> Option<OffsetDateTime> {
+        let view = LoglineView::try_from(line).ok()?;
+        view.offset_datetime().ok()
+    }
+
+    inputs
+        .data()
+        .iter()
+        .filter_map(|line| parse(divan::black_box(*line)))
+        .count()
+}
@@ -0,0 +1,77 @@
+#![allow(non_snake_case)]
+
+mod utilities;
+use utilities::*;
+
+#[global_allocator]
+static ALLOC: divan::AllocProfiler = divan::AllocProfiler::system();
+
+fn main() {
+    println!("*** Comparing allocations of owned parsers for AWS CloudFront logs ***\n");
+    println!("Parses all lines into owned loglines,");
+    println!("once into a new struct per line and once into a single reused struct.");
+    divan::main();
+}
+
+#[divan::bench(name = "00 OwnedValidatedSimpleLogline (TryFrom)", args = ARGS_NO_COMMENTS)]
+fn simple_try_from(inputs: Inputs) -> usize {
+    inputs
+        .data()
+        .iter()
+        .filter_map(|line| owned::ValidatedSimpleLogline::try_from(divan::black_box(*line)).ok())
+        .count()
+}
+
+#[divan::bench(name = "01 OwnedValidatedSimpleLogline (parse_into)", args = ARGS_NO_COMMENTS)]
+fn simple_parse_into(bencher: divan::Bencher<'_, '_>, inputs: Inputs) {
+    let mut target = owned::ValidatedSimpleLogline::try_from(LOG_LINE_A).unwrap();
+    bencher.bench_local(|| {
+        inputs
+            .data()
+            .iter()
+            .filter(|line| target.parse_into(divan::black_box(line)).is_ok())
+            .count()
+    });
+}
+
+#[divan::bench(name = "02 OwnedValidatedChronoLogline (TryFrom)", args = ARGS_NO_COMMENTS)]
+fn chrono_try_from(inputs: Inputs) -> usize {
+    inputs
+        .data()
+        .iter()
+        .filter_map(|line| OwnedValidatedChronoLogline::try_from(divan::black_box(*line)).ok())
+        .count()
+}
+
+#[divan::bench(name = "03 OwnedValidatedChronoLogline (parse_into)", args = ARGS_NO_COMMENTS)]
+fn chrono_parse_into(bencher: divan::Bencher<'_, '_>, inputs: Inputs) {
+    let mut target = OwnedValidatedChronoLogline::try_from(LOG_LINE_A).unwrap();
+    bencher.bench_local(|| {
+        inputs
+            .data()
+            .iter()
+            .filter(|line| target.parse_into(divan::black_box(line)).is_ok())
+            .count()
+    });
+}
+
+#[divan::bench(name = "04 OwnedValidatedTimeLogline (TryFrom)", args = ARGS_NO_COMMENTS)]
+fn time_try_from(inputs: Inputs) -> usize {
+    inputs
+        .data()
+        .iter()
+        .filter_map(|line| OwnedValidatedTimeLogline::try_from(divan::black_box(*line)).ok())
+        .count()
+}
+
+#[divan::bench(name = "05 OwnedValidatedTimeLogline (parse_into)", args = ARGS_NO_COMMENTS)]
+fn time_parse_into(bencher: divan::Bencher<'_, '_>, inputs: Inputs) {
+    let mut target = OwnedValidatedTimeLogline::try_from(LOG_LINE_A).unwrap();
+    bencher.bench_local(|| {
+        inputs
+            .data()
+            .iter()
+            .filter(|line| target.parse_into(divan::black_box(line)).is_ok())
+            .count()
+    });
+}
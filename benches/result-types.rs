@@ -0,0 +1,81 @@
+#![allow(non_snake_case)]
+
+mod utilities;
+use utilities::*;
+
+fn main() {
+    println!("*** Comparing result type parsing for AWS CloudFront logs ***\n");
+    println!("Parses known and unknown values of the x-edge-detailed-result-type field,");
+    println!("once into the owned and once into the borrowed type.");
+    divan::main();
+}
+
+const KNOWN: [&str; 6] = [
+    "Hit",
+    "Miss",
+    "OriginDnsError",
+    "ClientCommError",
+    "LambdaGeneratedResponse",
+    "OriginContentRangeLengthError",
+];
+
+const UNKNOWN: [&str; 6] = [
+    "Hot",
+    "Missing",
+    "OriginDnsErrors",
+    "ClientCommFailure",
+    "LambdaGeneratedRequest",
+    "OriginContentRangeLengthErrors",
+];
+
+#[derive(Debug, Clone, Copy)]
+pub enum ResultTypes {
+    Known,
+    Unknown,
+}
+
+impl ResultTypes {
+    pub fn data(&self) -> &[&'static str] {
+        match self {
+            ResultTypes::Known => KNOWN.as_slice(),
+            ResultTypes::Unknown => UNKNOWN.as_slice(),
+        }
+    }
+}
+
+#[allow(clippy::to_string_trait_impl)]
+impl ToString for ResultTypes {
+    fn to_string(&self) -> String {
+        match self {
+            ResultTypes::Known => "Known result types",
+            ResultTypes::Unknown => "Unknown result types",
+        }
+        .to_string()
+    }
+}
+
+const RESULT_TYPE_ARGS: [ResultTypes; 2] = [ResultTypes::Known, ResultTypes::Unknown];
+
+#[divan::bench(name = "00 owned (FromStr)", args = RESULT_TYPE_ARGS)]
+fn owned(inputs: ResultTypes) -> usize {
+    inputs
+        .data()
+        .iter()
+        .filter_map(|value| {
+            divan::black_box(*value)
+                .parse::<DetailedEdgeResultType<'_>>()
+                .ok()
+        })
+        .filter(|result_type| matches!(result_type, DetailedEdgeResultType::Other(_)))
+        .count()
+}
+
+#[divan::bench(name = "01 borrowed (From<&str>)", args = RESULT_TYPE_ARGS)]
+fn borrowed(inputs: ResultTypes) -> usize {
+    inputs
+        .data()
+        .iter()
+        .map(|value| DetailedEdgeResultType::from(divan::black_box(*value)))
+        .filter(|result_type| matches!(result_type, DetailedEdgeResultType::Other(_)))
+        .count()
+}
@@ -0,0 +1,38 @@
+//! Feeds arbitrary bytes into the codec decoders; none of them must panic
+//!
+//! Run with `cargo +nightly fuzz run decode` from the repository root.
+
+#![no_main]
+
+use cloudfront_logs::{
+    codec::{Decode, Decoder, MAGIC, VERSION},
+    owned,
+};
+use libfuzzer_sys::fuzz_target;
+
+// decodes all records of the stream, both with the bytes as the whole stream and as the records after a valid header
+fn decode_all<T: Decode>(bytes: &[u8]) {
+    if let Ok(decoder) = Decoder::<_, T>::new(bytes) {
+        let _ = decoder.count();
+    }
+
+    let mut stream = MAGIC.to_vec();
+    stream.push(VERSION);
+    stream.extend_from_slice(bytes);
+    if let Ok(decoder) = Decoder::<_, T>::new(stream.as_slice()) {
+        let _ = decoder.count();
+    }
+
+    // a single record, without the length prefix
+    let mut input = bytes;
+    let _ = T::decode(&mut input);
+}
+
+fuzz_target!(|bytes: &[u8]| {
+    decode_all::<owned::ValidatedSimpleLogline>(bytes);
+    decode_all::<owned::UnvalidatedSimpleLogline>(bytes);
+    decode_all::<owned::ValidatedChronoLogline>(bytes);
+    decode_all::<owned::UnvalidatedChronoLogline>(bytes);
+    decode_all::<owned::ValidatedTimeLogline>(bytes);
+    decode_all::<owned::UnvalidatedTimeLogline>(bytes);
+});
@@ -0,0 +1,152 @@
+//! Feeds arbitrary bytes into all parsers; none of them must panic
+//!
+//! Run with `cargo +nightly fuzz run parse_line` from the repository root.
+
+#![no_main]
+
+use cloudfront_logs::{
+    borrowed, bytes_backed, chunked,
+    columns::LogColumns,
+    de, owned,
+    reader::{LogReader, QualityReport},
+    referential,
+    types::FieldMask,
+};
+use libfuzzer_sys::fuzz_target;
+use std::collections::HashMap;
+
+// a valid line, as the starting point for the in-place parsers
+const TEMPLATE: &str = "2019-12-04\t21:02:31\tLAX1\t392\t192.0.2.100\tGET\td111111abcdef8.cloudfront.net\t/index.html\t200\t-\tMozilla/5.0\t-\t-\tHit\tSOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==\td111111abcdef8.cloudfront.net\thttps\t23\t0.001\t1.2.3.4\tTLSv1.2\tECDHE-RSA-AES128-GCM-SHA256\tHit\tHTTP/2.0\t-\t-\t11040\t0.001\tHit\ttext/html\t78\t-\t-";
+
+fuzz_target!(|bytes: &[u8]| {
+    let _ = borrowed::ValidatedRawBytesLogline::try_from(bytes);
+    let _ = borrowed::UnvalidatedRawBytesLogline::from(bytes);
+    let _ = borrowed::bytes::ValidatedChronoBytesLogline::try_from(bytes);
+    let _ = borrowed::bytes::UnvalidatedChronoBytesLogline::try_from(bytes);
+    let _ = borrowed::bytes::ValidatedTimeBytesLogline::try_from(bytes);
+    let _ = borrowed::bytes::UnvalidatedTimeBytesLogline::try_from(bytes);
+
+    // the bytes as a whole log file, with header lines and invalid UTF-8
+    for _ in LogReader::<_>::new(bytes) {}
+    for _ in LogReader::<_, referential::UnvalidatedSimpleLogline>::new(bytes) {}
+    for _ in LogReader::<_, referential::ValidatedChronoLogline>::new(bytes) {}
+    let _ = QualityReport::from_reader(bytes);
+    let mut report = QualityReport::new();
+    for line in bytes.split(|byte| *byte == b'\n') {
+        report.add_bytes(line);
+    }
+
+    let buffer = bytes::Bytes::copy_from_slice(bytes);
+    let _ = bytes_backed::parse_buffer::<bytes_backed::ValidatedRawLogline>(buffer.clone()).count();
+    let _ = bytes_backed::parse_buffer::<bytes_backed::UnvalidatedSimpleLogline>(buffer.clone())
+        .count();
+    let _ =
+        bytes_backed::parse_buffer::<bytes_backed::ValidatedChronoLogline>(buffer.clone()).count();
+    let _ =
+        bytes_backed::parse_buffer::<bytes_backed::ValidatedTimeLogline>(buffer.clone()).count();
+    let _ =
+        bytes_backed::parse_buffer::<bytes_backed::ValidatedJiffLogline>(buffer.clone()).count();
+    let _ = bytes_backed::parse_buffer::<bytes_backed::ValidatedParquetLogline>(buffer).count();
+
+    let Ok(line) = std::str::from_utf8(bytes) else {
+        return;
+    };
+
+    let _ = borrowed::ValidatedRawLogline::try_from(line);
+    let _ = borrowed::UnvalidatedRawLogline::from(line);
+    let _ = borrowed::ValidatedSimpleLogline::try_from(line);
+    let _ = borrowed::UnvalidatedSimpleLogline::try_from(line);
+    let _ = borrowed::ValidatedChronoLogline::try_from(line);
+    let _ = borrowed::UnvalidatedChronoLogline::try_from(line);
+    let _ = borrowed::ValidatedTimeLogline::try_from(line);
+    let _ = borrowed::UnvalidatedTimeLogline::try_from(line);
+    let _ = borrowed::ValidatedJiffLogline::try_from(line);
+    let _ = borrowed::UnvalidatedJiffLogline::try_from(line);
+    let _ = borrowed::ValidatedParquetLogline::try_from(line);
+    let _ = borrowed::UnvalidatedParquetLogline::try_from(line);
+    let _ = borrowed::ValidatedProjectedLogline::parse(line, FieldMask::ALL);
+    let _ = borrowed::UnvalidatedProjectedLogline::parse(line, FieldMask::ALL);
+    if let Ok(view) = borrowed::LoglineView::try_from(line) {
+        let _ = view.time_taken_duration();
+    }
+
+    let _ = owned::ValidatedSimpleLogline::try_from(line);
+    let _ = owned::UnvalidatedSimpleLogline::try_from(line);
+    let _ = owned::ValidatedParquetLogline::try_from(line);
+    let _ = owned::UnvalidatedParquetLogline::try_from(line);
+    let _ = owned::ValidatedParquetMillisLogline::try_from(line);
+    let _ = owned::UnvalidatedParquetMillisLogline::try_from(line);
+    let _ = borrowed::ValidatedParquetMillisLogline::try_from(line);
+    let _ = borrowed::UnvalidatedParquetMillisLogline::try_from(line);
+
+    if let Ok(simple) = owned::UnvalidatedSimpleLogline::try_from(line) {
+        let _ = owned::UnvalidatedChronoLogline::try_from(simple.clone());
+        let _ = owned::UnvalidatedTimeLogline::try_from(simple);
+    }
+    if let Ok(simple) = owned::ValidatedSimpleLogline::try_from(line) {
+        let _ = owned::ValidatedChronoLogline::try_from(simple.clone());
+        let _ = owned::ValidatedTimeLogline::try_from(simple);
+    }
+
+    let _ = de::from_str::<HashMap<&str, &str>>(line);
+    let _ = de::from_str_unvalidated::<HashMap<&str, &str>>(line);
+
+    let _ = owned::ValidatedSimpleLogline::parse_lenient(line);
+    let _ = owned::UnvalidatedSimpleLogline::parse_lenient(line);
+    let _ = owned::ValidatedChronoLogline::parse_lenient(line);
+    let _ = owned::UnvalidatedChronoLogline::parse_lenient(line);
+    let _ = owned::ValidatedTimeLogline::parse_lenient(line);
+    let _ = owned::UnvalidatedTimeLogline::parse_lenient(line);
+    let _ = borrowed::ValidatedSimpleLogline::parse_lenient(line);
+    let _ = borrowed::UnvalidatedSimpleLogline::parse_lenient(line);
+    let _ = borrowed::ValidatedChronoLogline::parse_lenient(line);
+    let _ = borrowed::UnvalidatedChronoLogline::parse_lenient(line);
+    let _ = borrowed::ValidatedTimeLogline::parse_lenient(line);
+    let _ = borrowed::UnvalidatedTimeLogline::parse_lenient(line);
+    let _ = borrowed::ValidatedJiffLogline::parse_lenient(line);
+    let _ = borrowed::UnvalidatedJiffLogline::parse_lenient(line);
+
+    // twice, so the second call reuses the buffers of the first one
+    if let (Ok(mut simple), Ok(mut chrono), Ok(mut time)) = (
+        owned::UnvalidatedSimpleLogline::try_from(TEMPLATE),
+        owned::UnvalidatedChronoLogline::try_from(TEMPLATE),
+        owned::UnvalidatedTimeLogline::try_from(TEMPLATE),
+    ) {
+        let mut raw = owned::UnvalidatedRawLogline::from(TEMPLATE);
+        for _ in 0..2 {
+            raw.parse_into(line);
+            let _ = simple.parse_into(line);
+            let _ = chrono.parse_into(line);
+            let _ = time.parse_into(line);
+        }
+    }
+
+    let mut interner = owned::Interner::new();
+    for _ in 0..2 {
+        let _ = owned::ValidatedInternedLogline::parse(line, &mut interner);
+        let _ = owned::UnvalidatedInternedLogline::parse(line, &mut interner);
+    }
+
+    let mut columns = LogColumns::new();
+    let _ = columns.push_line(line);
+    let _ = columns.push_line(TEMPLATE);
+    let _ = columns.push_line(line);
+
+    let _ = chunked::parse_chunk::<chunked::ValidatedRawLogline>(line).count();
+    let _ = chunked::parse_chunk::<chunked::UnvalidatedSimpleLogline>(line).count();
+    let _ = chunked::parse_chunk::<chunked::ValidatedChronoLogline>(line).count();
+    let _ = chunked::parse_chunk::<chunked::ValidatedTimeLogline>(line).count();
+    let _ = chunked::parse_chunk::<chunked::ValidatedJiffLogline>(line).count();
+    let _ = chunked::parse_chunk::<chunked::ValidatedParquetLogline>(line).count();
+
+    let _ = referential::ValidatedRawLogline::try_from(line);
+    let _ = referential::UnvalidatedRawLogline::try_from(line);
+    let _ = referential::ValidatedSimpleLogline::try_from(line);
+    let _ = referential::UnvalidatedSimpleLogline::try_from(line);
+    let _ = referential::ValidatedChronoLogline::try_from(line);
+    let _ = referential::UnvalidatedChronoLogline::try_from(line);
+    let _ = referential::ValidatedTimeLogline::try_from(line);
+    let _ = referential::UnvalidatedTimeLogline::try_from(line);
+    let _ = referential::ValidatedParquetLogline::try_from(line);
+    let _ = referential::UnvalidatedParquetLogline::try_from(line);
+});
@@ -0,0 +1,127 @@
+//! Logline types which borrow their data from a byte slice ([`&[u8]`](slice))
+//!
+//! The [`&str`] based parsers require the whole line to be valid UTF-8 upfront,
+//! so callers usually run [`std::str::from_utf8`] over every line first.
+//! Almost all fields of a CloudFront log line are plain ASCII though,
+//! and only a few free-text fields (URI stem, referer, user agent, query and cookie) can contain arbitrary data.
+//!
+//! The variants in this module skip the upfront validation:
+//! * the structured fields are checked (and parsed) on construction, which is cheap since they are short,
+//! * the free-text fields are kept as [`LazyStr`], which is only checked on access.
+//!
+//! For corrupted lines there is an explicit lossy mode,
+//! see [`LazyStr::to_str_lossy`] and [`raw::Logline::get_lossy`],
+//! which replaces invalid sequences with `U+FFFD REPLACEMENT CHARACTER`.
+
+pub mod raw;
+pub mod typed;
+
+pub use raw::{
+    UnvalidatedLogline as UnvalidatedRawBytesLogline, ValidatedLogline as ValidatedRawBytesLogline,
+};
+
+#[cfg(feature = "chrono")]
+pub use typed::{UnvalidatedChronoBytesLogline, ValidatedChronoBytesLogline};
+
+#[cfg(feature = "time")]
+pub use typed::{UnvalidatedTimeBytesLogline, ValidatedTimeBytesLogline};
+
+use core::{
+    fmt::{self, Write},
+    str::Utf8Error,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+
+/// A free-text field which has not been checked for valid UTF-8 yet
+///
+/// # Examples
+///
+/// ```rust
+/// use cloudfront_logs::borrowed::bytes::LazyStr;
+///
+/// let field = LazyStr::new(b"/index.html");
+/// assert_eq!(field.to_str(), Ok("/index.html"));
+///
+/// let broken = LazyStr::new(b"/index\xFF.html");
+/// assert!(broken.to_str().is_err());
+/// assert_eq!(broken.to_str_lossy(), "/index\u{FFFD}.html");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LazyStr<'a>(&'a [u8]);
+
+impl<'a> LazyStr<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the unchecked bytes of the field
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Returns the field as [`&str`], if it is valid UTF-8
+    pub fn to_str(&self) -> Result<&'a str, Utf8Error> {
+        core::str::from_utf8(self.0)
+    }
+
+    /// Returns the field as string, replacing invalid UTF-8 sequences
+    ///
+    /// Only allocates if the field is not valid UTF-8.
+    #[cfg(feature = "alloc")]
+    pub fn to_str_lossy(&self) -> Cow<'a, str> {
+        String::from_utf8_lossy(self.0)
+    }
+
+    // returns None if the field is "-"
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub(crate) fn optional(bytes: &'a [u8]) -> Option<Self> {
+        if bytes == b"-" {
+            None
+        } else {
+            Some(Self(bytes))
+        }
+    }
+}
+
+// both impls replace invalid UTF-8 sequences like `to_str_lossy`, but without allocating
+impl fmt::Debug for LazyStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Ok(s) = self.to_str() {
+            return fmt::Debug::fmt(s, f);
+        }
+        f.write_char('"')?;
+        for chunk in self.0.utf8_chunks() {
+            write!(f, "{}", chunk.valid().escape_debug())?;
+            if !chunk.invalid().is_empty() {
+                f.write_char(char::REPLACEMENT_CHARACTER)?;
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+impl fmt::Display for LazyStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for chunk in self.0.utf8_chunks() {
+            f.write_str(chunk.valid())?;
+            if !chunk.invalid().is_empty() {
+                f.write_char(char::REPLACEMENT_CHARACTER)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PartialEq<str> for LazyStr<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other.as_bytes()
+    }
+}
+
+impl PartialEq<&str> for LazyStr<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == other.as_bytes()
+    }
+}
@@ -0,0 +1,291 @@
+use crate::{shared::*, types::*};
+use core::str::Utf8Error;
+
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+
+/// The validated raw log line, borrowed from bytes
+///
+/// All fields are [`&[u8]`](slice) slices into the original log line, none of them is checked for valid UTF-8.
+///
+/// On construction it checks if the line can be parsed, see [`validate_bytes`].
+///
+/// # Examples
+///
+/// Use `.try_from()` or `.try_into()` to construct an instance, since action can fail.
+///
+/// ```rust
+/// use cloudfront_logs::{borrowed::bytes::raw::ValidatedLogline, types::*};
+///
+/// let line = b"2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let item = ValidatedLogline::try_from(&line[..]).unwrap();
+///
+/// assert_eq!(item.date, b"2019-12-04");
+/// assert_eq!(item.get_str(Field::CIp), Ok("192.0.2.100"));
+/// assert_eq!(item.get_lossy(Field::CsUriStem), "/index.html");
+/// ```
+pub type ValidatedLogline<'a> = Logline<'a, Validated>;
+
+/// The unvalidated raw log line, borrowed from bytes
+///
+/// All fields are [`&[u8]`](slice) slices into the original log line, none of them is checked for valid UTF-8.
+///
+/// Unlike [`ValidatedLogline`], this variant does not check if the line can be parsed.
+/// Use this if you already did a check before creating this struct.
+///
+/// # Panics
+///
+/// Construction never panics.
+/// If the input has fewer fields than expected, the missing fields are empty slices.
+///
+/// # Examples
+///
+/// Use `.from()` or `.into()` to construct an instance, since no validation is done.
+///
+/// ```rust
+/// use cloudfront_logs::{borrowed::bytes::raw::UnvalidatedLogline, types::*};
+///
+/// let line = b"2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let item = UnvalidatedLogline::from(&line[..]);
+///
+/// assert_eq!(item.date, b"2019-12-04");
+/// assert_eq!(item.sc_bytes, b"392");
+/// ```
+pub type UnvalidatedLogline<'a> = Logline<'a, Unvalidated>;
+
+/// The generic, raw log line type, borrowed from bytes
+///
+/// Do not use it directly, prefer [`ValidatedLogline`] or [`UnvalidatedLogline`] instead.
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Logline<'a, V> {
+    pub date: &'a [u8],
+    pub time: &'a [u8],
+    pub x_edge_location: &'a [u8],
+    pub sc_bytes: &'a [u8],
+    pub c_ip: &'a [u8],
+    pub cs_method: &'a [u8],
+    pub cs_host: &'a [u8],
+    pub cs_uri_stem: &'a [u8],
+    pub sc_status: &'a [u8],
+    pub cs_referer: &'a [u8],
+    pub cs_user_agent: &'a [u8],
+    pub cs_uri_query: &'a [u8],
+    pub cs_cookie: &'a [u8],
+    pub x_edge_result_type: &'a [u8],
+    pub x_edge_request_id: &'a [u8],
+    pub x_host_header: &'a [u8],
+    pub cs_protocol: &'a [u8],
+    pub cs_bytes: &'a [u8],
+    pub time_taken: &'a [u8],
+    pub x_forwarded_for: &'a [u8],
+    pub ssl_protocol: &'a [u8],
+    pub ssl_cipher: &'a [u8],
+    pub x_edge_response_result_type: &'a [u8],
+    pub cs_protocol_version: &'a [u8],
+    pub fle_status: &'a [u8],
+    pub fle_encrypted_fields: &'a [u8],
+    pub c_port: &'a [u8],
+    pub time_to_first_byte: &'a [u8],
+    pub x_edge_detailed_result_type: &'a [u8],
+    pub sc_content_type: &'a [u8],
+    pub sc_content_len: &'a [u8],
+    pub sc_range_start: &'a [u8],
+    pub sc_range_end: &'a [u8],
+    __marker: PhantomData<V>,
+}
+
+impl<'a, V> Logline<'a, V> {
+    /// Returns the field as bytes
+    pub fn get(&self, field: Field) -> &'a [u8] {
+        match field {
+            Field::Date => self.date,
+            Field::Time => self.time,
+            Field::XEdgeLocation => self.x_edge_location,
+            Field::ScBytes => self.sc_bytes,
+            Field::CIp => self.c_ip,
+            Field::CsMethod => self.cs_method,
+            Field::CsHost => self.cs_host,
+            Field::CsUriStem => self.cs_uri_stem,
+            Field::ScStatus => self.sc_status,
+            Field::CsReferer => self.cs_referer,
+            Field::CsUserAgent => self.cs_user_agent,
+            Field::CsUriQuery => self.cs_uri_query,
+            Field::CsCookie => self.cs_cookie,
+            Field::XEdgeResultType => self.x_edge_result_type,
+            Field::XEdgeRequestId => self.x_edge_request_id,
+            Field::XHostHeader => self.x_host_header,
+            Field::CsProtocol => self.cs_protocol,
+            Field::CsBytes => self.cs_bytes,
+            Field::TimeTaken => self.time_taken,
+            Field::XForwardedFor => self.x_forwarded_for,
+            Field::SslProtocol => self.ssl_protocol,
+            Field::SslCipher => self.ssl_cipher,
+            Field::XEdgeResponseResultType => self.x_edge_response_result_type,
+            Field::CsProtocolVersion => self.cs_protocol_version,
+            Field::FleStatus => self.fle_status,
+            Field::FleEncryptedFields => self.fle_encrypted_fields,
+            Field::CPort => self.c_port,
+            Field::TimeToFirstByte => self.time_to_first_byte,
+            Field::XEdgeDetailedResultType => self.x_edge_detailed_result_type,
+            Field::ScContentType => self.sc_content_type,
+            Field::ScContentLen => self.sc_content_len,
+            Field::ScRangeStart => self.sc_range_start,
+            Field::ScRangeEnd => self.sc_range_end,
+        }
+    }
+
+    /// Returns the field as [`&str`], if it is valid UTF-8
+    pub fn get_str(&self, field: Field) -> Result<&'a str, Utf8Error> {
+        core::str::from_utf8(self.get(field))
+    }
+
+    /// Returns the field as string, replacing invalid UTF-8 sequences (lossy mode)
+    ///
+    /// Only allocates if the field is not valid UTF-8.
+    #[cfg(feature = "alloc")]
+    pub fn get_lossy(&self, field: Field) -> Cow<'a, str> {
+        String::from_utf8_lossy(self.get(field))
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Logline<'a, Validated> {
+    type Error = &'static str;
+
+    fn try_from(line: &'a [u8]) -> Result<Self, Self::Error> {
+        validate_bytes(line)?;
+        let result = new_log_line(line);
+        Ok(result)
+    }
+}
+
+impl<'a> From<&'a [u8]> for Logline<'a, Unvalidated> {
+    fn from(line: &'a [u8]) -> Self {
+        new_log_line(line)
+    }
+}
+
+pub(crate) fn new_log_line<V>(line: &[u8]) -> Logline<'_, V> {
+    let mut iter = MemchrByteSplitter::new(line);
+
+    Logline {
+        date: iter.next().unwrap_or_default(),
+        time: iter.next().unwrap_or_default(),
+        x_edge_location: iter.next().unwrap_or_default(),
+        sc_bytes: iter.next().unwrap_or_default(),
+        c_ip: iter.next().unwrap_or_default(),
+        cs_method: iter.next().unwrap_or_default(),
+        cs_host: iter.next().unwrap_or_default(),
+        cs_uri_stem: iter.next().unwrap_or_default(),
+        sc_status: iter.next().unwrap_or_default(),
+        cs_referer: iter.next().unwrap_or_default(),
+        cs_user_agent: iter.next().unwrap_or_default(),
+        cs_uri_query: iter.next().unwrap_or_default(),
+        cs_cookie: iter.next().unwrap_or_default(),
+        x_edge_result_type: iter.next().unwrap_or_default(),
+        x_edge_request_id: iter.next().unwrap_or_default(),
+        x_host_header: iter.next().unwrap_or_default(),
+        cs_protocol: iter.next().unwrap_or_default(),
+        cs_bytes: iter.next().unwrap_or_default(),
+        time_taken: iter.next().unwrap_or_default(),
+        x_forwarded_for: iter.next().unwrap_or_default(),
+        ssl_protocol: iter.next().unwrap_or_default(),
+        ssl_cipher: iter.next().unwrap_or_default(),
+        x_edge_response_result_type: iter.next().unwrap_or_default(),
+        cs_protocol_version: iter.next().unwrap_or_default(),
+        fle_status: iter.next().unwrap_or_default(),
+        fle_encrypted_fields: iter.next().unwrap_or_default(),
+        c_port: iter.next().unwrap_or_default(),
+        time_to_first_byte: iter.next().unwrap_or_default(),
+        x_edge_detailed_result_type: iter.next().unwrap_or_default(),
+        sc_content_type: iter.next().unwrap_or_default(),
+        sc_content_len: iter.next().unwrap_or_default(),
+        sc_range_start: iter.next().unwrap_or_default(),
+        sc_range_end: iter.next().unwrap_or_default(),
+        __marker: PhantomData,
+    }
+}
+
+impl<'a> From<Logline<'a, Validated>> for Logline<'a, Unvalidated> {
+    fn from(validated: Logline<'a, Validated>) -> Self {
+        Logline {
+            date: validated.date,
+            time: validated.time,
+            x_edge_location: validated.x_edge_location,
+            sc_bytes: validated.sc_bytes,
+            c_ip: validated.c_ip,
+            cs_method: validated.cs_method,
+            cs_host: validated.cs_host,
+            cs_uri_stem: validated.cs_uri_stem,
+            sc_status: validated.sc_status,
+            cs_referer: validated.cs_referer,
+            cs_user_agent: validated.cs_user_agent,
+            cs_uri_query: validated.cs_uri_query,
+            cs_cookie: validated.cs_cookie,
+            x_edge_result_type: validated.x_edge_result_type,
+            x_edge_request_id: validated.x_edge_request_id,
+            x_host_header: validated.x_host_header,
+            cs_protocol: validated.cs_protocol,
+            cs_bytes: validated.cs_bytes,
+            time_taken: validated.time_taken,
+            x_forwarded_for: validated.x_forwarded_for,
+            ssl_protocol: validated.ssl_protocol,
+            ssl_cipher: validated.ssl_cipher,
+            x_edge_response_result_type: validated.x_edge_response_result_type,
+            cs_protocol_version: validated.cs_protocol_version,
+            fle_status: validated.fle_status,
+            fle_encrypted_fields: validated.fle_encrypted_fields,
+            c_port: validated.c_port,
+            time_to_first_byte: validated.time_to_first_byte,
+            x_edge_detailed_result_type: validated.x_edge_detailed_result_type,
+            sc_content_type: validated.sc_content_type,
+            sc_content_len: validated.sc_content_len,
+            sc_range_start: validated.sc_range_start,
+            sc_range_end: validated.sc_range_end,
+            __marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<Logline<'a, Unvalidated>> for Logline<'a, Validated> {
+    fn from(unvalidated: Logline<'a, Unvalidated>) -> Self {
+        Logline {
+            date: unvalidated.date,
+            time: unvalidated.time,
+            x_edge_location: unvalidated.x_edge_location,
+            sc_bytes: unvalidated.sc_bytes,
+            c_ip: unvalidated.c_ip,
+            cs_method: unvalidated.cs_method,
+            cs_host: unvalidated.cs_host,
+            cs_uri_stem: unvalidated.cs_uri_stem,
+            sc_status: unvalidated.sc_status,
+            cs_referer: unvalidated.cs_referer,
+            cs_user_agent: unvalidated.cs_user_agent,
+            cs_uri_query: unvalidated.cs_uri_query,
+            cs_cookie: unvalidated.cs_cookie,
+            x_edge_result_type: unvalidated.x_edge_result_type,
+            x_edge_request_id: unvalidated.x_edge_request_id,
+            x_host_header: unvalidated.x_host_header,
+            cs_protocol: unvalidated.cs_protocol,
+            cs_bytes: unvalidated.cs_bytes,
+            time_taken: unvalidated.time_taken,
+            x_forwarded_for: unvalidated.x_forwarded_for,
+            ssl_protocol: unvalidated.ssl_protocol,
+            ssl_cipher: unvalidated.ssl_cipher,
+            x_edge_response_result_type: unvalidated.x_edge_response_result_type,
+            cs_protocol_version: unvalidated.cs_protocol_version,
+            fle_status: unvalidated.fle_status,
+            fle_encrypted_fields: unvalidated.fle_encrypted_fields,
+            c_port: unvalidated.c_port,
+            time_to_first_byte: unvalidated.time_to_first_byte,
+            x_edge_detailed_result_type: unvalidated.x_edge_detailed_result_type,
+            sc_content_type: unvalidated.sc_content_type,
+            sc_content_len: unvalidated.sc_content_len,
+            sc_range_start: unvalidated.sc_range_start,
+            sc_range_end: unvalidated.sc_range_end,
+            __marker: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use crate::{
+    borrowed::bytes::{
+        LazyStr,
+        raw::{
+            Logline as RawLogline, UnvalidatedLogline as UnvalidatedRaw,
+            ValidatedLogline as ValidatedRaw, new_log_line as new_raw_log_line,
+        },
+    },
+    shared::*,
+    types::*,
+};
+
+pub use crate::types::{Datelike, Timelike};
+
+/// The validated typed log line, borrowed from bytes, using [`chrono`] crate for date and time
+///
+/// Most fields are parsed into more meaningful types.
+/// The free-text fields (URI stem, referer, user agent, query and cookie) are not checked for valid UTF-8,
+/// use the methods of [`LazyStr`] to access them.
+///
+/// On construction it checks if the line can be parsed.
+///
+/// # Examples
+///
+/// Use `.try_from()` or `.try_into()` to construct an instance, since action can fail.
+///
+/// ```rust
+/// use cloudfront_logs::{borrowed::bytes::typed::chrono::ValidatedLogline, types::*};
+///
+/// let line = b"2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let item = ValidatedLogline::try_from(&line[..]).unwrap();
+///
+/// assert_eq!(item.date, NaiveDate::from_ymd_opt(2019, 12, 4).unwrap());
+/// assert_eq!(item.sc_bytes, 392u64);
+/// assert_eq!(item.cs_uri_stem.to_str(), Ok("/index.html"));
+/// assert!(item.cs_user_agent.to_str_lossy().starts_with("Mozilla/5.0"));
+/// ```
+pub type ValidatedLogline<'a> = Logline<'a, Validated>;
+
+/// The unvalidated typed log line, borrowed from bytes, using [`chrono`] crate for date and time
+///
+/// Most fields are parsed into more meaningful types.
+/// The free-text fields (URI stem, referer, user agent, query and cookie) are not checked for valid UTF-8,
+/// use the methods of [`LazyStr`] to access them.
+///
+/// Unlike [`ValidatedLogline`], this variant does not check if the line can be parsed.
+/// Use this if you already did a check before creating this struct.
+///
+/// # Errors
+///
+/// Construction never panics, an invalid log line results in an error instead.
+///
+/// # Examples
+///
+/// Use `.try_from()` or `.try_into()` to construct an instance, since action can fail.
+///
+/// ```rust
+/// use cloudfront_logs::{borrowed::bytes::typed::chrono::UnvalidatedLogline, types::*};
+///
+/// let line = b"2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let item = UnvalidatedLogline::try_from(&line[..]).unwrap();
+///
+/// assert_eq!(item.date, NaiveDate::from_ymd_opt(2019, 12, 4).unwrap());
+/// assert_eq!(item.sc_bytes, 392u64);
+/// assert_eq!(item.cs_protocol, CsProtocol::Https);
+/// ```
+pub type UnvalidatedLogline<'a> = Logline<'a, Unvalidated>;
+
+/// The generic, typed log line type, borrowed from bytes
+///
+/// Do not use it directly, prefer [`ValidatedLogline`] or [`UnvalidatedLogline`] instead.
+#[must_use]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logline<'a, V> {
+    pub date: NaiveDate,
+    pub time: NaiveTime,
+    pub datetime: NaiveDateTime,
+    pub x_edge_location: &'a str,
+    pub sc_bytes: u64,
+    pub c_ip: IpAddr,
+    pub cs_method: &'a str,
+    pub cs_host: &'a str,
+    pub cs_uri_stem: LazyStr<'a>,
+    pub sc_status: u16,
+    pub cs_referer: Option<LazyStr<'a>>,
+    pub cs_user_agent: LazyStr<'a>,
+    pub cs_uri_query: Option<LazyStr<'a>>,
+    pub cs_cookie: Option<LazyStr<'a>>,
+    pub x_edge_result_type: EdgeResultType<'a>,
+    pub x_edge_request_id: &'a str,
+    pub x_host_header: &'a str,
+    pub cs_protocol: CsProtocol,
+    pub cs_bytes: u64,
+    pub time_taken: Duration,
+    pub x_forwarded_for: Option<ForwardedForAddrs>,
+    pub ssl_protocol: Option<SslProtocol>,
+    pub ssl_cipher: Option<&'a str>,
+    pub x_edge_response_result_type: EdgeResultType<'a>,
+    pub cs_protocol_version: CsProtocolVersion,
+    pub fle_status: Option<&'a str>,
+    pub fle_encrypted_fields: Option<u64>,
+    pub c_port: u16,
+    pub time_to_first_byte: Duration,
+    pub x_edge_detailed_result_type: DetailedEdgeResultType<'a>,
+    pub sc_content_type: Option<&'a str>,
+    pub sc_content_len: Option<u64>,
+    pub sc_range_start: Option<i64>,
+    pub sc_range_end: Option<i64>,
+    __marker: PhantomData<V>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for Logline<'a, Validated> {
+    type Error = &'static str;
+
+    fn try_from(line: &'a [u8]) -> Result<Self, Self::Error> {
+        validate_bytes(line)?;
+        try_from_v(new_raw_log_line(line))
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Logline<'a, Unvalidated> {
+    type Error = &'static str;
+
+    fn try_from(line: &'a [u8]) -> Result<Self, Self::Error> {
+        try_from_v(new_raw_log_line(line))
+    }
+}
+
+impl<'a> TryFrom<ValidatedRaw<'a>> for Logline<'a, Validated> {
+    type Error = &'static str;
+
+    fn try_from(raw: ValidatedRaw<'a>) -> Result<Self, Self::Error> {
+        try_from_v(raw)
+    }
+}
+
+impl<'a> TryFrom<UnvalidatedRaw<'a>> for Logline<'a, Unvalidated> {
+    type Error = &'static str;
+
+    fn try_from(raw: UnvalidatedRaw<'a>) -> Result<Self, Self::Error> {
+        try_from_v(raw)
+    }
+}
+
+fn try_from_v<V>(raw: RawLogline<'_, V>) -> Result<Logline<'_, V>, &'static str> {
+    let date = str_from_bytes(raw.date)
+        .ok()
+        .and_then(|s| parse_chrono_date(s).ok())
+        .ok_or("date invalid")?;
+    let time = str_from_bytes(raw.time)
+        .ok()
+        .and_then(|s| parse_chrono_time(s).ok())
+        .ok_or("time invalid")?;
+    let datetime = NaiveDateTime::new(date, time);
+
+    let line = Logline {
+        date,
+        time,
+        datetime,
+        x_edge_location: str_from_bytes(raw.x_edge_location)
+            .map_err(|_e| "x_edge_location invalid")?,
+        sc_bytes: parse_bytes(raw.sc_bytes).map_err(|_e| "sc_bytes invalid")?,
+        c_ip: parse_bytes(raw.c_ip).map_err(|_e| "c_ip invalid")?,
+        cs_method: str_from_bytes(raw.cs_method).map_err(|_e| "cs_method invalid")?,
+        cs_host: str_from_bytes(raw.cs_host).map_err(|_e| "cs_host invalid")?,
+        cs_uri_stem: LazyStr::new(raw.cs_uri_stem),
+        sc_status: parse_bytes(raw.sc_status).map_err(|_e| "sc_status invalid")?,
+        cs_referer: LazyStr::optional(raw.cs_referer),
+        cs_user_agent: LazyStr::new(raw.cs_user_agent),
+        cs_uri_query: LazyStr::optional(raw.cs_uri_query),
+        cs_cookie: LazyStr::optional(raw.cs_cookie),
+        x_edge_result_type: str_from_bytes(raw.x_edge_result_type)
+            .map_err(|_e| "x_edge_result_type invalid")?
+            .into(),
+        x_edge_request_id: str_from_bytes(raw.x_edge_request_id)
+            .map_err(|_e| "x_edge_request_id invalid")?,
+        x_host_header: str_from_bytes(raw.x_host_header).map_err(|_e| "x_host_header invalid")?,
+        cs_protocol: parse_bytes(raw.cs_protocol).map_err(|_e| "cs_protocol invalid")?,
+        cs_bytes: parse_bytes(raw.cs_bytes).map_err(|_e| "cs_bytes invalid")?,
+        time_taken: str_from_bytes(raw.time_taken)
+            .map_err(|_e| ())
+            .and_then(parse_duration)
+            .map_err(|_e| "time_taken invalid")?,
+        x_forwarded_for: parse_bytes_as_option(raw.x_forwarded_for)
+            .map_err(|_e| "x_forwarded_for invalid")?,
+        ssl_protocol: parse_bytes_as_option(raw.ssl_protocol)
+            .map_err(|_e| "ssl_protocol invalid")?,
+        ssl_cipher: str_from_bytes_as_option(raw.ssl_cipher).map_err(|_e| "ssl_cipher invalid")?,
+        x_edge_response_result_type: str_from_bytes(raw.x_edge_response_result_type)
+            .map_err(|_e| "x_edge_response_result_type invalid")?
+            .into(),
+        cs_protocol_version: parse_bytes(raw.cs_protocol_version)
+            .map_err(|_e| "cs_protocol_version invalid")?,
+        fle_status: str_from_bytes_as_option(raw.fle_status).map_err(|_e| "fle_status invalid")?,
+        fle_encrypted_fields: parse_bytes_as_option(raw.fle_encrypted_fields)
+            .map_err(|_e| "fle_encrypted_fields invalid")?,
+        c_port: parse_bytes(raw.c_port).map_err(|_e| "c_port invalid")?,
+        time_to_first_byte: str_from_bytes(raw.time_to_first_byte)
+            .map_err(|_e| ())
+            .and_then(parse_duration)
+            .map_err(|_e| "time_to_first_byte invalid")?,
+        x_edge_detailed_result_type: str_from_bytes(raw.x_edge_detailed_result_type)
+            .map_err(|_e| "x_edge_detailed_result_type invalid")?
+            .into(),
+        sc_content_type: str_from_bytes_as_option(raw.sc_content_type)
+            .map_err(|_e| "sc_content_type invalid")?,
+        sc_content_len: parse_bytes_as_option(raw.sc_content_len)
+            .map_err(|_e| "sc_content_len invalid")?,
+        sc_range_start: parse_bytes_as_option(raw.sc_range_start)
+            .map_err(|_e| "sc_range_start invalid")?,
+        sc_range_end: parse_bytes_as_option(raw.sc_range_end)
+            .map_err(|_e| "sc_range_end invalid")?,
+        __marker: PhantomData,
+    };
+    Ok(line)
+}
+
+impl<'a> From<Logline<'a, Validated>> for Logline<'a, Unvalidated> {
+    fn from(validated: Logline<'a, Validated>) -> Self {
+        Logline {
+            date: validated.date,
+            time: validated.time,
+            datetime: validated.datetime,
+            x_edge_location: validated.x_edge_location,
+            sc_bytes: validated.sc_bytes,
+            c_ip: validated.c_ip,
+            cs_method: validated.cs_method,
+            cs_host: validated.cs_host,
+            cs_uri_stem: validated.cs_uri_stem,
+            sc_status: validated.sc_status,
+            cs_referer: validated.cs_referer,
+            cs_user_agent: validated.cs_user_agent,
+            cs_uri_query: validated.cs_uri_query,
+            cs_cookie: validated.cs_cookie,
+            x_edge_result_type: validated.x_edge_result_type,
+            x_edge_request_id: validated.x_edge_request_id,
+            x_host_header: validated.x_host_header,
+            cs_protocol: validated.cs_protocol,
+            cs_bytes: validated.cs_bytes,
+            time_taken: validated.time_taken,
+            x_forwarded_for: validated.x_forwarded_for,
+            ssl_protocol: validated.ssl_protocol,
+            ssl_cipher: validated.ssl_cipher,
+            x_edge_response_result_type: validated.x_edge_response_result_type,
+            cs_protocol_version: validated.cs_protocol_version,
+            fle_status: validated.fle_status,
+            fle_encrypted_fields: validated.fle_encrypted_fields,
+            c_port: validated.c_port,
+            time_to_first_byte: validated.time_to_first_byte,
+            x_edge_detailed_result_type: validated.x_edge_detailed_result_type,
+            sc_content_type: validated.sc_content_type,
+            sc_content_len: validated.sc_content_len,
+            sc_range_start: validated.sc_range_start,
+            sc_range_end: validated.sc_range_end,
+            __marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<Logline<'a, Unvalidated>> for Logline<'a, Validated> {
+    fn from(unvalidated: Logline<'a, Unvalidated>) -> Self {
+        Logline {
+            date: unvalidated.date,
+            time: unvalidated.time,
+            datetime: unvalidated.datetime,
+            x_edge_location: unvalidated.x_edge_location,
+            sc_bytes: unvalidated.sc_bytes,
+            c_ip: unvalidated.c_ip,
+            cs_method: unvalidated.cs_method,
+            cs_host: unvalidated.cs_host,
+            cs_uri_stem: unvalidated.cs_uri_stem,
+            sc_status: unvalidated.sc_status,
+            cs_referer: unvalidated.cs_referer,
+            cs_user_agent: unvalidated.cs_user_agent,
+            cs_uri_query: unvalidated.cs_uri_query,
+            cs_cookie: unvalidated.cs_cookie,
+            x_edge_result_type: unvalidated.x_edge_result_type,
+            x_edge_request_id: unvalidated.x_edge_request_id,
+            x_host_header: unvalidated.x_host_header,
+            cs_protocol: unvalidated.cs_protocol,
+            cs_bytes: unvalidated.cs_bytes,
+            time_taken: unvalidated.time_taken,
+            x_forwarded_for: unvalidated.x_forwarded_for,
+            ssl_protocol: unvalidated.ssl_protocol,
+            ssl_cipher: unvalidated.ssl_cipher,
+            x_edge_response_result_type: unvalidated.x_edge_response_result_type,
+            cs_protocol_version: unvalidated.cs_protocol_version,
+            fle_status: unvalidated.fle_status,
+            fle_encrypted_fields: unvalidated.fle_encrypted_fields,
+            c_port: unvalidated.c_port,
+            time_to_first_byte: unvalidated.time_to_first_byte,
+            x_edge_detailed_result_type: unvalidated.x_edge_detailed_result_type,
+            sc_content_type: unvalidated.sc_content_type,
+            sc_content_len: unvalidated.sc_content_len,
+            sc_range_start: unvalidated.sc_range_start,
+            sc_range_end: unvalidated.sc_range_end,
+            __marker: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+#[cfg(feature = "chrono")]
+pub mod chrono;
+
+#[cfg(feature = "chrono")]
+pub use chrono::{
+    UnvalidatedLogline as UnvalidatedChronoBytesLogline,
+    ValidatedLogline as ValidatedChronoBytesLogline,
+};
+
+#[cfg(feature = "time")]
+pub mod time;
+
+#[cfg(feature = "time")]
+pub use time::{
+    UnvalidatedLogline as UnvalidatedTimeBytesLogline,
+    ValidatedLogline as ValidatedTimeBytesLogline,
+};
@@ -3,17 +3,29 @@
 //! Therefore they are only suitable for cases where the log date is immediately processed/consumed
 //! and the struct can be discarded quickly afterwards.
 
+pub mod bytes;
+#[cfg(feature = "chrono")]
+pub mod projection;
 pub mod raw;
+#[cfg(feature = "alloc")]
 pub mod simple;
 pub mod typed;
+pub mod view;
 
 #[cfg(feature = "parquet")]
 pub mod parquet;
+#[cfg(feature = "parquet")]
+pub mod parquet_millis;
 
 pub use raw::{
     UnvalidatedLogline as UnvalidatedRawLogline, ValidatedLogline as ValidatedRawLogline,
 };
 
+pub use view::LoglineView;
+
+pub use bytes::{UnvalidatedRawBytesLogline, ValidatedRawBytesLogline};
+
+#[cfg(feature = "alloc")]
 pub use simple::{
     UnvalidatedLogline as UnvalidatedSimpleLogline, ValidatedLogline as ValidatedSimpleLogline,
 };
@@ -23,12 +35,29 @@ pub use typed::chrono::{
     UnvalidatedLogline as UnvalidatedChronoLogline, ValidatedLogline as ValidatedChronoLogline,
 };
 
+#[cfg(feature = "chrono")]
+pub use projection::{
+    UnvalidatedLogline as UnvalidatedProjectedLogline,
+    ValidatedLogline as ValidatedProjectedLogline,
+};
+
 #[cfg(feature = "time")]
 pub use typed::time::{
     UnvalidatedLogline as UnvalidatedTimeLogline, ValidatedLogline as ValidatedTimeLogline,
 };
 
+#[cfg(feature = "jiff")]
+pub use typed::jiff::{
+    UnvalidatedLogline as UnvalidatedJiffLogline, ValidatedLogline as ValidatedJiffLogline,
+};
+
 #[cfg(feature = "parquet")]
 pub use parquet::{
     UnvalidatedLogline as UnvalidatedParquetLogline, ValidatedLogline as ValidatedParquetLogline,
 };
+
+#[cfg(feature = "parquet")]
+pub use parquet_millis::{
+    UnvalidatedLogline as UnvalidatedParquetMillisLogline,
+    ValidatedLogline as ValidatedParquetMillisLogline,
+};
@@ -0,0 +1,25 @@
+use crate::{
+    borrowed::raw::{UnvalidatedLogline as UnvalidatedRaw, ValidatedLogline as ValidatedRaw},
+    shared::*,
+    types::*,
+};
+
+pub use crate::types::{Datelike, Timelike};
+
+crate::shared::impl_parquet_loglines! {
+    family: borrowed<'a>,
+    input: 'a,
+    strings: [&'a str], optional: as_optional_str,
+    module: parquet_millis,
+    schema: V1_MILLIS,
+    durations: u64 as "u64 milliseconds", parsed by parse_millis,
+    fields: time_taken_ms, time_to_first_byte_ms,
+    title: ", with durations in milliseconds",
+    docs: [
+        "",
+        " Same as the [`parquet`](super::parquet) variant, but `time_taken` and `time_to_first_byte`",
+        " are written as exact integer milliseconds (`time_taken_ms`, `time_to_first_byte_ms`)",
+        " instead of float seconds.",
+    ],
+    example: [" assert_eq!(item.time_taken_ms, 1);"],
+}
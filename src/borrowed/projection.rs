@@ -0,0 +1,273 @@
+use crate::{shared::*, types::*};
+
+/// The validated projected log line
+///
+/// Only the fields selected by a [`FieldMask`] are parsed, all other fields are skipped and stay `None`.
+/// The parsed fields use the same types as the [`chrono`](crate::borrowed::typed::chrono) variant.
+/// `datetime` is set if both `date` and `time` are selected.
+///
+/// Note that fields which are optional in the log line (`-`) are also `None`,
+/// use [`mask()`](Logline::mask) to tell apart skipped and empty fields if needed.
+///
+/// On construction it checks if the line can be parsed.
+/// This is useful if you cannot skip the comment lines or have reason to not trust the input for format correctness.
+/// The latter should be only an issue if you do not use this crate on CloudFront logs directly.
+///
+/// # Examples
+///
+/// Use `.parse()` with a field mask to construct an instance.
+///
+/// ```rust
+/// use cloudfront_logs::{borrowed::projection::ValidatedLogline, types::*};
+///
+/// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let mask = FieldMask::from([Field::Date, Field::Time, Field::CsHost, Field::ScStatus, Field::ScBytes]);
+/// let item = ValidatedLogline::parse(line, mask).unwrap();
+///
+/// assert_eq!(item.datetime, NaiveDate::from_ymd_opt(2019, 12, 4).unwrap().and_hms_opt(21, 2, 31));
+/// assert_eq!(item.cs_host, Some("d111111abcdef8.cloudfront.net"));
+/// assert_eq!(item.sc_status, Some(200));
+/// assert_eq!(item.sc_bytes, Some(392));
+/// assert_eq!(item.c_ip, None);
+/// ```
+pub type ValidatedLogline<'a> = Logline<'a, Validated>;
+
+/// The unvalidated projected log line
+///
+/// Only the fields selected by a [`FieldMask`] are parsed, all other fields are skipped and stay `None`.
+/// The parsed fields use the same types as the [`chrono`](crate::borrowed::typed::chrono) variant.
+/// `datetime` is set if both `date` and `time` are selected.
+///
+/// Unlike [`ValidatedLogline`], this variant does not check if the line can be parsed.
+/// Use this if you already did a check before creating this struct.
+/// A common scenario is that you 1) trust the input data and 2) skipped the comment lines.
+///
+/// Since the line is not validated, the splitting stops right after the last selected field,
+/// which makes this variant even faster if only fields at the beginning of the line are needed.
+/// A line which ends before the last selected field is still an error.
+///
+/// # Examples
+///
+/// Use `.parse()` with a field mask to construct an instance.
+///
+/// ```rust
+/// use cloudfront_logs::{borrowed::projection::UnvalidatedLogline, types::*};
+///
+/// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let mask = FieldMask::from([Field::Date, Field::Time, Field::CsHost, Field::ScStatus, Field::ScBytes]);
+/// let item = UnvalidatedLogline::parse(line, mask).unwrap();
+///
+/// assert_eq!(item.datetime, NaiveDate::from_ymd_opt(2019, 12, 4).unwrap().and_hms_opt(21, 2, 31));
+/// assert_eq!(item.cs_host, Some("d111111abcdef8.cloudfront.net"));
+/// assert_eq!(item.sc_status, Some(200));
+/// assert_eq!(item.sc_bytes, Some(392));
+/// assert_eq!(item.c_ip, None);
+/// ```
+pub type UnvalidatedLogline<'a> = Logline<'a, Unvalidated>;
+
+/// The generic, projected log line type
+///
+/// Do not use it directly, prefer [`ValidatedLogline`] or [`UnvalidatedLogline`] instead.
+#[must_use]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logline<'a, V> {
+    pub date: Option<NaiveDate>,
+    pub time: Option<NaiveTime>,
+    pub datetime: Option<NaiveDateTime>,
+    pub x_edge_location: Option<&'a str>,
+    pub sc_bytes: Option<u64>,
+    pub c_ip: Option<IpAddr>,
+    pub cs_method: Option<&'a str>,
+    pub cs_host: Option<&'a str>,
+    pub cs_uri_stem: Option<&'a str>,
+    pub sc_status: Option<u16>,
+    pub cs_referer: Option<&'a str>,
+    pub cs_user_agent: Option<&'a str>,
+    pub cs_uri_query: Option<&'a str>,
+    pub cs_cookie: Option<&'a str>,
+    pub x_edge_result_type: Option<EdgeResultType<'a>>,
+    pub x_edge_request_id: Option<&'a str>,
+    pub x_host_header: Option<&'a str>,
+    pub cs_protocol: Option<CsProtocol>,
+    pub cs_bytes: Option<u64>,
+    pub time_taken: Option<Duration>,
+    pub x_forwarded_for: Option<ForwardedForAddrs>,
+    pub ssl_protocol: Option<SslProtocol>,
+    pub ssl_cipher: Option<&'a str>,
+    pub x_edge_response_result_type: Option<EdgeResultType<'a>>,
+    pub cs_protocol_version: Option<CsProtocolVersion>,
+    pub fle_status: Option<&'a str>,
+    pub fle_encrypted_fields: Option<u64>,
+    pub c_port: Option<u16>,
+    pub time_to_first_byte: Option<Duration>,
+    pub x_edge_detailed_result_type: Option<DetailedEdgeResultType<'a>>,
+    pub sc_content_type: Option<&'a str>,
+    pub sc_content_len: Option<u64>,
+    pub sc_range_start: Option<i64>,
+    pub sc_range_end: Option<i64>,
+    mask: FieldMask,
+    __marker: PhantomData<V>,
+}
+
+impl<'a> Logline<'a, Validated> {
+    pub fn parse(line: &'a str, mask: FieldMask) -> Result<Self, &'static str> {
+        validate_line(line)?;
+        new_log_line(line, mask)
+    }
+}
+
+impl<'a> Logline<'a, Unvalidated> {
+    pub fn parse(line: &'a str, mask: FieldMask) -> Result<Self, &'static str> {
+        new_log_line(line, mask)
+    }
+}
+
+impl<V> Logline<'_, V> {
+    /// Returns the fields which were selected for parsing
+    pub fn mask(&self) -> FieldMask {
+        self.mask
+    }
+}
+
+fn new_log_line<V>(line: &str, mask: FieldMask) -> Result<Logline<'_, V>, &'static str> {
+    let mut result = Logline {
+        date: None,
+        time: None,
+        datetime: None,
+        x_edge_location: None,
+        sc_bytes: None,
+        c_ip: None,
+        cs_method: None,
+        cs_host: None,
+        cs_uri_stem: None,
+        sc_status: None,
+        cs_referer: None,
+        cs_user_agent: None,
+        cs_uri_query: None,
+        cs_cookie: None,
+        x_edge_result_type: None,
+        x_edge_request_id: None,
+        x_host_header: None,
+        cs_protocol: None,
+        cs_bytes: None,
+        time_taken: None,
+        x_forwarded_for: None,
+        ssl_protocol: None,
+        ssl_cipher: None,
+        x_edge_response_result_type: None,
+        cs_protocol_version: None,
+        fle_status: None,
+        fle_encrypted_fields: None,
+        c_port: None,
+        time_to_first_byte: None,
+        x_edge_detailed_result_type: None,
+        sc_content_type: None,
+        sc_content_len: None,
+        sc_range_start: None,
+        sc_range_end: None,
+        mask,
+        __marker: PhantomData,
+    };
+
+    let Some(last) = mask.last() else {
+        return Ok(result);
+    };
+
+    // the fields up to the last selected one have to be present, like for the other parsers
+    let mut iter = MemchrTabSplitter::new(line);
+    for field in Field::ALL {
+        let value = iter.field()?;
+        if mask.contains(field) {
+            result.set(field, value)?;
+        }
+        if field == last {
+            break;
+        }
+    }
+
+    if let (Some(date), Some(time)) = (result.date, result.time) {
+        result.datetime = Some(NaiveDateTime::new(date, time));
+    }
+    Ok(result)
+}
+
+impl<'a, V> Logline<'a, V> {
+    fn set(&mut self, field: Field, value: &'a str) -> Result<(), &'static str> {
+        match field {
+            Field::Date => {
+                self.date = Some(parse_chrono_date(value).map_err(|_e| "date invalid")?);
+            }
+            Field::Time => {
+                self.time = Some(parse_chrono_time(value).map_err(|_e| "time invalid")?);
+            }
+            Field::XEdgeLocation => self.x_edge_location = Some(value),
+            Field::ScBytes => self.sc_bytes = Some(value.parse().map_err(|_e| "sc_bytes invalid")?),
+            Field::CIp => self.c_ip = Some(value.parse().map_err(|_e| "c_ip invalid")?),
+            Field::CsMethod => self.cs_method = Some(value),
+            Field::CsHost => self.cs_host = Some(value),
+            Field::CsUriStem => self.cs_uri_stem = Some(value),
+            Field::ScStatus => {
+                self.sc_status = Some(value.parse().map_err(|_e| "sc_status invalid")?);
+            }
+            Field::CsReferer => self.cs_referer = value.as_optional_str(),
+            Field::CsUserAgent => self.cs_user_agent = Some(value),
+            Field::CsUriQuery => self.cs_uri_query = value.as_optional_str(),
+            Field::CsCookie => self.cs_cookie = value.as_optional_str(),
+            Field::XEdgeResultType => {
+                self.x_edge_result_type = Some(value.into());
+            }
+            Field::XEdgeRequestId => self.x_edge_request_id = Some(value),
+            Field::XHostHeader => self.x_host_header = Some(value),
+            Field::CsProtocol => {
+                self.cs_protocol = Some(value.parse().map_err(|_e| "cs_protocol invalid")?);
+            }
+            Field::CsBytes => self.cs_bytes = Some(value.parse().map_err(|_e| "cs_bytes invalid")?),
+            Field::TimeTaken => {
+                self.time_taken = Some(parse_duration(value).map_err(|_e| "time_taken invalid")?);
+            }
+            Field::XForwardedFor => {
+                self.x_forwarded_for =
+                    parse_as_option(value).map_err(|_e| "x_forwarded_for invalid")?;
+            }
+            Field::SslProtocol => {
+                self.ssl_protocol = parse_as_option(value).map_err(|_e| "ssl_protocol invalid")?;
+            }
+            Field::SslCipher => self.ssl_cipher = value.as_optional_str(),
+            Field::XEdgeResponseResultType => {
+                self.x_edge_response_result_type = Some(value.into());
+            }
+            Field::CsProtocolVersion => {
+                self.cs_protocol_version =
+                    Some(value.parse().map_err(|_e| "cs_protocol_version invalid")?);
+            }
+            Field::FleStatus => self.fle_status = value.as_optional_str(),
+            Field::FleEncryptedFields => {
+                self.fle_encrypted_fields =
+                    parse_as_option(value).map_err(|_e| "fle_encrypted_fields invalid")?;
+            }
+            Field::CPort => self.c_port = Some(value.parse().map_err(|_e| "c_port invalid")?),
+            Field::TimeToFirstByte => {
+                self.time_to_first_byte =
+                    Some(parse_duration(value).map_err(|_e| "time_to_first_byte invalid")?);
+            }
+            Field::XEdgeDetailedResultType => {
+                self.x_edge_detailed_result_type = Some(value.into());
+            }
+            Field::ScContentType => self.sc_content_type = value.as_optional_str(),
+            Field::ScContentLen => {
+                self.sc_content_len =
+                    parse_as_option(value).map_err(|_e| "sc_content_len invalid")?;
+            }
+            Field::ScRangeStart => {
+                self.sc_range_start =
+                    parse_as_option(value).map_err(|_e| "sc_range_start invalid")?;
+            }
+            Field::ScRangeEnd => {
+                self.sc_range_end = parse_as_option(value).map_err(|_e| "sc_range_end invalid")?;
+            }
+        }
+        Ok(())
+    }
+}
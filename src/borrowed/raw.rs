@@ -45,7 +45,8 @@ pub type ValidatedLogline<'a> = Logline<'a, Validated>;
 ///
 /// # Panics
 ///
-/// Construction can panic if the input is not a valid log line!
+/// Construction never panics.
+/// If the input has fewer fields than expected, the missing fields are empty strings.
 ///
 /// # Examples
 ///
@@ -108,6 +109,59 @@ pub struct Logline<'a, V> {
     __marker: PhantomData<V>,
 }
 
+impl<'a, V> Logline<'a, V> {
+    /// Returns the field
+    ///
+    /// Useful if the fields of interest are only known at runtime, for example from a configuration.
+    ///
+    /// ```rust
+    /// use cloudfront_logs::{borrowed::raw::ValidatedLogline, types::*};
+    ///
+    /// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+    /// let item = ValidatedLogline::try_from(line).unwrap();
+    ///
+    /// let field: Field = "cs(Host)".parse().unwrap();
+    /// assert_eq!(item.get(field), "d111111abcdef8.cloudfront.net");
+    /// ```
+    pub fn get(&self, field: Field) -> &'a str {
+        match field {
+            Field::Date => self.date,
+            Field::Time => self.time,
+            Field::XEdgeLocation => self.x_edge_location,
+            Field::ScBytes => self.sc_bytes,
+            Field::CIp => self.c_ip,
+            Field::CsMethod => self.cs_method,
+            Field::CsHost => self.cs_host,
+            Field::CsUriStem => self.cs_uri_stem,
+            Field::ScStatus => self.sc_status,
+            Field::CsReferer => self.cs_referer,
+            Field::CsUserAgent => self.cs_user_agent,
+            Field::CsUriQuery => self.cs_uri_query,
+            Field::CsCookie => self.cs_cookie,
+            Field::XEdgeResultType => self.x_edge_result_type,
+            Field::XEdgeRequestId => self.x_edge_request_id,
+            Field::XHostHeader => self.x_host_header,
+            Field::CsProtocol => self.cs_protocol,
+            Field::CsBytes => self.cs_bytes,
+            Field::TimeTaken => self.time_taken,
+            Field::XForwardedFor => self.x_forwarded_for,
+            Field::SslProtocol => self.ssl_protocol,
+            Field::SslCipher => self.ssl_cipher,
+            Field::XEdgeResponseResultType => self.x_edge_response_result_type,
+            Field::CsProtocolVersion => self.cs_protocol_version,
+            Field::FleStatus => self.fle_status,
+            Field::FleEncryptedFields => self.fle_encrypted_fields,
+            Field::CPort => self.c_port,
+            Field::TimeToFirstByte => self.time_to_first_byte,
+            Field::XEdgeDetailedResultType => self.x_edge_detailed_result_type,
+            Field::ScContentType => self.sc_content_type,
+            Field::ScContentLen => self.sc_content_len,
+            Field::ScRangeStart => self.sc_range_start,
+            Field::ScRangeEnd => self.sc_range_end,
+        }
+    }
+}
+
 impl<'a> TryFrom<&'a str> for Logline<'a, Validated> {
     type Error = &'static str;
 
@@ -128,39 +182,39 @@ fn new_log_line<V>(line: &str) -> Logline<'_, V> {
     let mut iter = MemchrTabSplitter::new(line);
 
     Logline {
-        date: iter.next().unwrap(),
-        time: iter.next().unwrap(),
-        x_edge_location: iter.next().unwrap(),
-        sc_bytes: iter.next().unwrap(),
-        c_ip: iter.next().unwrap(),
-        cs_method: iter.next().unwrap(),
-        cs_host: iter.next().unwrap(),
-        cs_uri_stem: iter.next().unwrap(),
-        sc_status: iter.next().unwrap(),
-        cs_referer: iter.next().unwrap(),
-        cs_user_agent: iter.next().unwrap(),
-        cs_uri_query: iter.next().unwrap(),
-        cs_cookie: iter.next().unwrap(),
-        x_edge_result_type: iter.next().unwrap(),
-        x_edge_request_id: iter.next().unwrap(),
-        x_host_header: iter.next().unwrap(),
-        cs_protocol: iter.next().unwrap(),
-        cs_bytes: iter.next().unwrap(),
-        time_taken: iter.next().unwrap(),
-        x_forwarded_for: iter.next().unwrap(),
-        ssl_protocol: iter.next().unwrap(),
-        ssl_cipher: iter.next().unwrap(),
-        x_edge_response_result_type: iter.next().unwrap(),
-        cs_protocol_version: iter.next().unwrap(),
-        fle_status: iter.next().unwrap(),
-        fle_encrypted_fields: iter.next().unwrap(),
-        c_port: iter.next().unwrap(),
-        time_to_first_byte: iter.next().unwrap(),
-        x_edge_detailed_result_type: iter.next().unwrap(),
-        sc_content_type: iter.next().unwrap(),
-        sc_content_len: iter.next().unwrap(),
-        sc_range_start: iter.next().unwrap(),
-        sc_range_end: iter.next().unwrap(),
+        date: iter.next().unwrap_or_default(),
+        time: iter.next().unwrap_or_default(),
+        x_edge_location: iter.next().unwrap_or_default(),
+        sc_bytes: iter.next().unwrap_or_default(),
+        c_ip: iter.next().unwrap_or_default(),
+        cs_method: iter.next().unwrap_or_default(),
+        cs_host: iter.next().unwrap_or_default(),
+        cs_uri_stem: iter.next().unwrap_or_default(),
+        sc_status: iter.next().unwrap_or_default(),
+        cs_referer: iter.next().unwrap_or_default(),
+        cs_user_agent: iter.next().unwrap_or_default(),
+        cs_uri_query: iter.next().unwrap_or_default(),
+        cs_cookie: iter.next().unwrap_or_default(),
+        x_edge_result_type: iter.next().unwrap_or_default(),
+        x_edge_request_id: iter.next().unwrap_or_default(),
+        x_host_header: iter.next().unwrap_or_default(),
+        cs_protocol: iter.next().unwrap_or_default(),
+        cs_bytes: iter.next().unwrap_or_default(),
+        time_taken: iter.next().unwrap_or_default(),
+        x_forwarded_for: iter.next().unwrap_or_default(),
+        ssl_protocol: iter.next().unwrap_or_default(),
+        ssl_cipher: iter.next().unwrap_or_default(),
+        x_edge_response_result_type: iter.next().unwrap_or_default(),
+        cs_protocol_version: iter.next().unwrap_or_default(),
+        fle_status: iter.next().unwrap_or_default(),
+        fle_encrypted_fields: iter.next().unwrap_or_default(),
+        c_port: iter.next().unwrap_or_default(),
+        time_to_first_byte: iter.next().unwrap_or_default(),
+        x_edge_detailed_result_type: iter.next().unwrap_or_default(),
+        sc_content_type: iter.next().unwrap_or_default(),
+        sc_content_len: iter.next().unwrap_or_default(),
+        sc_range_start: iter.next().unwrap_or_default(),
+        sc_range_end: iter.next().unwrap_or_default(),
         __marker: PhantomData,
     }
 }
@@ -246,3 +300,61 @@ impl<'a> From<Logline<'a, Unvalidated>> for Logline<'a, Validated> {
         }
     }
 }
+
+#[cfg(feature = "alloc")]
+impl<V> Logline<'_, V> {
+    /// Converts the line into the [owned](crate::owned::raw) variant, keeping the validation marker
+    pub fn into_owned(self) -> crate::owned::raw::Logline<V> {
+        self.into()
+    }
+
+    /// Copies the line into the [owned](crate::owned::raw) variant, keeping the validation marker
+    pub fn to_owned_logline(&self) -> crate::owned::raw::Logline<V>
+    where
+        Self: Copy,
+    {
+        (*self).into()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, V> From<&'a crate::owned::raw::Logline<V>> for Logline<'a, V> {
+    fn from(owned: &'a crate::owned::raw::Logline<V>) -> Self {
+        Logline {
+            date: owned.date.as_str(),
+            time: owned.time.as_str(),
+            x_edge_location: owned.x_edge_location.as_str(),
+            sc_bytes: owned.sc_bytes.as_str(),
+            c_ip: owned.c_ip.as_str(),
+            cs_method: owned.cs_method.as_str(),
+            cs_host: owned.cs_host.as_str(),
+            cs_uri_stem: owned.cs_uri_stem.as_str(),
+            sc_status: owned.sc_status.as_str(),
+            cs_referer: owned.cs_referer.as_str(),
+            cs_user_agent: owned.cs_user_agent.as_str(),
+            cs_uri_query: owned.cs_uri_query.as_str(),
+            cs_cookie: owned.cs_cookie.as_str(),
+            x_edge_result_type: owned.x_edge_result_type.as_str(),
+            x_edge_request_id: owned.x_edge_request_id.as_str(),
+            x_host_header: owned.x_host_header.as_str(),
+            cs_protocol: owned.cs_protocol.as_str(),
+            cs_bytes: owned.cs_bytes.as_str(),
+            time_taken: owned.time_taken.as_str(),
+            x_forwarded_for: owned.x_forwarded_for.as_str(),
+            ssl_protocol: owned.ssl_protocol.as_str(),
+            ssl_cipher: owned.ssl_cipher.as_str(),
+            x_edge_response_result_type: owned.x_edge_response_result_type.as_str(),
+            cs_protocol_version: owned.cs_protocol_version.as_str(),
+            fle_status: owned.fle_status.as_str(),
+            fle_encrypted_fields: owned.fle_encrypted_fields.as_str(),
+            c_port: owned.c_port.as_str(),
+            time_to_first_byte: owned.time_to_first_byte.as_str(),
+            x_edge_detailed_result_type: owned.x_edge_detailed_result_type.as_str(),
+            sc_content_type: owned.sc_content_type.as_str(),
+            sc_content_len: owned.sc_content_len.as_str(),
+            sc_range_start: owned.sc_range_start.as_str(),
+            sc_range_end: owned.sc_range_end.as_str(),
+            __marker: PhantomData,
+        }
+    }
+}
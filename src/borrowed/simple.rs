@@ -18,9 +18,9 @@ use crate::{
 /// This is useful if you cannot skip the comment lines or have reason to not trust the input for format correctness.
 /// The latter should be only an issue if you do not use this crate on CloudFront logs directly.
 ///
-/// # Panics
+/// # Errors
 ///
-/// Construction can panic if the input is not a valid log line!
+/// Construction never panics, an invalid log line results in an error instead.
 ///
 /// # Examples
 ///
@@ -55,9 +55,9 @@ pub type ValidatedLogline<'a> = Logline<'a, Validated>;
 /// Note: This is the only variant which can use the `From` trait instead of `TryFrom`,
 /// because validation is skipped and the input data does not need to be parsed into other types.
 ///
-/// # Panics
+/// # Errors
 ///
-/// Construction can panic if the input is not a valid log line!
+/// Construction never panics, an invalid log line results in an error instead.
 ///
 /// # Examples
 ///
@@ -97,7 +97,7 @@ pub struct Logline<'a, V> {
     pub cs_user_agent: &'a str,
     pub cs_uri_query: Option<&'a str>,
     pub cs_cookie: Option<&'a str>,
-    pub x_edge_result_type: EdgeResultType,
+    pub x_edge_result_type: EdgeResultType<'a>,
     pub x_edge_request_id: &'a str,
     pub x_host_header: &'a str,
     pub cs_protocol: CsProtocol,
@@ -106,13 +106,13 @@ pub struct Logline<'a, V> {
     pub x_forwarded_for: Option<ForwardedForAddrs>,
     pub ssl_protocol: Option<SslProtocol>,
     pub ssl_cipher: Option<&'a str>,
-    pub x_edge_response_result_type: EdgeResultType,
+    pub x_edge_response_result_type: EdgeResultType<'a>,
     pub cs_protocol_version: CsProtocolVersion,
     pub fle_status: Option<&'a str>,
     pub fle_encrypted_fields: Option<u64>,
     pub c_port: u16,
     pub time_to_first_byte: Duration,
-    pub x_edge_detailed_result_type: DetailedEdgeResultType,
+    pub x_edge_detailed_result_type: DetailedEdgeResultType<'a>,
     pub sc_content_type: Option<&'a str>,
     pub sc_content_len: Option<u64>,
     pub sc_range_start: Option<i64>, // *1
@@ -144,114 +144,160 @@ fn new_log_line<V>(line: &str) -> Result<Logline<'_, V>, &'static str> {
     let mut iter = MemchrTabSplitter::new(line);
 
     let line = Logline {
-        date: iter.next().unwrap(),
-        time: iter.next().unwrap(),
-        x_edge_location: iter.next().unwrap(),
-        sc_bytes: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "sc_bytes invalid")?,
-        c_ip: iter.next().unwrap().parse().map_err(|_e| "c_ip invalid")?,
-        cs_method: iter.next().unwrap(),
-        cs_host: iter.next().unwrap(),
-        cs_uri_stem: iter.next().unwrap(),
-        sc_status: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "sc_status invalid")?,
-        cs_referer: iter.next().unwrap().as_optional_str(),
-        cs_user_agent: iter.next().unwrap(),
-        cs_uri_query: iter.next().unwrap().as_optional_str(),
-        cs_cookie: iter.next().unwrap().as_optional_str(),
-        x_edge_result_type: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "x_edge_result_type invalid")?,
-        x_edge_request_id: iter.next().unwrap(),
-        x_host_header: iter.next().unwrap(),
-        cs_protocol: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "cs_protocol invalid")?,
-        cs_bytes: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "cs_bytes invalid")?,
-        time_taken: iter
-            .next()
-            .unwrap()
-            .parse::<f64>()
-            .map(Duration::from_secs_f64)
-            .map_err(|_e| "time_taken invalid")?,
-        x_forwarded_for: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "x_forwarded_for invalid")?,
-        ssl_protocol: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "ssl_protocol invalid")?,
-        ssl_cipher: iter.next().unwrap().as_optional_str(),
-        x_edge_response_result_type: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "x_edge_response_result_type invalid")?,
+        date: iter.field()?,
+        time: iter.field()?,
+        x_edge_location: iter.field()?,
+        sc_bytes: iter.field()?.parse().map_err(|_e| "sc_bytes invalid")?,
+        c_ip: iter.field()?.parse().map_err(|_e| "c_ip invalid")?,
+        cs_method: iter.field()?,
+        cs_host: iter.field()?,
+        cs_uri_stem: iter.field()?,
+        sc_status: iter.field()?.parse().map_err(|_e| "sc_status invalid")?,
+        cs_referer: iter.field()?.as_optional_str(),
+        cs_user_agent: iter.field()?,
+        cs_uri_query: iter.field()?.as_optional_str(),
+        cs_cookie: iter.field()?.as_optional_str(),
+        x_edge_result_type: iter.field()?.into(),
+        x_edge_request_id: iter.field()?,
+        x_host_header: iter.field()?,
+        cs_protocol: iter.field()?.parse().map_err(|_e| "cs_protocol invalid")?,
+        cs_bytes: iter.field()?.parse().map_err(|_e| "cs_bytes invalid")?,
+        time_taken: parse_duration(iter.field()?).map_err(|_e| "time_taken invalid")?,
+        x_forwarded_for: parse_as_option(iter.field()?).map_err(|_e| "x_forwarded_for invalid")?,
+        ssl_protocol: parse_as_option(iter.field()?).map_err(|_e| "ssl_protocol invalid")?,
+        ssl_cipher: iter.field()?.as_optional_str(),
+        x_edge_response_result_type: iter.field()?.into(),
         cs_protocol_version: iter
-            .next()
-            .unwrap()
+            .field()?
             .parse()
             .map_err(|_e| "cs_protocol_version invalid")?,
-        fle_status: iter.next().unwrap().as_optional_str(),
-        fle_encrypted_fields: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
+        fle_status: iter.field()?.as_optional_str(),
+        fle_encrypted_fields: parse_as_option(iter.field()?)
             .map_err(|_e| "fle_encrypted_fields invalid")?,
-        c_port: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "c_port invalid")?,
-        time_to_first_byte: iter
-            .next()
-            .unwrap()
-            .parse::<f64>()
-            .map(Duration::from_secs_f64)
+        c_port: iter.field()?.parse().map_err(|_e| "c_port invalid")?,
+        time_to_first_byte: parse_duration(iter.field()?)
             .map_err(|_e| "time_to_first_byte invalid")?,
-        x_edge_detailed_result_type: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "x_edge_detailed_result_type invalid")?,
-        sc_content_type: iter.next().unwrap().as_optional_str(),
-        sc_content_len: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "sc_content_len invalid")?,
-        sc_range_start: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "sc_range_start invalid")?,
-        sc_range_end: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "sc_range_end invalid")?,
+        x_edge_detailed_result_type: iter.field()?.into(),
+        sc_content_type: iter.field()?.as_optional_str(),
+        sc_content_len: parse_as_option(iter.field()?).map_err(|_e| "sc_content_len invalid")?,
+        sc_range_start: parse_as_option(iter.field()?).map_err(|_e| "sc_range_start invalid")?,
+        sc_range_end: parse_as_option(iter.field()?).map_err(|_e| "sc_range_end invalid")?,
         __marker: PhantomData,
     };
     Ok(line)
 }
 
+impl<'a> Logline<'a, Validated> {
+    /// Parses the line, falling back to placeholders for fields which cannot be parsed
+    ///
+    /// Unlike [`TryFrom`], a single corrupted field does not discard the whole line:
+    /// the returned [`Lenient`] holds the logline and the list of fields which could not be parsed.
+    /// See [`Lenient`] for the placeholder values.
+    /// The line itself still has to be valid, e.g. have the right number of fields.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cloudfront_logs::{borrowed::simple::ValidatedLogline, types::*};
+    ///
+    /// // the sc-status field is corrupted
+    /// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	2xx	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+    ///
+    /// let lenient = ValidatedLogline::parse_lenient(line).unwrap();
+    /// assert!(lenient.has_error(Field::ScStatus));
+    /// assert_eq!(lenient.logline.sc_status, 0);
+    /// assert_eq!(lenient.logline.sc_bytes, 392);
+    /// ```
+    pub fn parse_lenient(line: &'a str) -> Result<Lenient<Self>, &'static str> {
+        validate_line(line)?;
+        parse_lenient(line)
+    }
+}
+
+impl<'a> Logline<'a, Unvalidated> {
+    /// Parses the line, falling back to placeholders for fields which cannot be parsed
+    ///
+    /// See [`ValidatedLogline::parse_lenient`] for details.
+    pub fn parse_lenient(line: &'a str) -> Result<Lenient<Self>, &'static str> {
+        parse_lenient(line)
+    }
+}
+
+// the lenient counterpart of the regular parsing, only a missing field is still an error;
+// also used by the typed and owned variants
+pub(crate) fn parse_lenient<V>(line: &str) -> Result<Lenient<Logline<'_, V>>, &'static str> {
+    let mut iter = MemchrTabSplitter::new(line);
+    let mut errors = FieldErrors::default();
+
+    let line = Logline {
+        date: iter.field()?,
+        time: iter.field()?,
+        x_edge_location: iter.field()?,
+        sc_bytes: errors.parse(Field::ScBytes, iter.field()?, str::parse, 0),
+        c_ip: errors.parse(
+            Field::CIp,
+            iter.field()?,
+            str::parse,
+            IpAddr::from([0, 0, 0, 0]),
+        ),
+        cs_method: iter.field()?,
+        cs_host: iter.field()?,
+        cs_uri_stem: iter.field()?,
+        sc_status: errors.parse(Field::ScStatus, iter.field()?, str::parse, 0),
+        cs_referer: iter.field()?.as_optional_str(),
+        cs_user_agent: iter.field()?,
+        cs_uri_query: iter.field()?.as_optional_str(),
+        cs_cookie: iter.field()?.as_optional_str(),
+        x_edge_result_type: iter.field()?.into(),
+        x_edge_request_id: iter.field()?,
+        x_host_header: iter.field()?,
+        cs_protocol: errors.parse(
+            Field::CsProtocol,
+            iter.field()?,
+            str::parse,
+            CsProtocol::Http,
+        ),
+        cs_bytes: errors.parse(Field::CsBytes, iter.field()?, str::parse, 0),
+        time_taken: errors.parse(
+            Field::TimeTaken,
+            iter.field()?,
+            parse_duration,
+            Duration::ZERO,
+        ),
+        x_forwarded_for: errors.parse(Field::XForwardedFor, iter.field()?, parse_as_option, None),
+        ssl_protocol: errors.parse(Field::SslProtocol, iter.field()?, parse_as_option, None),
+        ssl_cipher: iter.field()?.as_optional_str(),
+        x_edge_response_result_type: iter.field()?.into(),
+        cs_protocol_version: errors.parse(
+            Field::CsProtocolVersion,
+            iter.field()?,
+            str::parse,
+            CsProtocolVersion::HTTP1_1,
+        ),
+        fle_status: iter.field()?.as_optional_str(),
+        fle_encrypted_fields: errors.parse(
+            Field::FleEncryptedFields,
+            iter.field()?,
+            parse_as_option,
+            None,
+        ),
+        c_port: errors.parse(Field::CPort, iter.field()?, str::parse, 0),
+        time_to_first_byte: errors.parse(
+            Field::TimeToFirstByte,
+            iter.field()?,
+            parse_duration,
+            Duration::ZERO,
+        ),
+        x_edge_detailed_result_type: iter.field()?.into(),
+        sc_content_type: iter.field()?.as_optional_str(),
+        sc_content_len: errors.parse(Field::ScContentLen, iter.field()?, parse_as_option, None),
+        sc_range_start: errors.parse(Field::ScRangeStart, iter.field()?, parse_as_option, None),
+        sc_range_end: errors.parse(Field::ScRangeEnd, iter.field()?, parse_as_option, None),
+        __marker: PhantomData,
+    };
+    Ok(errors.finish(line))
+}
+
 impl<'a> TryFrom<ValidatedRaw<'a>> for Logline<'a, Validated> {
     type Error = &'static str;
 
@@ -283,10 +329,7 @@ fn try_from_v<V>(raw: RawLogline<'_, V>) -> Result<Logline<'_, V>, &'static str>
         cs_user_agent: raw.cs_user_agent,
         cs_uri_query: raw.cs_uri_query.as_optional_str(),
         cs_cookie: raw.cs_cookie.as_optional_str(),
-        x_edge_result_type: raw
-            .x_edge_result_type
-            .parse()
-            .map_err(|_e| "x_edge_result_type invalid")?,
+        x_edge_result_type: raw.x_edge_result_type.into(),
         x_edge_request_id: raw.x_edge_request_id,
         x_host_header: raw.x_host_header,
         cs_protocol: raw
@@ -294,19 +337,12 @@ fn try_from_v<V>(raw: RawLogline<'_, V>) -> Result<Logline<'_, V>, &'static str>
             .parse()
             .map_err(|_e| "cs_protocol invalid")?,
         cs_bytes: raw.cs_bytes.parse().map_err(|_e| "cs_bytes invalid")?,
-        time_taken: raw
-            .time_taken
-            .parse::<f64>()
-            .map(Duration::from_secs_f64)
-            .map_err(|_e| "time_taken invalid")?,
+        time_taken: parse_duration(raw.time_taken).map_err(|_e| "time_taken invalid")?,
         x_forwarded_for: parse_as_option(raw.x_forwarded_for)
             .map_err(|_e| "x_forwarded_for invalid")?,
         ssl_protocol: parse_as_option(raw.ssl_protocol).map_err(|_e| "ssl_protocol invalid")?,
         ssl_cipher: raw.ssl_cipher.as_optional_str(),
-        x_edge_response_result_type: raw
-            .x_edge_response_result_type
-            .parse()
-            .map_err(|_e| "x_edge_response_result_type invalid")?,
+        x_edge_response_result_type: raw.x_edge_response_result_type.into(),
         cs_protocol_version: raw
             .cs_protocol_version
             .parse()
@@ -315,15 +351,9 @@ fn try_from_v<V>(raw: RawLogline<'_, V>) -> Result<Logline<'_, V>, &'static str>
         fle_encrypted_fields: parse_as_option(raw.fle_encrypted_fields)
             .map_err(|_e| "fle_encrypted_fields invalid")?,
         c_port: raw.c_port.parse().map_err(|_e| "c_port invalid")?,
-        time_to_first_byte: raw
-            .time_to_first_byte
-            .parse::<f64>()
-            .map(Duration::from_secs_f64)
+        time_to_first_byte: parse_duration(raw.time_to_first_byte)
             .map_err(|_e| "time_to_first_byte invalid")?,
-        x_edge_detailed_result_type: raw
-            .x_edge_detailed_result_type
-            .parse()
-            .map_err(|_e| "x_edge_detailed_result_type invalid")?,
+        x_edge_detailed_result_type: raw.x_edge_detailed_result_type.into(),
         sc_content_type: raw.sc_content_type.as_optional_str(),
         sc_content_len: parse_as_option(raw.sc_content_len)
             .map_err(|_e| "sc_content_len invalid")?,
@@ -416,3 +446,59 @@ impl<'a> From<Logline<'a, Unvalidated>> for Logline<'a, Validated> {
         }
     }
 }
+
+impl<V> Logline<'_, V> {
+    /// Converts the line into the [owned](crate::owned::simple) variant, keeping the validation marker
+    pub fn into_owned(self) -> crate::owned::simple::Logline<V> {
+        self.into()
+    }
+
+    /// Copies the line into the [owned](crate::owned::simple) variant, keeping the validation marker
+    pub fn to_owned_logline(&self) -> crate::owned::simple::Logline<V>
+    where
+        Self: Clone,
+    {
+        self.clone().into()
+    }
+}
+
+impl<'a, V> From<&'a crate::owned::simple::Logline<V>> for Logline<'a, V> {
+    fn from(owned: &'a crate::owned::simple::Logline<V>) -> Self {
+        Logline {
+            date: owned.date.as_str(),
+            time: owned.time.as_str(),
+            x_edge_location: owned.x_edge_location.as_str(),
+            sc_bytes: owned.sc_bytes,
+            c_ip: owned.c_ip,
+            cs_method: owned.cs_method.as_str(),
+            cs_host: owned.cs_host.as_str(),
+            cs_uri_stem: owned.cs_uri_stem.as_str(),
+            sc_status: owned.sc_status,
+            cs_referer: owned.cs_referer.as_deref(),
+            cs_user_agent: owned.cs_user_agent.as_str(),
+            cs_uri_query: owned.cs_uri_query.as_deref(),
+            cs_cookie: owned.cs_cookie.as_deref(),
+            x_edge_result_type: owned.x_edge_result_type.as_borrowed(),
+            x_edge_request_id: owned.x_edge_request_id.as_str(),
+            x_host_header: owned.x_host_header.as_str(),
+            cs_protocol: owned.cs_protocol,
+            cs_bytes: owned.cs_bytes,
+            time_taken: owned.time_taken,
+            x_forwarded_for: owned.x_forwarded_for.clone(),
+            ssl_protocol: owned.ssl_protocol,
+            ssl_cipher: owned.ssl_cipher.as_deref(),
+            x_edge_response_result_type: owned.x_edge_response_result_type.as_borrowed(),
+            cs_protocol_version: owned.cs_protocol_version,
+            fle_status: owned.fle_status.as_deref(),
+            fle_encrypted_fields: owned.fle_encrypted_fields,
+            c_port: owned.c_port,
+            time_to_first_byte: owned.time_to_first_byte,
+            x_edge_detailed_result_type: owned.x_edge_detailed_result_type.as_borrowed(),
+            sc_content_type: owned.sc_content_type.as_deref(),
+            sc_content_len: owned.sc_content_len,
+            sc_range_start: owned.sc_range_start,
+            sc_range_end: owned.sc_range_end,
+            __marker: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,460 @@
+use crate::{
+    borrowed::raw::{
+        Logline as RawLogline, UnvalidatedLogline as UnvalidatedRaw,
+        ValidatedLogline as ValidatedRaw,
+    },
+    borrowed::simple::Logline as SimpleLogline,
+    shared::*,
+    types::*,
+};
+use jiff::{
+    Timestamp, Zoned,
+    civil::{Date, Time},
+    tz::{Offset, TimeZone},
+};
+
+/// The validated typed log line, using [`jiff`](https://docs.rs/jiff/latest/jiff/index.html) crate for date and time
+///
+/// Most fields are parsed into more meaningful types.
+///
+/// On construction it checks if the line can be parsed.
+/// This is useful if you cannot skip the comment lines or have reason to not trust the input for format correctness.
+/// The latter should be only an issue if you do not use this crate on CloudFront logs directly.
+///
+/// # Errors
+///
+/// Construction never panics, an invalid log line results in an error instead.
+///
+/// # Examples
+///
+/// Use `.try_from()` or `.try_into()` to construct an instance, since action can fail.
+///
+/// ```rust
+/// use cloudfront_logs::{borrowed::typed::jiff::ValidatedLogline, types::*};
+///
+/// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let item = ValidatedLogline::try_from(line).unwrap();
+/// // alternative:
+/// let item: ValidatedLogline<'_> = line.try_into().unwrap();
+///
+/// assert_eq!(item.date, jiff::civil::date(2019, 12, 4));
+/// assert_eq!(item.sc_bytes, 392u64);
+/// assert_eq!(item.cs_protocol, CsProtocol::Https);
+/// ```
+pub type ValidatedLogline<'a> = Logline<'a, Validated>;
+
+/// The unvalidated typed log line, using [`jiff`](https://docs.rs/jiff/latest/jiff/index.html) crate for date and time
+///
+/// Most fields are parsed into more meaningful types.
+///
+/// Unlike [`ValidatedLogline`], this variant does not check if the line can be parsed.
+/// Use this if you already did a check before creating this struct.
+/// A common scenario is that you 1) trust the input data and 2) skipped the comment lines.
+///
+/// Note: This is the only variant which can use the `From` trait instead of `TryFrom`,
+/// because validation is skipped and the input data does not need to be parsed into other types.
+///
+/// # Errors
+///
+/// Construction never panics, an invalid log line results in an error instead.
+///
+/// # Examples
+///
+/// Use `.try_from()` or `.try_into()` to construct an instance, since action can fail.
+///
+/// ```rust
+/// use cloudfront_logs::{borrowed::typed::jiff::UnvalidatedLogline, types::*};
+///
+/// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let item = UnvalidatedLogline::try_from(line).unwrap();
+/// // alternative:
+/// let item: UnvalidatedLogline<'_> = line.try_into().unwrap();
+///
+/// assert_eq!(item.date, jiff::civil::date(2019, 12, 4));
+/// assert_eq!(item.sc_bytes, 392u64);
+/// assert_eq!(item.cs_protocol, CsProtocol::Https);
+/// ```
+pub type UnvalidatedLogline<'a> = Logline<'a, Unvalidated>;
+
+/// The generic, raw log line type
+///
+/// Do not use it directly, prefer [`ValidatedLogline`] or [`UnvalidatedLogline`] instead.
+#[must_use]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logline<'a, V> {
+    pub date: Date,
+    pub time: Time,
+    pub datetime: Timestamp,
+    pub x_edge_location: &'a str,
+    pub sc_bytes: u64,
+    pub c_ip: IpAddr,
+    pub cs_method: &'a str,
+    pub cs_host: &'a str,
+    pub cs_uri_stem: &'a str,
+    pub sc_status: u16,
+    pub cs_referer: Option<&'a str>,
+    pub cs_user_agent: &'a str,
+    pub cs_uri_query: Option<&'a str>,
+    pub cs_cookie: Option<&'a str>,
+    pub x_edge_result_type: EdgeResultType<'a>,
+    pub x_edge_request_id: &'a str,
+    pub x_host_header: &'a str,
+    pub cs_protocol: CsProtocol,
+    pub cs_bytes: u64,
+    pub time_taken: Duration,
+    pub x_forwarded_for: Option<ForwardedForAddrs>,
+    pub ssl_protocol: Option<SslProtocol>,
+    pub ssl_cipher: Option<&'a str>,
+    pub x_edge_response_result_type: EdgeResultType<'a>,
+    pub cs_protocol_version: CsProtocolVersion,
+    pub fle_status: Option<&'a str>,
+    pub fle_encrypted_fields: Option<u64>,
+    pub c_port: u16,
+    pub time_to_first_byte: Duration,
+    pub x_edge_detailed_result_type: DetailedEdgeResultType<'a>,
+    pub sc_content_type: Option<&'a str>,
+    pub sc_content_len: Option<u64>,
+    pub sc_range_start: Option<i64>,
+    pub sc_range_end: Option<i64>,
+    __marker: PhantomData<V>,
+}
+
+impl<V> Logline<'_, V> {
+    /// The request time as seconds since the Unix epoch
+    pub fn unix_timestamp(&self) -> i64 {
+        self.datetime.as_second()
+    }
+
+    /// The request time as milliseconds since the Unix epoch
+    pub fn unix_timestamp_millis(&self) -> i64 {
+        self.datetime.as_millisecond()
+    }
+
+    /// The request time in the given timezone, e.g. for reporting in local time
+    pub fn datetime_in(&self, tz: TimeZone) -> Zoned {
+        self.datetime.to_zoned(tz)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Logline<'a, Validated> {
+    type Error = &'static str;
+
+    fn try_from(line: &'a str) -> Result<Self, Self::Error> {
+        validate_line(line)?;
+        new_log_line(line)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Logline<'a, Unvalidated> {
+    type Error = &'static str;
+
+    fn try_from(line: &'a str) -> Result<Self, Self::Error> {
+        new_log_line(line)
+    }
+}
+
+fn new_log_line<V>(line: &str) -> Result<Logline<'_, V>, &'static str> {
+    let mut iter = MemchrTabSplitter::new(line);
+
+    let date = parse_jiff_date(iter.field()?).map_err(|_e| "date invalid")?;
+    let time = parse_jiff_time(iter.field()?).map_err(|_e| "time invalid")?;
+    let datetime = Offset::UTC
+        .to_timestamp(date.to_datetime(time))
+        .map_err(|_e| "datetime invalid")?;
+
+    let line = Logline {
+        date,
+        time,
+        datetime,
+        x_edge_location: iter.field()?,
+        sc_bytes: iter.field()?.parse().map_err(|_e| "sc_bytes invalid")?,
+        c_ip: iter.field()?.parse().map_err(|_e| "c_ip invalid")?,
+        cs_method: iter.field()?,
+        cs_host: iter.field()?,
+        cs_uri_stem: iter.field()?,
+        sc_status: iter.field()?.parse().map_err(|_e| "sc_status invalid")?,
+        cs_referer: iter.field()?.as_optional_str(),
+        cs_user_agent: iter.field()?,
+        cs_uri_query: iter.field()?.as_optional_str(),
+        cs_cookie: iter.field()?.as_optional_str(),
+        x_edge_result_type: iter.field()?.into(),
+        x_edge_request_id: iter.field()?,
+        x_host_header: iter.field()?,
+        cs_protocol: iter.field()?.parse().map_err(|_e| "cs_protocol invalid")?,
+        cs_bytes: iter.field()?.parse().map_err(|_e| "cs_bytes invalid")?,
+        time_taken: parse_duration(iter.field()?).map_err(|_e| "time_taken invalid")?,
+        x_forwarded_for: parse_as_option(iter.field()?).map_err(|_e| "x_forwarded_for invalid")?,
+        ssl_protocol: parse_as_option(iter.field()?).map_err(|_e| "ssl_protocol invalid")?,
+        ssl_cipher: iter.field()?.as_optional_str(),
+        x_edge_response_result_type: iter.field()?.into(),
+        cs_protocol_version: iter
+            .field()?
+            .parse()
+            .map_err(|_e| "cs_protocol_version invalid")?,
+        fle_status: iter.field()?.as_optional_str(),
+        fle_encrypted_fields: parse_as_option(iter.field()?)
+            .map_err(|_e| "fle_encrypted_fields invalid")?,
+        c_port: iter.field()?.parse().map_err(|_e| "c_port invalid")?,
+        time_to_first_byte: parse_duration(iter.field()?)
+            .map_err(|_e| "time_to_first_byte invalid")?,
+        x_edge_detailed_result_type: iter.field()?.into(),
+        sc_content_type: iter.field()?.as_optional_str(),
+        sc_content_len: parse_as_option(iter.field()?).map_err(|_e| "sc_content_len invalid")?,
+        sc_range_start: parse_as_option(iter.field()?).map_err(|_e| "sc_range_start invalid")?,
+        sc_range_end: parse_as_option(iter.field()?).map_err(|_e| "sc_range_end invalid")?,
+        __marker: PhantomData,
+    };
+    Ok(line)
+}
+
+impl<'a> TryFrom<ValidatedRaw<'a>> for Logline<'a, Validated> {
+    type Error = &'static str;
+
+    fn try_from(raw: ValidatedRaw<'a>) -> Result<Self, Self::Error> {
+        try_from_v(raw)
+    }
+}
+
+impl<'a> TryFrom<UnvalidatedRaw<'a>> for Logline<'a, Unvalidated> {
+    type Error = &'static str;
+
+    fn try_from(raw: UnvalidatedRaw<'a>) -> Result<Self, Self::Error> {
+        try_from_v(raw)
+    }
+}
+
+fn try_from_v<V>(raw: RawLogline<'_, V>) -> Result<Logline<'_, V>, &'static str> {
+    let date = parse_jiff_date(raw.date).map_err(|_e| "date invalid")?;
+    let time = parse_jiff_time(raw.time).map_err(|_e| "time invalid")?;
+    let datetime = Offset::UTC
+        .to_timestamp(date.to_datetime(time))
+        .map_err(|_e| "datetime invalid")?;
+
+    let line = Logline {
+        date,
+        time,
+        datetime,
+        x_edge_location: raw.x_edge_location,
+        sc_bytes: raw.sc_bytes.parse().map_err(|_e| "sc_bytes invalid")?,
+        c_ip: raw.c_ip.parse().map_err(|_e| "c_ip invalid")?,
+        cs_method: raw.cs_method,
+        cs_host: raw.cs_host,
+        cs_uri_stem: raw.cs_uri_stem,
+        sc_status: raw.sc_status.parse().map_err(|_e| "sc_status invalid")?,
+        cs_referer: raw.cs_referer.as_optional_str(),
+        cs_user_agent: raw.cs_user_agent,
+        cs_uri_query: raw.cs_uri_query.as_optional_str(),
+        cs_cookie: raw.cs_cookie.as_optional_str(),
+        x_edge_result_type: raw.x_edge_result_type.into(),
+        x_edge_request_id: raw.x_edge_request_id,
+        x_host_header: raw.x_host_header,
+        cs_protocol: raw
+            .cs_protocol
+            .parse()
+            .map_err(|_e| "cs_protocol invalid")?,
+        cs_bytes: raw.cs_bytes.parse().map_err(|_e| "cs_bytes invalid")?,
+        time_taken: parse_duration(raw.time_taken).map_err(|_e| "time_taken invalid")?,
+        x_forwarded_for: parse_as_option(raw.x_forwarded_for)
+            .map_err(|_e| "x_forwarded_for invalid")?,
+        ssl_protocol: parse_as_option(raw.ssl_protocol).map_err(|_e| "ssl_protocol invalid")?,
+        ssl_cipher: raw.ssl_cipher.as_optional_str(),
+        x_edge_response_result_type: raw.x_edge_response_result_type.into(),
+        cs_protocol_version: raw
+            .cs_protocol_version
+            .parse()
+            .map_err(|_e| "cs_protocol_version invalid")?,
+        fle_status: raw.fle_status.as_optional_str(),
+        fle_encrypted_fields: parse_as_option(raw.fle_encrypted_fields)
+            .map_err(|_e| "fle_encrypted_fields invalid")?,
+        c_port: raw.c_port.parse().map_err(|_e| "c_port invalid")?,
+        time_to_first_byte: parse_duration(raw.time_to_first_byte)
+            .map_err(|_e| "time_to_first_byte invalid")?,
+        x_edge_detailed_result_type: raw.x_edge_detailed_result_type.into(),
+        sc_content_type: raw.sc_content_type.as_optional_str(),
+        sc_content_len: parse_as_option(raw.sc_content_len)
+            .map_err(|_e| "sc_content_len invalid")?,
+        sc_range_start: parse_as_option(raw.sc_range_start)
+            .map_err(|_e| "sc_range_start invalid")?,
+        sc_range_end: parse_as_option(raw.sc_range_end).map_err(|_e| "sc_range_end invalid")?,
+        __marker: PhantomData,
+    };
+    Ok(line)
+}
+
+impl<'a> Logline<'a, Validated> {
+    /// Parses the line, falling back to placeholders for fields which cannot be parsed
+    ///
+    /// Unlike [`TryFrom`], a single corrupted field does not discard the whole line:
+    /// the returned [`Lenient`] holds the logline and the list of fields which could not be parsed.
+    /// An unparseable date or time is replaced by the Unix epoch, see [`Lenient`] for the other placeholders.
+    /// The line itself still has to be valid, e.g. have the right number of fields.
+    pub fn parse_lenient(line: &'a str) -> Result<Lenient<Self>, &'static str> {
+        SimpleLogline::<Validated>::parse_lenient(line).map(from_lenient_simple)
+    }
+}
+
+impl<'a> Logline<'a, Unvalidated> {
+    /// Parses the line, falling back to placeholders for fields which cannot be parsed
+    ///
+    /// See [`ValidatedLogline::parse_lenient`] for details.
+    pub fn parse_lenient(line: &'a str) -> Result<Lenient<Self>, &'static str> {
+        SimpleLogline::<Unvalidated>::parse_lenient(line).map(from_lenient_simple)
+    }
+}
+
+fn from_lenient_simple<V>(simple: Lenient<SimpleLogline<'_, V>>) -> Lenient<Logline<'_, V>> {
+    let Lenient {
+        logline: simple,
+        errors: simple_errors,
+    } = simple;
+    // keep the errors in field order, date and time come first
+    let mut errors = FieldErrors::default();
+    let date = errors.parse(
+        Field::Date,
+        simple.date,
+        parse_jiff_date,
+        jiff::civil::date(1970, 1, 1),
+    );
+    let time = errors.parse(Field::Time, simple.time, parse_jiff_time, Time::midnight());
+    // the very end of year 9999 is out of the timestamp range, blame the date for it
+    let datetime = errors.parse(
+        Field::Date,
+        simple.date,
+        |_date| Offset::UTC.to_timestamp(date.to_datetime(time)),
+        Timestamp::UNIX_EPOCH,
+    );
+    errors.0.extend(simple_errors);
+    errors.finish(from_simple(simple, date, time, datetime))
+}
+
+// moves the fields of the simple line, with its date, time and datetime already parsed
+fn from_simple<V>(
+    simple: SimpleLogline<'_, V>,
+    date: Date,
+    time: Time,
+    datetime: Timestamp,
+) -> Logline<'_, V> {
+    Logline {
+        date,
+        time,
+        datetime,
+        x_edge_location: simple.x_edge_location,
+        sc_bytes: simple.sc_bytes,
+        c_ip: simple.c_ip,
+        cs_method: simple.cs_method,
+        cs_host: simple.cs_host,
+        cs_uri_stem: simple.cs_uri_stem,
+        sc_status: simple.sc_status,
+        cs_referer: simple.cs_referer,
+        cs_user_agent: simple.cs_user_agent,
+        cs_uri_query: simple.cs_uri_query,
+        cs_cookie: simple.cs_cookie,
+        x_edge_result_type: simple.x_edge_result_type,
+        x_edge_request_id: simple.x_edge_request_id,
+        x_host_header: simple.x_host_header,
+        cs_protocol: simple.cs_protocol,
+        cs_bytes: simple.cs_bytes,
+        time_taken: simple.time_taken,
+        x_forwarded_for: simple.x_forwarded_for,
+        ssl_protocol: simple.ssl_protocol,
+        ssl_cipher: simple.ssl_cipher,
+        x_edge_response_result_type: simple.x_edge_response_result_type,
+        cs_protocol_version: simple.cs_protocol_version,
+        fle_status: simple.fle_status,
+        fle_encrypted_fields: simple.fle_encrypted_fields,
+        c_port: simple.c_port,
+        time_to_first_byte: simple.time_to_first_byte,
+        x_edge_detailed_result_type: simple.x_edge_detailed_result_type,
+        sc_content_type: simple.sc_content_type,
+        sc_content_len: simple.sc_content_len,
+        sc_range_start: simple.sc_range_start,
+        sc_range_end: simple.sc_range_end,
+        __marker: PhantomData,
+    }
+}
+
+impl<'a> From<Logline<'a, Validated>> for Logline<'a, Unvalidated> {
+    fn from(validated: Logline<'a, Validated>) -> Self {
+        Logline {
+            date: validated.date,
+            time: validated.time,
+            datetime: validated.datetime,
+            x_edge_location: validated.x_edge_location,
+            sc_bytes: validated.sc_bytes,
+            c_ip: validated.c_ip,
+            cs_method: validated.cs_method,
+            cs_host: validated.cs_host,
+            cs_uri_stem: validated.cs_uri_stem,
+            sc_status: validated.sc_status,
+            cs_referer: validated.cs_referer,
+            cs_user_agent: validated.cs_user_agent,
+            cs_uri_query: validated.cs_uri_query,
+            cs_cookie: validated.cs_cookie,
+            x_edge_result_type: validated.x_edge_result_type,
+            x_edge_request_id: validated.x_edge_request_id,
+            x_host_header: validated.x_host_header,
+            cs_protocol: validated.cs_protocol,
+            cs_bytes: validated.cs_bytes,
+            time_taken: validated.time_taken,
+            x_forwarded_for: validated.x_forwarded_for,
+            ssl_protocol: validated.ssl_protocol,
+            ssl_cipher: validated.ssl_cipher,
+            x_edge_response_result_type: validated.x_edge_response_result_type,
+            cs_protocol_version: validated.cs_protocol_version,
+            fle_status: validated.fle_status,
+            fle_encrypted_fields: validated.fle_encrypted_fields,
+            c_port: validated.c_port,
+            time_to_first_byte: validated.time_to_first_byte,
+            x_edge_detailed_result_type: validated.x_edge_detailed_result_type,
+            sc_content_type: validated.sc_content_type,
+            sc_content_len: validated.sc_content_len,
+            sc_range_start: validated.sc_range_start,
+            sc_range_end: validated.sc_range_end,
+            __marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<Logline<'a, Unvalidated>> for Logline<'a, Validated> {
+    fn from(unvalidated: Logline<'a, Unvalidated>) -> Self {
+        Logline {
+            date: unvalidated.date,
+            time: unvalidated.time,
+            datetime: unvalidated.datetime,
+            x_edge_location: unvalidated.x_edge_location,
+            sc_bytes: unvalidated.sc_bytes,
+            c_ip: unvalidated.c_ip,
+            cs_method: unvalidated.cs_method,
+            cs_host: unvalidated.cs_host,
+            cs_uri_stem: unvalidated.cs_uri_stem,
+            sc_status: unvalidated.sc_status,
+            cs_referer: unvalidated.cs_referer,
+            cs_user_agent: unvalidated.cs_user_agent,
+            cs_uri_query: unvalidated.cs_uri_query,
+            cs_cookie: unvalidated.cs_cookie,
+            x_edge_result_type: unvalidated.x_edge_result_type,
+            x_edge_request_id: unvalidated.x_edge_request_id,
+            x_host_header: unvalidated.x_host_header,
+            cs_protocol: unvalidated.cs_protocol,
+            cs_bytes: unvalidated.cs_bytes,
+            time_taken: unvalidated.time_taken,
+            x_forwarded_for: unvalidated.x_forwarded_for,
+            ssl_protocol: unvalidated.ssl_protocol,
+            ssl_cipher: unvalidated.ssl_cipher,
+            x_edge_response_result_type: unvalidated.x_edge_response_result_type,
+            cs_protocol_version: unvalidated.cs_protocol_version,
+            fle_status: unvalidated.fle_status,
+            fle_encrypted_fields: unvalidated.fle_encrypted_fields,
+            c_port: unvalidated.c_port,
+            time_to_first_byte: unvalidated.time_to_first_byte,
+            x_edge_detailed_result_type: unvalidated.x_edge_detailed_result_type,
+            sc_content_type: unvalidated.sc_content_type,
+            sc_content_len: unvalidated.sc_content_len,
+            sc_range_start: unvalidated.sc_range_start,
+            sc_range_end: unvalidated.sc_range_end,
+            __marker: PhantomData,
+        }
+    }
+}
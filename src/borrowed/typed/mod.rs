@@ -13,3 +13,11 @@ pub mod time;
 pub use time::{
     UnvalidatedLogline as UnvalidatedTimeLogline, ValidatedLogline as ValidatedTimeLogline,
 };
+
+#[cfg(feature = "jiff")]
+pub mod jiff;
+
+#[cfg(feature = "jiff")]
+pub use jiff::{
+    UnvalidatedLogline as UnvalidatedJiffLogline, ValidatedLogline as ValidatedJiffLogline,
+};
@@ -1,9 +1,9 @@
 use crate::{
-    TIME_DATE_FMT, TIME_TIME_FMT,
     borrowed::raw::{
         Logline as RawLogline, UnvalidatedLogline as UnvalidatedRaw,
         ValidatedLogline as ValidatedRaw,
     },
+    borrowed::simple::Logline as SimpleLogline,
     shared::*,
     types::*,
 };
@@ -16,9 +16,9 @@ use crate::{
 /// This is useful if you cannot skip the comment lines or have reason to not trust the input for format correctness.
 /// The latter should be only an issue if you do not use this crate on CloudFront logs directly.
 ///
-/// # Panics
+/// # Errors
 ///
-/// Construction can panic if the input is not a valid log line!
+/// Construction never panics, an invalid log line results in an error instead.
 ///
 /// # Examples
 ///
@@ -50,9 +50,9 @@ pub type ValidatedLogline<'a> = Logline<'a, Validated>;
 /// Note: This is the only variant which can use the `From` trait instead of `TryFrom`,
 /// because validation is skipped and the input data does not need to be parsed into other types.
 ///
-/// # Panics
+/// # Errors
 ///
-/// Construction can panic if the input is not a valid log line!
+/// Construction never panics, an invalid log line results in an error instead.
 ///
 /// # Examples
 ///
@@ -93,7 +93,7 @@ pub struct Logline<'a, V> {
     pub cs_user_agent: &'a str,
     pub cs_uri_query: Option<&'a str>,
     pub cs_cookie: Option<&'a str>,
-    pub x_edge_result_type: EdgeResultType,
+    pub x_edge_result_type: EdgeResultType<'a>,
     pub x_edge_request_id: &'a str,
     pub x_host_header: &'a str,
     pub cs_protocol: CsProtocol,
@@ -102,13 +102,13 @@ pub struct Logline<'a, V> {
     pub x_forwarded_for: Option<ForwardedForAddrs>,
     pub ssl_protocol: Option<SslProtocol>,
     pub ssl_cipher: Option<&'a str>,
-    pub x_edge_response_result_type: EdgeResultType,
+    pub x_edge_response_result_type: EdgeResultType<'a>,
     pub cs_protocol_version: CsProtocolVersion,
     pub fle_status: Option<&'a str>,
     pub fle_encrypted_fields: Option<u64>,
     pub c_port: u16,
     pub time_to_first_byte: Duration,
-    pub x_edge_detailed_result_type: DetailedEdgeResultType,
+    pub x_edge_detailed_result_type: DetailedEdgeResultType<'a>,
     pub sc_content_type: Option<&'a str>,
     pub sc_content_len: Option<u64>,
     pub sc_range_start: Option<i64>,
@@ -116,6 +116,33 @@ pub struct Logline<'a, V> {
     __marker: PhantomData<V>,
 }
 
+impl<V> Logline<'_, V> {
+    /// The request time as UTC datetime
+    ///
+    /// The `datetime` field is already an [`OffsetDateTime`] with UTC offset,
+    /// this returns the dedicated [`UtcDateTime`] type instead.
+    pub fn datetime_utc(&self) -> UtcDateTime {
+        self.datetime.to_utc()
+    }
+
+    /// The request time as seconds since the Unix epoch
+    pub fn unix_timestamp(&self) -> i64 {
+        self.datetime.unix_timestamp()
+    }
+
+    /// The request time as milliseconds since the Unix epoch
+    pub fn unix_timestamp_millis(&self) -> i64 {
+        self.datetime.unix_timestamp() * 1_000 + i64::from(self.datetime.millisecond())
+    }
+
+    /// The request time at the given offset, e.g. for reporting in local time
+    ///
+    /// Returns `None` if the result would be out of the supported date range.
+    pub fn datetime_in(&self, offset: UtcOffset) -> Option<OffsetDateTime> {
+        self.datetime.checked_to_offset(offset)
+    }
+}
+
 impl<'a> TryFrom<&'a str> for Logline<'a, Validated> {
     type Error = &'static str;
 
@@ -136,115 +163,50 @@ impl<'a> TryFrom<&'a str> for Logline<'a, Unvalidated> {
 fn new_log_line<V>(line: &str) -> Result<Logline<'_, V>, &'static str> {
     let mut iter = MemchrTabSplitter::new(line);
 
-    let date = Date::parse(iter.next().unwrap(), TIME_DATE_FMT).map_err(|_e| "date invalid")?;
-    let time = Time::parse(iter.next().unwrap(), TIME_TIME_FMT).map_err(|_e| "time invalid")?;
+    let date = parse_time_date(iter.field()?).map_err(|_e| "date invalid")?;
+    let time = parse_time_time(iter.field()?).map_err(|_e| "time invalid")?;
     let datetime = OffsetDateTime::new_utc(date, time);
 
     let line = Logline {
         date,
         time,
         datetime,
-        x_edge_location: iter.next().unwrap(),
-        sc_bytes: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "sc_bytes invalid")?,
-        c_ip: iter.next().unwrap().parse().map_err(|_e| "c_ip invalid")?,
-        cs_method: iter.next().unwrap(),
-        cs_host: iter.next().unwrap(),
-        cs_uri_stem: iter.next().unwrap(),
-        sc_status: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "sc_status invalid")?,
-        cs_referer: iter.next().unwrap().as_optional_str(),
-        cs_user_agent: iter.next().unwrap(),
-        cs_uri_query: iter.next().unwrap().as_optional_str(),
-        cs_cookie: iter.next().unwrap().as_optional_str(),
-        x_edge_result_type: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "x_edge_result_type invalid")?,
-        x_edge_request_id: iter.next().unwrap(),
-        x_host_header: iter.next().unwrap(),
-        cs_protocol: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "cs_protocol invalid")?,
-        cs_bytes: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "cs_bytes invalid")?,
-        time_taken: iter
-            .next()
-            .unwrap()
-            .parse::<f64>()
-            .map(Duration::from_secs_f64)
-            .map_err(|_e| "time_taken invalid")?,
-        x_forwarded_for: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "x_forwarded_for invalid")?,
-        ssl_protocol: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "ssl_protocol invalid")?,
-        ssl_cipher: iter.next().unwrap().as_optional_str(),
-        x_edge_response_result_type: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "x_edge_response_result_type invalid")?,
+        x_edge_location: iter.field()?,
+        sc_bytes: iter.field()?.parse().map_err(|_e| "sc_bytes invalid")?,
+        c_ip: iter.field()?.parse().map_err(|_e| "c_ip invalid")?,
+        cs_method: iter.field()?,
+        cs_host: iter.field()?,
+        cs_uri_stem: iter.field()?,
+        sc_status: iter.field()?.parse().map_err(|_e| "sc_status invalid")?,
+        cs_referer: iter.field()?.as_optional_str(),
+        cs_user_agent: iter.field()?,
+        cs_uri_query: iter.field()?.as_optional_str(),
+        cs_cookie: iter.field()?.as_optional_str(),
+        x_edge_result_type: iter.field()?.into(),
+        x_edge_request_id: iter.field()?,
+        x_host_header: iter.field()?,
+        cs_protocol: iter.field()?.parse().map_err(|_e| "cs_protocol invalid")?,
+        cs_bytes: iter.field()?.parse().map_err(|_e| "cs_bytes invalid")?,
+        time_taken: parse_duration(iter.field()?).map_err(|_e| "time_taken invalid")?,
+        x_forwarded_for: parse_as_option(iter.field()?).map_err(|_e| "x_forwarded_for invalid")?,
+        ssl_protocol: parse_as_option(iter.field()?).map_err(|_e| "ssl_protocol invalid")?,
+        ssl_cipher: iter.field()?.as_optional_str(),
+        x_edge_response_result_type: iter.field()?.into(),
         cs_protocol_version: iter
-            .next()
-            .unwrap()
+            .field()?
             .parse()
             .map_err(|_e| "cs_protocol_version invalid")?,
-        fle_status: iter.next().unwrap().as_optional_str(),
-        fle_encrypted_fields: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
+        fle_status: iter.field()?.as_optional_str(),
+        fle_encrypted_fields: parse_as_option(iter.field()?)
             .map_err(|_e| "fle_encrypted_fields invalid")?,
-        c_port: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "c_port invalid")?,
-        time_to_first_byte: iter
-            .next()
-            .unwrap()
-            .parse::<f64>()
-            .map(Duration::from_secs_f64)
+        c_port: iter.field()?.parse().map_err(|_e| "c_port invalid")?,
+        time_to_first_byte: parse_duration(iter.field()?)
             .map_err(|_e| "time_to_first_byte invalid")?,
-        x_edge_detailed_result_type: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "x_edge_detailed_result_type invalid")?,
-        sc_content_type: iter.next().unwrap().as_optional_str(),
-        sc_content_len: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "sc_content_len invalid")?,
-        sc_range_start: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "sc_range_start invalid")?,
-        sc_range_end: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "sc_range_end invalid")?,
+        x_edge_detailed_result_type: iter.field()?.into(),
+        sc_content_type: iter.field()?.as_optional_str(),
+        sc_content_len: parse_as_option(iter.field()?).map_err(|_e| "sc_content_len invalid")?,
+        sc_range_start: parse_as_option(iter.field()?).map_err(|_e| "sc_range_start invalid")?,
+        sc_range_end: parse_as_option(iter.field()?).map_err(|_e| "sc_range_end invalid")?,
         __marker: PhantomData,
     };
     Ok(line)
@@ -267,8 +229,8 @@ impl<'a> TryFrom<UnvalidatedRaw<'a>> for Logline<'a, Unvalidated> {
 }
 
 fn try_from_v<V>(raw: RawLogline<'_, V>) -> Result<Logline<'_, V>, &'static str> {
-    let date = Date::parse(raw.date, TIME_DATE_FMT).map_err(|_e| "date invalid")?;
-    let time = Time::parse(raw.time, TIME_TIME_FMT).map_err(|_e| "time invalid")?;
+    let date = parse_time_date(raw.date).map_err(|_e| "date invalid")?;
+    let time = parse_time_time(raw.time).map_err(|_e| "time invalid")?;
     let datetime = OffsetDateTime::new_utc(date, time);
 
     let line = Logline {
@@ -286,10 +248,7 @@ fn try_from_v<V>(raw: RawLogline<'_, V>) -> Result<Logline<'_, V>, &'static str>
         cs_user_agent: raw.cs_user_agent,
         cs_uri_query: raw.cs_uri_query.as_optional_str(),
         cs_cookie: raw.cs_cookie.as_optional_str(),
-        x_edge_result_type: raw
-            .x_edge_result_type
-            .parse()
-            .map_err(|_e| "x_edge_result_type invalid")?,
+        x_edge_result_type: raw.x_edge_result_type.into(),
         x_edge_request_id: raw.x_edge_request_id,
         x_host_header: raw.x_host_header,
         cs_protocol: raw
@@ -297,19 +256,12 @@ fn try_from_v<V>(raw: RawLogline<'_, V>) -> Result<Logline<'_, V>, &'static str>
             .parse()
             .map_err(|_e| "cs_protocol invalid")?,
         cs_bytes: raw.cs_bytes.parse().map_err(|_e| "cs_bytes invalid")?,
-        time_taken: raw
-            .time_taken
-            .parse::<f64>()
-            .map(Duration::from_secs_f64)
-            .map_err(|_e| "time_taken invalid")?,
+        time_taken: parse_duration(raw.time_taken).map_err(|_e| "time_taken invalid")?,
         x_forwarded_for: parse_as_option(raw.x_forwarded_for)
             .map_err(|_e| "x_forwarded_for invalid")?,
         ssl_protocol: parse_as_option(raw.ssl_protocol).map_err(|_e| "ssl_protocol invalid")?,
         ssl_cipher: raw.ssl_cipher.as_optional_str(),
-        x_edge_response_result_type: raw
-            .x_edge_response_result_type
-            .parse()
-            .map_err(|_e| "x_edge_response_result_type invalid")?,
+        x_edge_response_result_type: raw.x_edge_response_result_type.into(),
         cs_protocol_version: raw
             .cs_protocol_version
             .parse()
@@ -318,15 +270,9 @@ fn try_from_v<V>(raw: RawLogline<'_, V>) -> Result<Logline<'_, V>, &'static str>
         fle_encrypted_fields: parse_as_option(raw.fle_encrypted_fields)
             .map_err(|_e| "fle_encrypted_fields invalid")?,
         c_port: raw.c_port.parse().map_err(|_e| "c_port invalid")?,
-        time_to_first_byte: raw
-            .time_to_first_byte
-            .parse::<f64>()
-            .map(Duration::from_secs_f64)
+        time_to_first_byte: parse_duration(raw.time_to_first_byte)
             .map_err(|_e| "time_to_first_byte invalid")?,
-        x_edge_detailed_result_type: raw
-            .x_edge_detailed_result_type
-            .parse()
-            .map_err(|_e| "x_edge_detailed_result_type invalid")?,
+        x_edge_detailed_result_type: raw.x_edge_detailed_result_type.into(),
         sc_content_type: raw.sc_content_type.as_optional_str(),
         sc_content_len: parse_as_option(raw.sc_content_len)
             .map_err(|_e| "sc_content_len invalid")?,
@@ -338,6 +284,88 @@ fn try_from_v<V>(raw: RawLogline<'_, V>) -> Result<Logline<'_, V>, &'static str>
     Ok(line)
 }
 
+impl<'a> Logline<'a, Validated> {
+    /// Parses the line, falling back to placeholders for fields which cannot be parsed
+    ///
+    /// Unlike [`TryFrom`], a single corrupted field does not discard the whole line:
+    /// the returned [`Lenient`] holds the logline and the list of fields which could not be parsed.
+    /// An unparseable date or time is replaced by the Unix epoch, see [`Lenient`] for the other placeholders.
+    /// The line itself still has to be valid, e.g. have the right number of fields.
+    pub fn parse_lenient(line: &'a str) -> Result<Lenient<Self>, &'static str> {
+        SimpleLogline::<Validated>::parse_lenient(line).map(from_lenient_simple)
+    }
+}
+
+impl<'a> Logline<'a, Unvalidated> {
+    /// Parses the line, falling back to placeholders for fields which cannot be parsed
+    ///
+    /// See [`ValidatedLogline::parse_lenient`] for details.
+    pub fn parse_lenient(line: &'a str) -> Result<Lenient<Self>, &'static str> {
+        SimpleLogline::<Unvalidated>::parse_lenient(line).map(from_lenient_simple)
+    }
+}
+
+fn from_lenient_simple<V>(simple: Lenient<SimpleLogline<'_, V>>) -> Lenient<Logline<'_, V>> {
+    let Lenient {
+        logline: simple,
+        errors: simple_errors,
+    } = simple;
+    // keep the errors in field order, date and time come first
+    let mut errors = FieldErrors::default();
+    let date = errors.parse(
+        Field::Date,
+        simple.date,
+        parse_time_date,
+        OffsetDateTime::UNIX_EPOCH.date(),
+    );
+    let time = errors.parse(Field::Time, simple.time, parse_time_time, Time::MIDNIGHT);
+    errors.0.extend(simple_errors);
+    errors.finish(from_simple(simple, date, time))
+}
+
+// moves the fields of the simple line, with its date and time already parsed
+fn from_simple<V>(simple: SimpleLogline<'_, V>, date: Date, time: Time) -> Logline<'_, V> {
+    let datetime = OffsetDateTime::new_utc(date, time);
+
+    Logline {
+        date,
+        time,
+        datetime,
+        x_edge_location: simple.x_edge_location,
+        sc_bytes: simple.sc_bytes,
+        c_ip: simple.c_ip,
+        cs_method: simple.cs_method,
+        cs_host: simple.cs_host,
+        cs_uri_stem: simple.cs_uri_stem,
+        sc_status: simple.sc_status,
+        cs_referer: simple.cs_referer,
+        cs_user_agent: simple.cs_user_agent,
+        cs_uri_query: simple.cs_uri_query,
+        cs_cookie: simple.cs_cookie,
+        x_edge_result_type: simple.x_edge_result_type,
+        x_edge_request_id: simple.x_edge_request_id,
+        x_host_header: simple.x_host_header,
+        cs_protocol: simple.cs_protocol,
+        cs_bytes: simple.cs_bytes,
+        time_taken: simple.time_taken,
+        x_forwarded_for: simple.x_forwarded_for,
+        ssl_protocol: simple.ssl_protocol,
+        ssl_cipher: simple.ssl_cipher,
+        x_edge_response_result_type: simple.x_edge_response_result_type,
+        cs_protocol_version: simple.cs_protocol_version,
+        fle_status: simple.fle_status,
+        fle_encrypted_fields: simple.fle_encrypted_fields,
+        c_port: simple.c_port,
+        time_to_first_byte: simple.time_to_first_byte,
+        x_edge_detailed_result_type: simple.x_edge_detailed_result_type,
+        sc_content_type: simple.sc_content_type,
+        sc_content_len: simple.sc_content_len,
+        sc_range_start: simple.sc_range_start,
+        sc_range_end: simple.sc_range_end,
+        __marker: PhantomData,
+    }
+}
+
 impl<'a> From<Logline<'a, Validated>> for Logline<'a, Unvalidated> {
     fn from(validated: Logline<'a, Validated>) -> Self {
         Logline {
@@ -421,3 +449,60 @@ impl<'a> From<Logline<'a, Unvalidated>> for Logline<'a, Validated> {
         }
     }
 }
+
+impl<V> Logline<'_, V> {
+    /// Converts the line into the [owned](crate::owned::typed::time) variant, keeping the validation marker
+    pub fn into_owned(self) -> crate::owned::typed::time::Logline<V> {
+        self.into()
+    }
+
+    /// Copies the line into the [owned](crate::owned::typed::time) variant, keeping the validation marker
+    pub fn to_owned_logline(&self) -> crate::owned::typed::time::Logline<V>
+    where
+        Self: Clone,
+    {
+        self.clone().into()
+    }
+}
+
+impl<'a, V> From<&'a crate::owned::typed::time::Logline<V>> for Logline<'a, V> {
+    fn from(owned: &'a crate::owned::typed::time::Logline<V>) -> Self {
+        Logline {
+            date: owned.date,
+            time: owned.time,
+            datetime: owned.datetime,
+            x_edge_location: owned.x_edge_location.as_str(),
+            sc_bytes: owned.sc_bytes,
+            c_ip: owned.c_ip,
+            cs_method: owned.cs_method.as_str(),
+            cs_host: owned.cs_host.as_str(),
+            cs_uri_stem: owned.cs_uri_stem.as_str(),
+            sc_status: owned.sc_status,
+            cs_referer: owned.cs_referer.as_deref(),
+            cs_user_agent: owned.cs_user_agent.as_str(),
+            cs_uri_query: owned.cs_uri_query.as_deref(),
+            cs_cookie: owned.cs_cookie.as_deref(),
+            x_edge_result_type: owned.x_edge_result_type.as_borrowed(),
+            x_edge_request_id: owned.x_edge_request_id.as_str(),
+            x_host_header: owned.x_host_header.as_str(),
+            cs_protocol: owned.cs_protocol,
+            cs_bytes: owned.cs_bytes,
+            time_taken: owned.time_taken,
+            x_forwarded_for: owned.x_forwarded_for.clone(),
+            ssl_protocol: owned.ssl_protocol,
+            ssl_cipher: owned.ssl_cipher.as_deref(),
+            x_edge_response_result_type: owned.x_edge_response_result_type.as_borrowed(),
+            cs_protocol_version: owned.cs_protocol_version,
+            fle_status: owned.fle_status.as_deref(),
+            fle_encrypted_fields: owned.fle_encrypted_fields,
+            c_port: owned.c_port,
+            time_to_first_byte: owned.time_to_first_byte,
+            x_edge_detailed_result_type: owned.x_edge_detailed_result_type.as_borrowed(),
+            sc_content_type: owned.sc_content_type.as_deref(),
+            sc_content_len: owned.sc_content_len,
+            sc_range_start: owned.sc_range_start,
+            sc_range_end: owned.sc_range_end,
+            __marker: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{COMMENT_U8, FIELDS, TAB_U8, TABS, shared::*, types::*};
+
+/// Lazy view into a borrowed log line
+///
+/// On construction the line is validated and the start offsets of all fields are recorded in a single pass;
+/// nothing else is parsed upfront.
+/// Any field can then be accessed in O(1), either by its name, by [`Field`] or by its index.
+///
+/// The typed getters (like [`sc_status_u16`](LoglineView::sc_status_u16)) parse the field on every call,
+/// so this view is a good fit for sparse access patterns, where only a few fields of each line are needed.
+/// If you need most of the fields, prefer one of the fully parsed variants like
+/// [`ValidatedSimpleLogline`](crate::borrowed::ValidatedSimpleLogline) instead.
+///
+/// This is the successor of the deprecated [`SmartRawLogLineView`](crate::SmartRawLogLineView).
+///
+/// # Examples
+///
+/// ```rust
+/// use cloudfront_logs::{borrowed::view::LoglineView, types::*};
+///
+/// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let view = LoglineView::try_from(line).unwrap();
+///
+/// assert_eq!(view.sc_range_end(), "-");
+/// assert_eq!(view.date(), "2019-12-04");
+/// assert_eq!(view.get(Field::CsHost), "d111111abcdef8.cloudfront.net");
+/// assert_eq!(view.get_by_index(8), Some("200"));
+/// assert_eq!(view.sc_status_u16(), Ok(200));
+/// assert_eq!(view.x_edge_result_type_enum(), Ok(EdgeResultType::Hit));
+/// ```
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoglineView<'a> {
+    line: &'a str,
+    offsets: [u32; FIELDS],
+}
+
+impl<'a> TryFrom<&'a str> for LoglineView<'a> {
+    type Error = &'static str;
+
+    fn try_from(line: &'a str) -> Result<Self, Self::Error> {
+        let bytes = line.as_bytes();
+        if bytes.is_empty() {
+            return Err("Invalid log line (empty)");
+        }
+        if bytes.first() == Some(&COMMENT_U8) {
+            return Err("Invalid log line (comment)");
+        }
+        if u32::try_from(bytes.len()).is_err() {
+            return Err("Invalid log line (too long)");
+        }
+
+        // offsets[0] is always 0, the start of the first field
+        let mut offsets = [0u32; FIELDS];
+        let mut tabs = 0;
+        for tab_idx in memchr::memchr_iter(TAB_U8, bytes) {
+            tabs += 1;
+            let offset = offsets
+                .get_mut(tabs)
+                .ok_or("Invalid log line (field count)")?;
+            // cannot truncate, the line length was checked above
+            *offset = (tab_idx + 1) as u32;
+        }
+        if tabs != TABS {
+            return Err("Invalid log line (field count)");
+        }
+
+        Ok(Self { line, offsets })
+    }
+}
+
+macro_rules! field_getters {
+    ($($name:ident => $field:ident),* $(,)?) => {
+        $(
+            #[inline]
+            pub fn $name(&self) -> &'a str {
+                self.get(Field::$field)
+            }
+        )*
+    };
+}
+
+impl<'a> LoglineView<'a> {
+    /// Returns the full, original log line
+    pub fn as_raw(&self) -> &'a str {
+        self.line
+    }
+
+    /// Returns the field by its (zero-based) index, or `None` if the index is out of bounds
+    #[inline]
+    pub fn get_by_index(&self, index: usize) -> Option<&'a str> {
+        let start = *self.offsets.get(index)? as usize;
+        let end = match self.offsets.get(index + 1) {
+            // skip the tab stop in front of the next field
+            Some(next) => *next as usize - 1,
+            None => self.line.len(),
+        };
+        self.line.get(start..end)
+    }
+
+    /// Returns the field
+    #[inline]
+    pub fn get(&self, field: Field) -> &'a str {
+        self.get_by_index(field.index()).unwrap_or_default()
+    }
+
+    field_getters!(
+        date => Date,
+        time => Time,
+        x_edge_location => XEdgeLocation,
+        sc_bytes => ScBytes,
+        c_ip => CIp,
+        cs_method => CsMethod,
+        cs_host => CsHost,
+        cs_uri_stem => CsUriStem,
+        sc_status => ScStatus,
+        cs_referer => CsReferer,
+        cs_user_agent => CsUserAgent,
+        cs_uri_query => CsUriQuery,
+        cs_cookie => CsCookie,
+        x_edge_result_type => XEdgeResultType,
+        x_edge_request_id => XEdgeRequestId,
+        x_host_header => XHostHeader,
+        cs_protocol => CsProtocol,
+        cs_bytes => CsBytes,
+        time_taken => TimeTaken,
+        x_forwarded_for => XForwardedFor,
+        ssl_protocol => SslProtocol,
+        ssl_cipher => SslCipher,
+        x_edge_response_result_type => XEdgeResponseResultType,
+        cs_protocol_version => CsProtocolVersion,
+        fle_status => FleStatus,
+        fle_encrypted_fields => FleEncryptedFields,
+        c_port => CPort,
+        time_to_first_byte => TimeToFirstByte,
+        x_edge_detailed_result_type => XEdgeDetailedResultType,
+        sc_content_type => ScContentType,
+        sc_content_len => ScContentLen,
+        sc_range_start => ScRangeStart,
+        sc_range_end => ScRangeEnd,
+    );
+
+    // === typed getters ===
+
+    #[cfg(feature = "chrono")]
+    pub fn datetime(&self) -> Result<NaiveDateTime, &'static str> {
+        let date = parse_chrono_date(self.date()).map_err(|_e| "date invalid")?;
+        let time = parse_chrono_time(self.time()).map_err(|_e| "time invalid")?;
+        Ok(NaiveDateTime::new(date, time))
+    }
+
+    #[cfg(feature = "time")]
+    pub fn offset_datetime(&self) -> Result<OffsetDateTime, &'static str> {
+        let date = parse_time_date(self.date()).map_err(|_e| "date invalid")?;
+        let time = parse_time_time(self.time()).map_err(|_e| "time invalid")?;
+        Ok(OffsetDateTime::new_utc(date, time))
+    }
+
+    pub fn sc_bytes_u64(&self) -> Result<u64, &'static str> {
+        self.sc_bytes().parse().map_err(|_e| "sc_bytes invalid")
+    }
+
+    pub fn c_ip_addr(&self) -> Result<IpAddr, &'static str> {
+        self.c_ip().parse().map_err(|_e| "c_ip invalid")
+    }
+
+    pub fn sc_status_u16(&self) -> Result<u16, &'static str> {
+        self.sc_status().parse().map_err(|_e| "sc_status invalid")
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn x_edge_result_type_enum(&self) -> Result<EdgeResultType<'a>, &'static str> {
+        Ok(EdgeResultType::from(self.x_edge_result_type()))
+    }
+
+    pub fn cs_protocol_enum(&self) -> Result<CsProtocol, &'static str> {
+        self.cs_protocol()
+            .parse()
+            .map_err(|_e| "cs_protocol invalid")
+    }
+
+    pub fn cs_bytes_u64(&self) -> Result<u64, &'static str> {
+        self.cs_bytes().parse().map_err(|_e| "cs_bytes invalid")
+    }
+
+    pub fn time_taken_duration(&self) -> Result<Duration, &'static str> {
+        parse_duration(self.time_taken()).map_err(|_e| "time_taken invalid")
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn x_forwarded_for_addrs(&self) -> Result<Option<ForwardedForAddrs>, &'static str> {
+        parse_as_option(self.x_forwarded_for()).map_err(|_e| "x_forwarded_for invalid")
+    }
+
+    pub fn ssl_protocol_enum(&self) -> Result<Option<SslProtocol>, &'static str> {
+        parse_as_option(self.ssl_protocol()).map_err(|_e| "ssl_protocol invalid")
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn x_edge_response_result_type_enum(&self) -> Result<EdgeResultType<'a>, &'static str> {
+        Ok(EdgeResultType::from(self.x_edge_response_result_type()))
+    }
+
+    pub fn cs_protocol_version_enum(&self) -> Result<CsProtocolVersion, &'static str> {
+        self.cs_protocol_version()
+            .parse()
+            .map_err(|_e| "cs_protocol_version invalid")
+    }
+
+    pub fn fle_encrypted_fields_u64(&self) -> Result<Option<u64>, &'static str> {
+        parse_as_option(self.fle_encrypted_fields()).map_err(|_e| "fle_encrypted_fields invalid")
+    }
+
+    pub fn c_port_u16(&self) -> Result<u16, &'static str> {
+        self.c_port().parse().map_err(|_e| "c_port invalid")
+    }
+
+    pub fn time_to_first_byte_duration(&self) -> Result<Duration, &'static str> {
+        parse_duration(self.time_to_first_byte()).map_err(|_e| "time_to_first_byte invalid")
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn x_edge_detailed_result_type_enum(
+        &self,
+    ) -> Result<DetailedEdgeResultType<'a>, &'static str> {
+        Ok(DetailedEdgeResultType::from(
+            self.x_edge_detailed_result_type(),
+        ))
+    }
+
+    pub fn sc_content_len_u64(&self) -> Result<Option<u64>, &'static str> {
+        parse_as_option(self.sc_content_len()).map_err(|_e| "sc_content_len invalid")
+    }
+
+    pub fn sc_range_start_i64(&self) -> Result<Option<i64>, &'static str> {
+        parse_as_option(self.sc_range_start()).map_err(|_e| "sc_range_start invalid")
+    }
+
+    pub fn sc_range_end_i64(&self) -> Result<Option<i64>, &'static str> {
+        parse_as_option(self.sc_range_end()).map_err(|_e| "sc_range_end invalid")
+    }
+}
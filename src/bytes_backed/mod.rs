@@ -0,0 +1,127 @@
+//! Line owning variants of the parser types which are backed by [`bytes::Bytes`] buffers
+//!
+//! Like the [`chunked`](crate::chunked) variants, many loglines can share one buffer:
+//! [`lines`] slices the buffer without copying any data, each line only increments its reference count.
+//! This avoids the copy into a [`String`](alloc::string::String) or [`Arc<str>`](alloc::sync::Arc)
+//! when the input already arrives as [`Bytes`], e.g. from a network stack.
+//!
+//! Each line is checked for valid UTF-8 exactly once, when the logline is constructed;
+//! its borrowed view and [`as_raw`](raw::Logline::as_raw) refer to the checked string afterwards.
+//!
+//! The loglines are `Send + Sync + 'static` and clonable.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bytes::Bytes;
+//! use cloudfront_logs::bytes_backed::{self, ValidatedSimpleLogline};
+//!
+//! let input = Bytes::from_static(b"#Version: 1.0
+//! 2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-
+//! ");
+//!
+//! let lines = bytes_backed::parse_buffer::<ValidatedSimpleLogline>(input.clone())
+//!     .collect::<Result<Vec<_>, _>>()
+//!     .unwrap();
+//!
+//! assert_eq!(lines.len(), 1);
+//! assert_eq!(lines[0].view().sc_bytes, 392);
+//! // the line is a slice of the input buffer, not a copy
+//! assert_eq!(lines[0].as_bytes().as_ptr(), input[14..].as_ptr());
+//! ```
+
+use crate::COMMENT_U8;
+use bytes::Bytes;
+
+pub mod raw;
+pub mod simple;
+pub mod typed;
+
+#[cfg(feature = "parquet")]
+pub mod parquet;
+
+pub use raw::{
+    UnvalidatedLogline as UnvalidatedRawLogline, ValidatedLogline as ValidatedRawLogline,
+};
+
+pub use simple::{
+    UnvalidatedLogline as UnvalidatedSimpleLogline, ValidatedLogline as ValidatedSimpleLogline,
+};
+
+#[cfg(feature = "chrono")]
+pub use typed::chrono::{
+    UnvalidatedLogline as UnvalidatedChronoLogline, ValidatedLogline as ValidatedChronoLogline,
+};
+
+#[cfg(feature = "time")]
+pub use typed::time::{
+    UnvalidatedLogline as UnvalidatedTimeLogline, ValidatedLogline as ValidatedTimeLogline,
+};
+
+#[cfg(feature = "jiff")]
+pub use typed::jiff::{
+    UnvalidatedLogline as UnvalidatedJiffLogline, ValidatedLogline as ValidatedJiffLogline,
+};
+
+#[cfg(feature = "parquet")]
+pub use parquet::{
+    UnvalidatedLogline as UnvalidatedParquetLogline, ValidatedLogline as ValidatedParquetLogline,
+};
+
+#[inline]
+pub(crate) fn utf8(bytes: &Bytes) -> Result<&str, &'static str> {
+    core::str::from_utf8(bytes).map_err(|_e| "Invalid log line (not valid UTF-8)")
+}
+
+/// Iterator over the lines of a buffer, see [`lines`]
+#[derive(Debug, Clone)]
+pub struct Lines {
+    buffer: Bytes,
+    pos: usize,
+}
+
+impl Iterator for Lines {
+    type Item = Bytes;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let rest = self
+                .buffer
+                .get(self.pos..)
+                .filter(|rest| !rest.is_empty())?;
+            let start = self.pos;
+            let (mut len, consumed) = match memchr::memchr(b'\n', rest) {
+                Some(idx) => (idx, idx + 1),
+                None => (rest.len(), rest.len()),
+            };
+            self.pos += consumed;
+
+            if len > 0 && rest.get(len - 1) == Some(&b'\r') {
+                len -= 1;
+            }
+            if len == 0 || rest.first() == Some(&COMMENT_U8) {
+                continue;
+            }
+            return Some(self.buffer.slice(start..start + len));
+        }
+    }
+}
+
+/// Splits the buffer into lines without copying them
+///
+/// Lines are terminated by `\n` or `\r\n`.
+/// Empty lines and comment lines (like the `#Version` and `#Fields` headers) are skipped.
+/// The lines are not checked for valid UTF-8 yet, this happens when they are parsed.
+pub fn lines(buffer: Bytes) -> Lines {
+    Lines { buffer, pos: 0 }
+}
+
+/// Splits the buffer into lines and parses each into the target logline type
+///
+/// See [`lines`] for which lines are yielded.
+pub fn parse_buffer<T>(buffer: Bytes) -> impl Iterator<Item = Result<T, T::Error>>
+where
+    T: TryFrom<Bytes>,
+{
+    lines(buffer).map(T::try_from)
+}
@@ -0,0 +1,100 @@
+use crate::{
+    borrowed::UnvalidatedRawLogline as BorrowedLine, bytes_backed::utf8, shared::validate_bytes,
+    types::*,
+};
+use bytes::Bytes;
+
+// the line and its borrowed view
+type Dependent<'a> = (&'a str, BorrowedLine<'a>);
+
+self_cell::self_cell!(
+    struct Container {
+        owner: Bytes,
+
+        #[covariant]
+        dependent: Dependent,
+    }
+
+    impl {Debug, PartialEq}
+);
+
+impl Clone for Container {
+    fn clone(&self) -> Self {
+        Self::try_new(self.borrow_owner().clone(), parse)
+            .expect("an already parsed line cannot fail to parse")
+    }
+}
+
+// the only UTF-8 check of the line, the view borrows the checked string
+fn parse(bytes: &Bytes) -> Result<Dependent<'_>, &'static str> {
+    let line = utf8(bytes)?;
+    Ok((line, BorrowedLine::from(line)))
+}
+
+pub type UnvalidatedLogline = Logline<Unvalidated>;
+pub type ValidatedLogline = Logline<Validated>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logline<V> {
+    inner: Container,
+    _marker: PhantomData<V>,
+}
+
+impl<V> Logline<V> {
+    pub fn view(&self) -> &BorrowedLine<'_> {
+        &self.inner.borrow_dependent().1
+    }
+
+    pub fn as_raw(&self) -> &str {
+        self.inner.borrow_dependent().0
+    }
+
+    /// The line as slice of the original buffer
+    pub fn as_bytes(&self) -> &Bytes {
+        self.inner.borrow_owner()
+    }
+
+    pub fn into_bytes(self) -> Bytes {
+        self.inner.into_owner()
+    }
+
+    /// Copies the fields into the [owned](crate::owned::raw) variant, keeping the validation marker
+    pub fn to_owned_logline(&self) -> crate::owned::raw::Logline<V> {
+        crate::owned::raw::from_borrowed(*self.view())
+    }
+
+    /// Converts the line into the [owned](crate::owned::raw) variant, keeping the validation marker
+    ///
+    /// The reference to the buffer is dropped afterwards.
+    pub fn into_owned(self) -> crate::owned::raw::Logline<V> {
+        self.to_owned_logline()
+    }
+
+    /// Returns the field, see [`BorrowedLine::get`]
+    pub fn get(&self, field: Field) -> &str {
+        self.view().get(field)
+    }
+}
+
+impl TryFrom<Bytes> for ValidatedLogline {
+    type Error = &'static str;
+
+    fn try_from(line: Bytes) -> Result<Self, Self::Error> {
+        validate_bytes(&line)?;
+        Ok(ValidatedLogline {
+            inner: Container::try_new(line, parse)?,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl TryFrom<Bytes> for UnvalidatedLogline {
+    type Error = &'static str;
+
+    fn try_from(line: Bytes) -> Result<Self, Self::Error> {
+        Ok(UnvalidatedLogline {
+            inner: Container::try_new(line, parse)?,
+            _marker: PhantomData,
+        })
+    }
+}
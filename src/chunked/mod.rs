@@ -0,0 +1,185 @@
+//! Line owning variants of the parser types which share one buffer between many lines
+//!
+//! The [`referential`](crate::referential) variants allocate one [`Arc<str>`] per line.
+//! When a whole (decompressed) log file is already in memory, this is not necessary:
+//! the loglines of this module keep a reference counted handle to the common [`Chunk`]
+//! and the byte range of their line instead, so splitting a chunk into lines does not copy any data.
+//!
+//! Like their referential counterparts, the loglines are `Send + Sync + 'static`, clonable,
+//! and offer a borrowed view into the parsed line.
+//! Note that the chunk is only freed when the last line referring to it is dropped.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cloudfront_logs::chunked::{self, ValidatedSimpleLogline};
+//!
+//! let input = "#Version: 1.0
+//! 2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-
+//! 2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-
+//! ";
+//!
+//! let lines = chunked::parse_chunk::<ValidatedSimpleLogline>(input)
+//!     .collect::<Result<Vec<_>, _>>()
+//!     .unwrap();
+//!
+//! assert_eq!(lines.len(), 2);
+//! assert_eq!(lines[0].view().sc_bytes, 392);
+//! // both lines refer to the same buffer
+//! assert!(std::sync::Arc::ptr_eq(lines[0].chunk(), lines[1].chunk()));
+//! ```
+
+use crate::{COMMENT_U8, types::*};
+use core::fmt;
+
+pub mod raw;
+pub mod simple;
+pub mod typed;
+
+#[cfg(feature = "parquet")]
+pub mod parquet;
+
+pub use raw::{
+    UnvalidatedLogline as UnvalidatedRawLogline, ValidatedLogline as ValidatedRawLogline,
+};
+
+pub use simple::{
+    UnvalidatedLogline as UnvalidatedSimpleLogline, ValidatedLogline as ValidatedSimpleLogline,
+};
+
+#[cfg(feature = "chrono")]
+pub use typed::chrono::{
+    UnvalidatedLogline as UnvalidatedChronoLogline, ValidatedLogline as ValidatedChronoLogline,
+};
+
+#[cfg(feature = "time")]
+pub use typed::time::{
+    UnvalidatedLogline as UnvalidatedTimeLogline, ValidatedLogline as ValidatedTimeLogline,
+};
+
+#[cfg(feature = "jiff")]
+pub use typed::jiff::{
+    UnvalidatedLogline as UnvalidatedJiffLogline, ValidatedLogline as ValidatedJiffLogline,
+};
+
+#[cfg(feature = "parquet")]
+pub use parquet::{
+    UnvalidatedLogline as UnvalidatedParquetLogline, ValidatedLogline as ValidatedParquetLogline,
+};
+
+/// A (thread safe) buffer of many log lines, usually a whole log file
+pub type Chunk = Arc<str>;
+
+/// A single line of a shared [`Chunk`]
+///
+/// Cloning only increments the reference count of the chunk.
+#[derive(Clone)]
+pub struct LineRef {
+    chunk: Chunk,
+    start: usize,
+    end: usize,
+}
+
+impl LineRef {
+    /// Refers to the given byte range of the chunk
+    ///
+    /// Fails if the range is out of bounds or does not lie on char boundaries.
+    pub fn new(chunk: Chunk, range: core::ops::Range<usize>) -> Result<Self, &'static str> {
+        if chunk.get(range.clone()).is_none() {
+            return Err("invalid line range");
+        }
+        Ok(Self {
+            chunk,
+            start: range.start,
+            end: range.end,
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        // the range was checked on construction
+        self.chunk.get(self.start..self.end).unwrap_or_default()
+    }
+
+    pub fn chunk(&self) -> &Chunk {
+        &self.chunk
+    }
+
+    pub fn range(&self) -> core::ops::Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl AsRef<str> for LineRef {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+// only the line itself is of interest, not the whole chunk
+impl fmt::Debug for LineRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LineRef").field(&self.as_str()).finish()
+    }
+}
+
+impl PartialEq for LineRef {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for LineRef {}
+
+/// Iterator over the lines of a chunk, see [`lines`]
+#[derive(Debug, Clone)]
+pub struct Lines {
+    chunk: Chunk,
+    pos: usize,
+}
+
+impl Iterator for Lines {
+    type Item = LineRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let rest = self.chunk.get(self.pos..).filter(|rest| !rest.is_empty())?;
+            let start = self.pos;
+            let (line, consumed) = match memchr::memchr(b'\n', rest.as_bytes()) {
+                Some(idx) => (rest.get(..idx).unwrap_or_default(), idx + 1),
+                None => (rest, rest.len()),
+            };
+            self.pos += consumed;
+
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if line.is_empty() || line.as_bytes().first() == Some(&COMMENT_U8) {
+                continue;
+            }
+            return Some(LineRef {
+                chunk: Arc::clone(&self.chunk),
+                start,
+                end: start + line.len(),
+            });
+        }
+    }
+}
+
+/// Splits the chunk into lines without copying them
+///
+/// Like [`str::lines`], lines are terminated by `\n` or `\r\n`.
+/// Empty lines and comment lines (like the `#Version` and `#Fields` headers) are skipped.
+pub fn lines(chunk: impl Into<Chunk>) -> Lines {
+    Lines {
+        chunk: chunk.into(),
+        pos: 0,
+    }
+}
+
+/// Splits the chunk into lines and parses each into the target logline type
+///
+/// See [`lines`] for which lines are yielded.
+pub fn parse_chunk<T>(chunk: impl Into<Chunk>) -> impl Iterator<Item = Result<T, T::Error>>
+where
+    T: TryFrom<LineRef>,
+{
+    lines(chunk).map(T::try_from)
+}
@@ -0,0 +1,118 @@
+use crate::{
+    borrowed::UnvalidatedParquetLogline as BorrowedLine,
+    chunked::{Chunk, LineRef},
+    shared::validate_line,
+    types::*,
+};
+
+pub use crate::types::{Datelike, Timelike};
+
+self_cell::self_cell!(
+    struct Container {
+        owner: LineRef,
+
+        #[covariant]
+        dependent: BorrowedLine,
+    }
+
+    impl {Debug, PartialEq}
+);
+
+impl Clone for Container {
+    fn clone(&self) -> Self {
+        let input = self.borrow_owner().clone();
+        Self::new(input, |line| {
+            BorrowedLine::try_from(line.as_str())
+                .expect("an already parsed line cannot fail to parse")
+        })
+    }
+}
+
+pub type UnvalidatedLogline = Logline<Unvalidated>;
+pub type ValidatedLogline = Logline<Validated>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logline<V> {
+    inner: Container,
+    _marker: PhantomData<V>,
+}
+
+impl<V> Logline<V> {
+    pub fn view(&self) -> &BorrowedLine<'_> {
+        self.inner.borrow_dependent()
+    }
+
+    pub fn as_raw(&self) -> &str {
+        self.inner.borrow_owner().as_str()
+    }
+
+    /// The shared buffer the line is part of
+    pub fn chunk(&self) -> &Chunk {
+        self.inner.borrow_owner().chunk()
+    }
+
+    pub fn into_line(self) -> LineRef {
+        self.inner.into_owner()
+    }
+
+    pub fn schema() -> &'static str {
+        crate::consts::parquet_schemata::V1
+    }
+
+    pub fn schema_as_type() -> parquet::schema::types::Type {
+        parquet::schema::parser::parse_message_type(crate::consts::parquet_schemata::V1).unwrap()
+    }
+}
+
+impl Logline<Validated> {
+    /// Copies the fields into the [owned](crate::owned::parquet) variant
+    pub fn to_owned_logline(&self) -> crate::owned::parquet::ValidatedLogline {
+        crate::owned::parquet::UnvalidatedLogline::from(self.view().clone()).into()
+    }
+
+    /// Converts the line into the [owned](crate::owned::parquet) variant
+    ///
+    /// The reference to the chunk is dropped afterwards.
+    pub fn into_owned(self) -> crate::owned::parquet::ValidatedLogline {
+        self.to_owned_logline()
+    }
+}
+
+impl Logline<Unvalidated> {
+    /// Copies the fields into the [owned](crate::owned::parquet) variant
+    pub fn to_owned_logline(&self) -> crate::owned::parquet::UnvalidatedLogline {
+        self.view().clone().into()
+    }
+
+    /// Converts the line into the [owned](crate::owned::parquet) variant
+    ///
+    /// The reference to the chunk is dropped afterwards.
+    pub fn into_owned(self) -> crate::owned::parquet::UnvalidatedLogline {
+        self.to_owned_logline()
+    }
+}
+
+impl TryFrom<LineRef> for ValidatedLogline {
+    type Error = &'static str;
+
+    fn try_from(line: LineRef) -> Result<Self, Self::Error> {
+        validate_line(line.as_str())?;
+        let container = Container::try_new(line, |line| BorrowedLine::try_from(line.as_str()))?;
+        Ok(ValidatedLogline {
+            inner: container,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl TryFrom<LineRef> for UnvalidatedLogline {
+    type Error = &'static str;
+
+    fn try_from(line: LineRef) -> Result<Self, Self::Error> {
+        let container = Container::try_new(line, |line| BorrowedLine::try_from(line.as_str()))?;
+        Ok(UnvalidatedLogline {
+            inner: container,
+            _marker: PhantomData,
+        })
+    }
+}
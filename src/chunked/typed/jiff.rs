@@ -0,0 +1,80 @@
+use crate::{
+    borrowed::UnvalidatedJiffLogline as BorrowedLine,
+    chunked::{Chunk, LineRef},
+    shared::validate_line,
+    types::*,
+};
+
+self_cell::self_cell!(
+    struct Container {
+        owner: LineRef,
+
+        #[covariant]
+        dependent: BorrowedLine,
+    }
+
+    impl {Debug, PartialEq}
+);
+
+impl Clone for Container {
+    fn clone(&self) -> Self {
+        let input = self.borrow_owner().clone();
+        Self::new(input, |line| {
+            BorrowedLine::try_from(line.as_str())
+                .expect("an already parsed line cannot fail to parse")
+        })
+    }
+}
+
+pub type UnvalidatedLogline = Logline<Unvalidated>;
+pub type ValidatedLogline = Logline<Validated>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logline<V> {
+    inner: Container,
+    _marker: PhantomData<V>,
+}
+
+impl<V> Logline<V> {
+    pub fn view(&self) -> &BorrowedLine<'_> {
+        self.inner.borrow_dependent()
+    }
+
+    pub fn as_raw(&self) -> &str {
+        self.inner.borrow_owner().as_str()
+    }
+
+    /// The shared buffer the line is part of
+    pub fn chunk(&self) -> &Chunk {
+        self.inner.borrow_owner().chunk()
+    }
+
+    pub fn into_line(self) -> LineRef {
+        self.inner.into_owner()
+    }
+}
+
+impl TryFrom<LineRef> for ValidatedLogline {
+    type Error = &'static str;
+
+    fn try_from(line: LineRef) -> Result<Self, Self::Error> {
+        validate_line(line.as_str())?;
+        let container = Container::try_new(line, |line| BorrowedLine::try_from(line.as_str()))?;
+        Ok(ValidatedLogline {
+            inner: container,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl TryFrom<LineRef> for UnvalidatedLogline {
+    type Error = &'static str;
+
+    fn try_from(line: LineRef) -> Result<Self, Self::Error> {
+        let container = Container::try_new(line, |line| BorrowedLine::try_from(line.as_str()))?;
+        Ok(UnvalidatedLogline {
+            inner: container,
+            _marker: PhantomData,
+        })
+    }
+}
@@ -0,0 +1,812 @@
+//! Compact, versioned binary encoding of owned loglines
+//!
+//! Meant for streaming loglines between services or archiving them,
+//! where Parquet is too heavy and JSON too bloated.
+//! The [simple](crate::owned::simple) and [typed](crate::owned::typed) owned variants share the same wire format,
+//! so a stream written with one variant can be read into any other.
+//!
+//! [`Encoder`] writes to any [`Write`], [`Decoder`] reads from any [`Read`] and iterates over the decoded loglines.
+//! Both process one record at a time; use buffered readers and writers for good performance.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cloudfront_logs::{codec::{Decoder, Encoder}, owned::simple::ValidatedLogline};
+//! # #[cfg(feature = "chrono")]
+//! use cloudfront_logs::OwnedValidatedChronoLogline;
+//!
+//! let input = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+//! let line = ValidatedLogline::try_from(input).unwrap();
+//!
+//! let mut encoder = Encoder::new(Vec::new()).unwrap();
+//! encoder.encode(&line).unwrap();
+//! let bytes = encoder.into_inner();
+//! assert!(bytes.len() < input.len());
+//!
+//! let decoded = Decoder::<_, ValidatedLogline>::new(bytes.as_slice())
+//!     .unwrap()
+//!     .collect::<Result<Vec<_>, _>>()
+//!     .unwrap();
+//! assert_eq!(decoded, [line]);
+//!
+//! # #[cfg(feature = "chrono")]
+//! # {
+//! let typed = Decoder::<_, OwnedValidatedChronoLogline>::new(bytes.as_slice())
+//!     .unwrap()
+//!     .next()
+//!     .unwrap()
+//!     .unwrap();
+//! assert_eq!(typed.sc_bytes, 392);
+//! # }
+//! ```
+//!
+//! # Wire format (version 1)
+//!
+//! A stream starts with the 4 magic bytes `CFLB`, followed by the version byte (`1`).
+//! Then records follow until the end of the stream;
+//! each record is prefixed with its length in bytes (as varint) and contains one logline.
+//!
+//! The primitive values are encoded as:
+//!
+//! * **varint**: unsigned integer as [LEB128](https://en.wikipedia.org/wiki/LEB128), 7 bits per byte, least significant group first
+//! * **zigzag**: signed integer mapped to unsigned (`0, -1, 1, -2, …` to `0, 1, 2, 3, …`), then as varint
+//! * **string**: length in bytes as varint, followed by the UTF-8 bytes
+//! * **ip**: `4` followed by 4 bytes (IPv4) or `6` followed by 16 bytes (IPv6), in network byte order
+//! * **duration**: nanoseconds as varint
+//! * **option**: `0` if absent, otherwise `1` followed by the value
+//! * **enum**: a single byte, see the code tables below
+//! * **result type**: enum byte; `255` for unknown types, followed by the type as string
+//!
+//! The fields of a record, in order:
+//!
+//! | Field                           | Encoding                                                |
+//! |---------------------------------|---------------------------------------------------------|
+//! | `date`                          | year as 2 bytes (LE), month and day as 1 byte each      |
+//! | `time`                          | hour, minute and second as 1 byte each                  |
+//! | `x_edge_location`               | string                                                  |
+//! | `sc_bytes`                      | varint                                                  |
+//! | `c_ip`                          | ip                                                      |
+//! | `cs_method`                     | string                                                  |
+//! | `cs_host`                       | string                                                  |
+//! | `cs_uri_stem`                   | string                                                  |
+//! | `sc_status`                     | varint                                                  |
+//! | `cs_referer`                    | option of string                                        |
+//! | `cs_user_agent`                 | string                                                  |
+//! | `cs_uri_query`                  | option of string                                        |
+//! | `cs_cookie`                     | option of string                                        |
+//! | `x_edge_result_type`            | result type ([`EdgeResultType`])                        |
+//! | `x_edge_request_id`             | string                                                  |
+//! | `x_host_header`                 | string                                                  |
+//! | `cs_protocol`                   | enum ([`CsProtocol`])                                   |
+//! | `cs_bytes`                      | varint                                                  |
+//! | `time_taken`                    | duration                                                |
+//! | `x_forwarded_for`               | option of: count as varint, followed by the addresses   |
+//! | `ssl_protocol`                  | enum ([`SslProtocol`], `0` if absent)                   |
+//! | `ssl_cipher`                    | option of string                                        |
+//! | `x_edge_response_result_type`   | result type ([`EdgeResultType`])                        |
+//! | `cs_protocol_version`           | enum ([`CsProtocolVersion`])                            |
+//! | `fle_status`                    | option of string                                        |
+//! | `fle_encrypted_fields`          | option of varint                                        |
+//! | `c_port`                        | varint                                                  |
+//! | `time_to_first_byte`            | duration                                                |
+//! | `x_edge_detailed_result_type`   | result type ([`DetailedEdgeResultType`])                |
+//! | `sc_content_type`               | option of string                                        |
+//! | `sc_content_len`                | option of varint                                        |
+//! | `sc_range_start`                | option of zigzag                                        |
+//! | `sc_range_end`                  | option of zigzag                                        |
+//!
+//! The `x_forwarded_for` addresses ([`Addressable`]) start with a tag byte:
+//! `0` for `unknown`, `4`/`6` followed by the IP bytes (like ip), or
+//! `14`/`16` followed by the IP bytes and the port as 2 bytes (big endian) for socket addresses.
+//!
+//! The enum codes are:
+//!
+//! * [`CsProtocol`]: `0` http, `1` https, `2` ws, `3` wss
+//! * [`CsProtocolVersion`]: `0` HTTP/3.0, `1` HTTP/2.0, `2` HTTP/1.1, `3` HTTP/1.0, `4` HTTP/0.9
+//! * [`SslProtocol`]: `1` TLSv1.3, `2` TLSv1.2, `3` TLSv1.1, `4` TLSv1, `5` SSLv3
+//! * [`EdgeResultType`]: `0` Hit, `1` RefreshHit, `2` Miss, `3` LimitExceeded, `4` CapacityExceeded,
+//!   `5` Error, `6` Redirect, `7` LambdaGeneratedResponse
+//! * [`DetailedEdgeResultType`]: `0`-`7` as [`EdgeResultType`], `8` OriginShieldHit, `9` MissGeneratedResponse,
+//!   `10` AbortedOrigin, `11` ClientCommError, `12` ClientGeoBlocked, `13` ClientHungUpRequest,
+//!   `14` InvalidRequest, `15` InvalidRequestBlocked, `16` InvalidRequestCertificate, `17` InvalidRequestHeader,
+//!   `18` InvalidRequestMethod, `19` OriginCommError, `20` OriginConnectError, `21` OriginContentRangeLengthError,
+//!   `22` OriginDnsError, `23` OriginError, `24` OriginHeaderTooBigError, `25` OriginInvalidResponseError,
+//!   `26` OriginReadError, `27` OriginWriteError, `28` OriginZeroSizeObjectError, `29` SlowReaderOriginError
+//!
+//! New codes are only ever appended; any other change of the format increments the version.
+//!
+//! Precision: date and time are stored with second precision, like in the log files;
+//! socket addresses in `x_forwarded_for` lose IPv6 flow info and scope ID.
+
+use crate::types::*;
+use std::{
+    fmt,
+    io::{self, Read, Write},
+};
+
+/// The magic bytes at the start of each stream
+pub const MAGIC: [u8; 4] = *b"CFLB";
+
+/// The current version of the wire format
+pub const VERSION: u8 = 1;
+
+/// Upper limit for the length of a single record, to guard against corrupted length prefixes
+pub const MAX_RECORD_LEN: usize = 1 << 24;
+
+/// Loglines which can be written as records, see the [module documentation](self)
+pub trait Encode {
+    /// Appends the record (without its length prefix) to the buffer
+    fn encode(&self, buf: &mut Vec<u8>) -> Result<(), &'static str>;
+}
+
+/// Loglines which can be read from records, see the [module documentation](self)
+pub trait Decode: Sized {
+    /// Reads the record (without its length prefix), advancing the input
+    fn decode(input: &mut &[u8]) -> Result<Self, &'static str>;
+}
+
+/// Error of [`Encoder`] and [`Decoder`]
+#[derive(Debug)]
+pub enum CodecError {
+    /// Reading from or writing to the underlying stream failed
+    Io(io::Error),
+    /// The stream does not start with the [`MAGIC`] bytes
+    NotACodecStream,
+    /// The stream was written with an unknown version of the wire format
+    UnsupportedVersion(u8),
+    /// The record could not be encoded or decoded
+    Invalid(&'static str),
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "I/O error: {error}"),
+            Self::NotACodecStream => write!(f, "not an encoded logline stream"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported version {version}"),
+            Self::Invalid(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for CodecError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::NotACodecStream | Self::UnsupportedVersion(_) | Self::Invalid(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for CodecError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Writes loglines as records into a stream
+#[derive(Debug)]
+pub struct Encoder<W> {
+    writer: W,
+    buf: Vec<u8>,
+}
+
+impl<W: Write> Encoder<W> {
+    /// Writes the stream header
+    pub fn new(mut writer: W) -> Result<Self, CodecError> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        Ok(Self {
+            writer,
+            buf: Vec::new(),
+        })
+    }
+
+    /// Writes the logline as record
+    ///
+    /// Nothing is written if the logline cannot be encoded.
+    pub fn encode(&mut self, line: &impl Encode) -> Result<(), CodecError> {
+        self.buf.clear();
+        line.encode(&mut self.buf).map_err(CodecError::Invalid)?;
+        if self.buf.len() > MAX_RECORD_LEN {
+            return Err(CodecError::Invalid("record too large"));
+        }
+        let mut prefix = Vec::with_capacity(4);
+        wire::put_varint(&mut prefix, self.buf.len() as u64);
+        self.writer.write_all(&prefix)?;
+        self.writer.write_all(&self.buf)?;
+        Ok(())
+    }
+
+    /// Flushes the underlying writer
+    pub fn flush(&mut self) -> Result<(), CodecError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Returns a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Returns the underlying writer, without flushing it
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads records from a stream and decodes them into loglines
+///
+/// As [`Iterator`], it ends with the stream or after the first error.
+#[derive(Debug)]
+pub struct Decoder<R, T> {
+    reader: R,
+    buf: Vec<u8>,
+    finished: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<R: Read, T: Decode> Decoder<R, T> {
+    /// Reads and checks the stream header
+    pub fn new(mut reader: R) -> Result<Self, CodecError> {
+        let mut header = [0; 5];
+        reader.read_exact(&mut header)?;
+        let [m0, m1, m2, m3, version] = header;
+        if [m0, m1, m2, m3] != MAGIC {
+            return Err(CodecError::NotACodecStream);
+        }
+        if version != VERSION {
+            return Err(CodecError::UnsupportedVersion(version));
+        }
+        Ok(Self {
+            reader,
+            buf: Vec::new(),
+            finished: false,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Reads the next record, `None` at the end of the stream
+    pub fn decode(&mut self) -> Result<Option<T>, CodecError> {
+        let Some(len) = read_len(&mut self.reader)? else {
+            return Ok(None);
+        };
+        self.buf.resize(len, 0);
+        self.reader.read_exact(&mut self.buf)?;
+
+        let mut input = self.buf.as_slice();
+        let line = T::decode(&mut input).map_err(CodecError::Invalid)?;
+        if !input.is_empty() {
+            return Err(CodecError::Invalid("trailing bytes in record"));
+        }
+        Ok(Some(line))
+    }
+
+    /// Returns the underlying reader
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: Read, T: Decode> Iterator for Decoder<R, T> {
+    type Item = Result<T, CodecError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let item = self.decode().transpose();
+        self.finished = !matches!(item, Some(Ok(_)));
+        item
+    }
+}
+
+// reads the varint length prefix; `None` if the stream ends before the record
+fn read_len(reader: &mut impl Read) -> Result<Option<usize>, CodecError> {
+    let mut byte = [0; 1];
+    loop {
+        match reader.read(&mut byte) {
+            Ok(0) => return Ok(None),
+            Ok(_) => break,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error.into()),
+        }
+    }
+
+    let mut len = 0_u64;
+    let mut shift = 0;
+    let [mut current] = byte;
+    loop {
+        len |= u64::from(current & 0x7F) << shift;
+        if current & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+        if shift > 28 {
+            return Err(CodecError::Invalid("record too large"));
+        }
+        reader.read_exact(&mut byte)?;
+        [current] = byte;
+    }
+    match usize::try_from(len) {
+        Ok(len) if len <= MAX_RECORD_LEN => Ok(Some(len)),
+        _ => Err(CodecError::Invalid("record too large")),
+    }
+}
+
+// the primitives of the wire format, also used by the `impl_codec!` expansions
+pub(crate) mod wire {
+    use crate::types::*;
+    use core::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+
+    pub(crate) type Civil = ((u16, u8, u8), (u8, u8, u8));
+
+    pub(crate) fn put_varint(buf: &mut Vec<u8>, mut value: u64) {
+        while value >= 0x80 {
+            buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        buf.push(value as u8);
+    }
+
+    pub(crate) fn get_varint(input: &mut &[u8]) -> Result<u64, &'static str> {
+        let mut value = 0_u64;
+        for shift in (0..64).step_by(7) {
+            let byte = get_u8(input)?;
+            value |= u64::from(byte & 0x7F)
+                .checked_shl(shift)
+                .filter(|part| part >> shift == u64::from(byte & 0x7F))
+                .ok_or("varint overflow")?;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("varint overflow")
+    }
+
+    pub(crate) fn get_u8(input: &mut &[u8]) -> Result<u8, &'static str> {
+        let (first, rest) = input.split_first().ok_or("unexpected end of record")?;
+        *input = rest;
+        Ok(*first)
+    }
+
+    fn get_array<const N: usize>(input: &mut &[u8]) -> Result<[u8; N], &'static str> {
+        let (bytes, rest) = input
+            .split_first_chunk::<N>()
+            .ok_or("unexpected end of record")?;
+        *input = rest;
+        Ok(*bytes)
+    }
+
+    pub(crate) fn put_u16(buf: &mut Vec<u8>, value: u16) {
+        put_varint(buf, u64::from(value));
+    }
+
+    pub(crate) fn get_u16(input: &mut &[u8]) -> Result<u16, &'static str> {
+        u16::try_from(get_varint(input)?).map_err(|_e| "u16 overflow")
+    }
+
+    pub(crate) fn put_u64(buf: &mut Vec<u8>, value: u64) {
+        put_varint(buf, value);
+    }
+
+    pub(crate) fn get_u64(input: &mut &[u8]) -> Result<u64, &'static str> {
+        get_varint(input)
+    }
+
+    pub(crate) fn put_i64(buf: &mut Vec<u8>, value: i64) {
+        put_varint(buf, ((value << 1) ^ (value >> 63)) as u64);
+    }
+
+    pub(crate) fn get_i64(input: &mut &[u8]) -> Result<i64, &'static str> {
+        let value = get_varint(input)?;
+        Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+    }
+
+    pub(crate) fn put_str(buf: &mut Vec<u8>, value: &str) {
+        put_varint(buf, value.len() as u64);
+        buf.extend_from_slice(value.as_bytes());
+    }
+
+    pub(crate) fn get_str<'a>(input: &mut &'a [u8]) -> Result<&'a str, &'static str> {
+        let len = usize::try_from(get_varint(input)?).map_err(|_e| "string too long")?;
+        let (bytes, rest) = input
+            .split_at_checked(len)
+            .ok_or("unexpected end of record")?;
+        *input = rest;
+        core::str::from_utf8(bytes).map_err(|_e| "string not valid UTF-8")
+    }
+
+    pub(crate) fn get_string(input: &mut &[u8]) -> Result<String, &'static str> {
+        get_str(input).map(ToString::to_string)
+    }
+
+    pub(crate) fn put_option<T>(
+        buf: &mut Vec<u8>,
+        value: Option<T>,
+        put: impl FnOnce(&mut Vec<u8>, T),
+    ) {
+        match value {
+            Some(value) => {
+                buf.push(1);
+                put(buf, value);
+            }
+            None => buf.push(0),
+        }
+    }
+
+    pub(crate) fn get_option<T>(
+        input: &mut &[u8],
+        get: impl FnOnce(&mut &[u8]) -> Result<T, &'static str>,
+    ) -> Result<Option<T>, &'static str> {
+        match get_u8(input)? {
+            0 => Ok(None),
+            1 => get(input).map(Some),
+            _ => Err("invalid option tag"),
+        }
+    }
+
+    pub(crate) fn put_duration(buf: &mut Vec<u8>, value: Duration) -> Result<(), &'static str> {
+        let nanos = u64::try_from(value.as_nanos()).map_err(|_e| "duration too large")?;
+        put_varint(buf, nanos);
+        Ok(())
+    }
+
+    pub(crate) fn get_duration(input: &mut &[u8]) -> Result<Duration, &'static str> {
+        get_varint(input).map(Duration::from_nanos)
+    }
+
+    pub(crate) fn put_ip(buf: &mut Vec<u8>, value: IpAddr) {
+        match value {
+            IpAddr::V4(ip) => {
+                buf.push(4);
+                buf.extend_from_slice(&ip.octets());
+            }
+            IpAddr::V6(ip) => {
+                buf.push(6);
+                buf.extend_from_slice(&ip.octets());
+            }
+        }
+    }
+
+    fn get_ip_of(tag: u8, input: &mut &[u8]) -> Result<IpAddr, &'static str> {
+        match tag {
+            4 => Ok(IpAddr::V4(Ipv4Addr::from(get_array::<4>(input)?))),
+            6 => Ok(IpAddr::V6(Ipv6Addr::from(get_array::<16>(input)?))),
+            _ => Err("invalid ip tag"),
+        }
+    }
+
+    pub(crate) fn get_ip(input: &mut &[u8]) -> Result<IpAddr, &'static str> {
+        let tag = get_u8(input)?;
+        get_ip_of(tag, input)
+    }
+
+    pub(crate) fn put_forwarded_for(buf: &mut Vec<u8>, value: &ForwardedForAddrs) {
+        put_varint(buf, value.0.len() as u64);
+        for addr in &value.0 {
+            match addr {
+                Addressable::Unknown => buf.push(0),
+                Addressable::IpAddr(ip) => put_ip(buf, *ip),
+                Addressable::Socket(socket) => {
+                    let start = buf.len();
+                    put_ip(buf, socket.ip());
+                    if let Some(tag) = buf.get_mut(start) {
+                        *tag += 10;
+                    }
+                    buf.extend_from_slice(&socket.port().to_be_bytes());
+                }
+            }
+        }
+    }
+
+    pub(crate) fn get_forwarded_for(input: &mut &[u8]) -> Result<ForwardedForAddrs, &'static str> {
+        let count = get_varint(input)?;
+        let mut addrs = Vec::new();
+        for _ in 0..count {
+            let addr = match get_u8(input)? {
+                0 => Addressable::Unknown,
+                tag @ (4 | 6) => Addressable::IpAddr(get_ip_of(tag, input)?),
+                tag @ (14 | 16) => {
+                    let ip = get_ip_of(tag - 10, input)?;
+                    let port = u16::from_be_bytes(get_array::<2>(input)?);
+                    Addressable::Socket(SocketAddr::new(ip, port))
+                }
+                _ => return Err("invalid address tag"),
+            };
+            addrs.push(addr);
+        }
+        Ok(ForwardedForAddrs(addrs))
+    }
+
+    pub(crate) fn put_civil(
+        buf: &mut Vec<u8>,
+        ((year, month, day), (hour, minute, second)): Civil,
+    ) {
+        buf.extend_from_slice(&year.to_le_bytes());
+        buf.extend_from_slice(&[month, day, hour, minute, second]);
+    }
+
+    pub(crate) fn get_civil(input: &mut &[u8]) -> Result<Civil, &'static str> {
+        let year = u16::from_le_bytes(get_array::<2>(input)?);
+        let [month, day, hour, minute, second] = get_array::<5>(input)?;
+        // all conversions from the civil date/time rely on it being valid
+        crate::shared::check_ymd(year, month, day).map_err(|_e| "date invalid")?;
+        crate::shared::check_hms(hour, minute, second).map_err(|_e| "time invalid")?;
+        Ok(((year, month, day), (hour, minute, second)))
+    }
+
+    pub(crate) fn civil_from_strings(date: &str, time: &str) -> Result<Civil, &'static str> {
+        let date = crate::shared::parse_ymd(date.as_bytes()).map_err(|_e| "date invalid")?;
+        let time = crate::shared::parse_hms(time.as_bytes()).map_err(|_e| "time invalid")?;
+        Ok((date, time))
+    }
+
+    pub(crate) fn strings_from_civil(
+        ((year, month, day), (hour, minute, second)): Civil,
+    ) -> (String, String) {
+        (
+            format!("{year:04}-{month:02}-{day:02}"),
+            format!("{hour:02}:{minute:02}:{second:02}"),
+        )
+    }
+
+    #[cfg(feature = "chrono")]
+    pub(crate) fn civil_from_chrono(
+        date: &NaiveDate,
+        time: &NaiveTime,
+    ) -> Result<Civil, &'static str> {
+        let year = u16::try_from(date.year()).map_err(|_e| "year out of range")?;
+        Ok((
+            (year, date.month() as u8, date.day() as u8),
+            (time.hour() as u8, time.minute() as u8, time.second() as u8),
+        ))
+    }
+
+    #[cfg(feature = "chrono")]
+    pub(crate) fn chrono_from_civil(
+        ((year, month, day), (hour, minute, second)): Civil,
+    ) -> Result<(NaiveDate, NaiveTime, NaiveDateTime), &'static str> {
+        let date = NaiveDate::from_ymd_opt(i32::from(year), u32::from(month), u32::from(day))
+            .ok_or("date invalid")?;
+        let time = NaiveTime::from_hms_opt(u32::from(hour), u32::from(minute), u32::from(second))
+            .ok_or("time invalid")?;
+        Ok((date, time, NaiveDateTime::new(date, time)))
+    }
+
+    #[cfg(feature = "time")]
+    pub(crate) fn civil_from_time(date: &Date, time: &Time) -> Result<Civil, &'static str> {
+        let year = u16::try_from(date.year()).map_err(|_e| "year out of range")?;
+        Ok((
+            (year, u8::from(date.month()), date.day()),
+            (time.hour(), time.minute(), time.second()),
+        ))
+    }
+
+    #[cfg(feature = "time")]
+    pub(crate) fn time_from_civil(
+        ((year, month, day), (hour, minute, second)): Civil,
+    ) -> Result<(Date, Time, OffsetDateTime), &'static str> {
+        let month = ::time::Month::try_from(month).map_err(|_e| "date invalid")?;
+        let date =
+            Date::from_calendar_date(i32::from(year), month, day).map_err(|_e| "date invalid")?;
+        let time = Time::from_hms(hour, minute, second).map_err(|_e| "time invalid")?;
+        Ok((date, time, OffsetDateTime::new_utc(date, time)))
+    }
+
+    macro_rules! enum_codes {
+        ($put:ident, $get:ident, $ty:ident { $($code:literal => $variant:ident),+ $(,)? }) => {
+            pub(crate) fn $put(buf: &mut Vec<u8>, value: $ty) {
+                buf.push(match value {
+                    $($ty::$variant => $code,)+
+                });
+            }
+
+            pub(crate) fn $get(input: &mut &[u8]) -> Result<$ty, &'static str> {
+                match get_u8(input)? {
+                    $($code => Ok($ty::$variant),)+
+                    _ => Err(concat!("invalid ", stringify!($ty), " code")),
+                }
+            }
+        };
+    }
+
+    enum_codes!(put_protocol, get_protocol, CsProtocol {
+        0 => Http,
+        1 => Https,
+        2 => Ws,
+        3 => Wss,
+    });
+
+    enum_codes!(put_protocol_version, get_protocol_version, CsProtocolVersion {
+        0 => HTTP3_0,
+        1 => HTTP2_0,
+        2 => HTTP1_1,
+        3 => HTTP1_0,
+        4 => HTTP0_9,
+    });
+
+    enum_codes!(put_ssl_protocol_code, get_ssl_protocol_code, SslProtocol {
+        1 => TLSv1_3,
+        2 => TLSv1_2,
+        3 => TLSv1_1,
+        4 => TLSv1_0,
+        5 => SSLv3,
+    });
+
+    pub(crate) fn put_ssl_protocol(buf: &mut Vec<u8>, value: Option<SslProtocol>) {
+        match value {
+            Some(value) => put_ssl_protocol_code(buf, value),
+            None => buf.push(0),
+        }
+    }
+
+    pub(crate) fn get_ssl_protocol(input: &mut &[u8]) -> Result<Option<SslProtocol>, &'static str> {
+        if input.first() == Some(&0) {
+            get_u8(input)?;
+            return Ok(None);
+        }
+        get_ssl_protocol_code(input).map(Some)
+    }
+
+    const OTHER: u8 = 255;
+
+    // result types: a known code, or `OTHER` followed by the string
+    macro_rules! result_codes {
+        ($put:ident, $get:ident, $ty:ident { $($code:literal => $variant:ident),+ $(,)? }) => {
+            pub(crate) fn $put(buf: &mut Vec<u8>, value: &$ty<'_>) {
+                match value {
+                    $($ty::$variant => buf.push($code),)+
+                    $ty::Other(other) => {
+                        buf.push(OTHER);
+                        put_str(buf, other);
+                    }
+                }
+            }
+
+            pub(crate) fn $get(input: &mut &[u8]) -> Result<$ty<'static>, &'static str> {
+                match get_u8(input)? {
+                    $($code => Ok($ty::$variant),)+
+                    OTHER => Ok($ty::Other(Cow::Owned(get_string(input)?))),
+                    _ => Err(concat!("invalid ", stringify!($ty), " code")),
+                }
+            }
+        };
+    }
+
+    result_codes!(put_result_type, get_result_type, EdgeResultType {
+        0 => Hit,
+        1 => RefreshHit,
+        2 => Miss,
+        3 => LimitExceeded,
+        4 => CapacityExceeded,
+        5 => Error,
+        6 => Redirect,
+        7 => LambdaGeneratedResponse,
+    });
+
+    result_codes!(put_detailed_result_type, get_detailed_result_type, DetailedEdgeResultType {
+        0 => Hit,
+        1 => RefreshHit,
+        2 => Miss,
+        3 => LimitExceeded,
+        4 => CapacityExceeded,
+        5 => Error,
+        6 => Redirect,
+        7 => LambdaGeneratedResponse,
+        8 => OriginShieldHit,
+        9 => MissGeneratedResponse,
+        10 => AbortedOrigin,
+        11 => ClientCommError,
+        12 => ClientGeoBlocked,
+        13 => ClientHungUpRequest,
+        14 => InvalidRequest,
+        15 => InvalidRequestBlocked,
+        16 => InvalidRequestCertificate,
+        17 => InvalidRequestHeader,
+        18 => InvalidRequestMethod,
+        19 => OriginCommError,
+        20 => OriginConnectError,
+        21 => OriginContentRangeLengthError,
+        22 => OriginDnsError,
+        23 => OriginError,
+        24 => OriginHeaderTooBigError,
+        25 => OriginInvalidResponseError,
+        26 => OriginReadError,
+        27 => OriginWriteError,
+        28 => OriginZeroSizeObjectError,
+        29 => SlowReaderOriginError,
+    });
+}
+
+// implements `Encode` and `Decode` for an owned logline type;
+// invoked in the type's module, since the validation marker field is private.
+// `head` names the date/time fields, `civil` the conversions from and to the civil date/time,
+// the latter followed by `?` if it can fail.
+macro_rules! impl_codec {
+    ($ty:ident, head: [$($head:ident),+], civil: $to_civil:path, $from_civil:ident $($try:tt)?) => {
+        impl<V> $crate::codec::Encode for $ty<V> {
+            fn encode(&self, buf: &mut Vec<u8>) -> Result<(), &'static str> {
+                use $crate::codec::wire::*;
+                put_civil(buf, $to_civil(&self.date, &self.time)?);
+                put_str(buf, &self.x_edge_location);
+                put_u64(buf, self.sc_bytes);
+                put_ip(buf, self.c_ip);
+                put_str(buf, &self.cs_method);
+                put_str(buf, &self.cs_host);
+                put_str(buf, &self.cs_uri_stem);
+                put_u16(buf, self.sc_status);
+                put_option(buf, self.cs_referer.as_deref(), put_str);
+                put_str(buf, &self.cs_user_agent);
+                put_option(buf, self.cs_uri_query.as_deref(), put_str);
+                put_option(buf, self.cs_cookie.as_deref(), put_str);
+                put_result_type(buf, &self.x_edge_result_type);
+                put_str(buf, &self.x_edge_request_id);
+                put_str(buf, &self.x_host_header);
+                put_protocol(buf, self.cs_protocol);
+                put_u64(buf, self.cs_bytes);
+                put_duration(buf, self.time_taken)?;
+                put_option(buf, self.x_forwarded_for.as_ref(), put_forwarded_for);
+                put_ssl_protocol(buf, self.ssl_protocol);
+                put_option(buf, self.ssl_cipher.as_deref(), put_str);
+                put_result_type(buf, &self.x_edge_response_result_type);
+                put_protocol_version(buf, self.cs_protocol_version);
+                put_option(buf, self.fle_status.as_deref(), put_str);
+                put_option(buf, self.fle_encrypted_fields, put_u64);
+                put_u16(buf, self.c_port);
+                put_duration(buf, self.time_to_first_byte)?;
+                put_detailed_result_type(buf, &self.x_edge_detailed_result_type);
+                put_option(buf, self.sc_content_type.as_deref(), put_str);
+                put_option(buf, self.sc_content_len, put_u64);
+                put_option(buf, self.sc_range_start, put_i64);
+                put_option(buf, self.sc_range_end, put_i64);
+                Ok(())
+            }
+        }
+
+        impl<V> $crate::codec::Decode for $ty<V> {
+            fn decode(input: &mut &[u8]) -> Result<Self, &'static str> {
+                use $crate::codec::wire::*;
+                let ($($head),+) = $from_civil(get_civil(input)?)$($try)?;
+                // fields are evaluated in order of appearance
+                Ok(Self {
+                    $($head,)+
+                    x_edge_location: get_string(input)?,
+                    sc_bytes: get_u64(input)?,
+                    c_ip: get_ip(input)?,
+                    cs_method: get_string(input)?,
+                    cs_host: get_string(input)?,
+                    cs_uri_stem: get_string(input)?,
+                    sc_status: get_u16(input)?,
+                    cs_referer: get_option(input, get_string)?,
+                    cs_user_agent: get_string(input)?,
+                    cs_uri_query: get_option(input, get_string)?,
+                    cs_cookie: get_option(input, get_string)?,
+                    x_edge_result_type: get_result_type(input)?,
+                    x_edge_request_id: get_string(input)?,
+                    x_host_header: get_string(input)?,
+                    cs_protocol: get_protocol(input)?,
+                    cs_bytes: get_u64(input)?,
+                    time_taken: get_duration(input)?,
+                    x_forwarded_for: get_option(input, get_forwarded_for)?,
+                    ssl_protocol: get_ssl_protocol(input)?,
+                    ssl_cipher: get_option(input, get_string)?,
+                    x_edge_response_result_type: get_result_type(input)?,
+                    cs_protocol_version: get_protocol_version(input)?,
+                    fle_status: get_option(input, get_string)?,
+                    fle_encrypted_fields: get_option(input, get_u64)?,
+                    c_port: get_u16(input)?,
+                    time_to_first_byte: get_duration(input)?,
+                    x_edge_detailed_result_type: get_detailed_result_type(input)?,
+                    sc_content_type: get_option(input, get_string)?,
+                    sc_content_len: get_option(input, get_u64)?,
+                    sc_range_start: get_option(input, get_i64)?,
+                    sc_range_end: get_option(input, get_i64)?,
+                    __marker: PhantomData,
+                })
+            }
+        }
+    };
+}
+
+pub(crate) use impl_codec;
@@ -0,0 +1,496 @@
+//! Columnar (struct-of-arrays) in-memory store of log lines
+//!
+//! [`LogColumns`] appends parsed lines into one vector per field,
+//! which keeps scans over single fields (sums, histograms, filters) cache friendly.
+//! It sits between the row-oriented [`owned`](crate::owned) types and full Arrow/Parquet,
+//! without requiring any additional dependency.
+//!
+//! The columns mirror the accessors of [`CloudFrontRecord`], so every logline variant can be appended.
+//! Low-cardinality strings (like hosts and user agents) are dictionary-encoded in a [`DictColumn`],
+//! the other strings are concatenated into a single buffer per column, see [`StrColumn`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cloudfront_logs::columns::LogColumns;
+//!
+//! let lines = [
+//!     "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-",
+//!     "2019-12-13	22:37:02	SEA19-C2	900	192.0.2.200	GET	d111111abcdef8.cloudfront.net	/	502	-	curl/7.55.1	-	-	Error	kBkDzGnceVtWHqSCqBUqtA_cEs2T3tFUBbnBNkB9El_uVRhHgcZfcw==	www.example.com	http	387	0.103	-	-	-	Error	HTTP/1.1	-	-	12644	0.103	OriginDnsError	text/html	507	-	-",
+//! ];
+//!
+//! let mut columns = LogColumns::new();
+//! for line in lines {
+//!     columns.push_line(line).unwrap();
+//! }
+//!
+//! assert_eq!(columns.bytes_sent().iter().sum::<u64>(), 1292);
+//! assert_eq!(columns.content_type().values().len(), 1);
+//!
+//! let errors = columns.filter(&columns.mask(|row| row.status >= 500));
+//! assert_eq!(errors.len(), 1);
+//! assert_eq!(errors.get(0).unwrap().edge_location, "SEA19-C2");
+//! ```
+
+use crate::{borrowed::ValidatedSimpleLogline, record::CloudFrontRecord, types::*};
+use core::ops::Range;
+use std::collections::HashMap;
+
+/// A dictionary-encoded column of (optional) strings
+///
+/// Each distinct value is stored once, the rows refer to it by its code (index into [`values`](Self::values)).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DictColumn {
+    values: Vec<Arc<str>>,
+    lookup: HashMap<Arc<str>, u32>,
+    codes: Vec<Option<u32>>,
+}
+
+impl DictColumn {
+    /// # Panics
+    ///
+    /// If the column holds more than [`u32::MAX`] distinct values.
+    fn push(&mut self, value: Option<&str>) {
+        let code = value.map(|value| {
+            if let Some(code) = self.lookup.get(value) {
+                return *code;
+            }
+            let code = u32::try_from(self.values.len()).expect("too many distinct values");
+            let value: Arc<str> = value.into();
+            self.values.push(Arc::clone(&value));
+            self.lookup.insert(value, code);
+            code
+        });
+        self.codes.push(code);
+    }
+
+    /// The value of the row, `None` if it is not set or out of bounds
+    pub fn get(&self, row: usize) -> Option<&str> {
+        let code = (*self.codes.get(row)?)?;
+        self.value(code)
+    }
+
+    /// The value of the code
+    pub fn value(&self, code: u32) -> Option<&str> {
+        self.values.get(code as usize).map(|value| &**value)
+    }
+
+    /// The codes of all rows, `None` where the value is not set
+    pub fn codes(&self) -> &[Option<u32>] {
+        &self.codes
+    }
+
+    /// The distinct values, in order of their first appearance
+    pub fn values(&self) -> &[Arc<str>] {
+        &self.values
+    }
+
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+}
+
+/// A column of (optional) strings, concatenated into a single buffer
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StrColumn {
+    data: String,
+    // end offset into `data` and whether the value is set, per row
+    ends: Vec<(usize, bool)>,
+}
+
+impl StrColumn {
+    fn push(&mut self, value: Option<&str>) {
+        self.data.push_str(value.unwrap_or_default());
+        self.ends.push((self.data.len(), value.is_some()));
+    }
+
+    /// The value of the row, `None` if it is not set or out of bounds
+    pub fn get(&self, row: usize) -> Option<&str> {
+        let (end, is_set) = *self.ends.get(row)?;
+        let start = match row.checked_sub(1) {
+            Some(previous) => self.ends.get(previous)?.0,
+            None => 0,
+        };
+        if is_set {
+            self.data.get(start..end)
+        } else {
+            None
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+}
+
+/// A single row of [`LogColumns`], borrowing its strings from the columns
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row<'a> {
+    pub timestamp: i64,
+    pub edge_location: &'a str,
+    pub bytes_sent: u64,
+    pub client_ip: IpAddr,
+    pub method: &'a str,
+    pub host: &'a str,
+    pub uri_stem: &'a str,
+    pub status: u16,
+    pub referer: Option<&'a str>,
+    pub user_agent: &'a str,
+    pub uri_query: Option<&'a str>,
+    pub result_type: EdgeResultType<'a>,
+    pub request_id: &'a str,
+    pub protocol: CsProtocol,
+    pub bytes_received: u64,
+    pub time_taken: Duration,
+    pub time_to_first_byte: Duration,
+    pub detailed_result_type: DetailedEdgeResultType<'a>,
+    pub content_type: Option<&'a str>,
+    pub content_len: Option<u64>,
+}
+
+impl<'a> Row<'a> {
+    /// Collects the values of all accessors, fails if any of them does
+    pub fn from_record<R: CloudFrontRecord>(record: &'a R) -> Result<Self, &'static str> {
+        Ok(Self {
+            timestamp: record.timestamp()?,
+            edge_location: record.edge_location(),
+            bytes_sent: record.bytes_sent()?,
+            client_ip: record.client_ip()?,
+            method: record.method(),
+            host: record.host(),
+            uri_stem: record.uri_stem(),
+            status: record.status()?,
+            referer: record.referer(),
+            user_agent: record.user_agent(),
+            uri_query: record.uri_query(),
+            result_type: record.result_type()?,
+            request_id: record.request_id(),
+            protocol: record.protocol()?,
+            bytes_received: record.bytes_received()?,
+            time_taken: record.time_taken()?,
+            time_to_first_byte: record.time_to_first_byte()?,
+            detailed_result_type: record.detailed_result_type()?,
+            content_type: record.content_type(),
+            content_len: record.content_len()?,
+        })
+    }
+}
+
+impl CloudFrontRecord for Row<'_> {
+    fn timestamp(&self) -> Result<i64, &'static str> {
+        Ok(self.timestamp)
+    }
+
+    fn edge_location(&self) -> &str {
+        self.edge_location
+    }
+
+    fn bytes_sent(&self) -> Result<u64, &'static str> {
+        Ok(self.bytes_sent)
+    }
+
+    fn client_ip(&self) -> Result<IpAddr, &'static str> {
+        Ok(self.client_ip)
+    }
+
+    fn method(&self) -> &str {
+        self.method
+    }
+
+    fn host(&self) -> &str {
+        self.host
+    }
+
+    fn uri_stem(&self) -> &str {
+        self.uri_stem
+    }
+
+    fn status(&self) -> Result<u16, &'static str> {
+        Ok(self.status)
+    }
+
+    fn referer(&self) -> Option<&str> {
+        self.referer
+    }
+
+    fn user_agent(&self) -> &str {
+        self.user_agent
+    }
+
+    fn uri_query(&self) -> Option<&str> {
+        self.uri_query
+    }
+
+    fn result_type(&self) -> Result<EdgeResultType<'_>, &'static str> {
+        Ok(self.result_type.clone())
+    }
+
+    fn request_id(&self) -> &str {
+        self.request_id
+    }
+
+    fn protocol(&self) -> Result<CsProtocol, &'static str> {
+        Ok(self.protocol)
+    }
+
+    fn bytes_received(&self) -> Result<u64, &'static str> {
+        Ok(self.bytes_received)
+    }
+
+    fn time_taken(&self) -> Result<Duration, &'static str> {
+        Ok(self.time_taken)
+    }
+
+    fn time_to_first_byte(&self) -> Result<Duration, &'static str> {
+        Ok(self.time_to_first_byte)
+    }
+
+    fn detailed_result_type(&self) -> Result<DetailedEdgeResultType<'_>, &'static str> {
+        Ok(self.detailed_result_type.clone())
+    }
+
+    fn content_type(&self) -> Option<&str> {
+        self.content_type
+    }
+
+    fn content_len(&self) -> Result<Option<u64>, &'static str> {
+        Ok(self.content_len)
+    }
+}
+
+/// Struct-of-arrays store of log lines, see the [module documentation](self)
+///
+/// All columns always have the same length.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogColumns {
+    timestamp: Vec<i64>,
+    edge_location: DictColumn,
+    bytes_sent: Vec<u64>,
+    client_ip: Vec<IpAddr>,
+    method: DictColumn,
+    host: DictColumn,
+    uri_stem: StrColumn,
+    status: Vec<u16>,
+    referer: DictColumn,
+    user_agent: DictColumn,
+    uri_query: StrColumn,
+    result_type: Vec<EdgeResultType<'static>>,
+    request_id: StrColumn,
+    protocol: Vec<CsProtocol>,
+    bytes_received: Vec<u64>,
+    time_taken: Vec<Duration>,
+    time_to_first_byte: Vec<Duration>,
+    detailed_result_type: Vec<DetailedEdgeResultType<'static>>,
+    content_type: DictColumn,
+    content_len: Vec<Option<u64>>,
+}
+
+impl LogColumns {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of rows
+    pub fn len(&self) -> usize {
+        self.timestamp.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timestamp.is_empty()
+    }
+
+    /// Appends the record as new row
+    ///
+    /// Nothing is appended if any of the record's accessors fails.
+    ///
+    /// # Panics
+    ///
+    /// If a dictionary-encoded column would hold more than [`u32::MAX`] distinct values.
+    pub fn push(&mut self, record: &impl CloudFrontRecord) -> Result<(), &'static str> {
+        let row = Row::from_record(record)?;
+        self.push_row(row);
+        Ok(())
+    }
+
+    /// Parses (and validates) the line and appends it as new row
+    pub fn push_line(&mut self, line: &str) -> Result<(), &'static str> {
+        let line = ValidatedSimpleLogline::try_from(line)?;
+        self.push(&line)
+    }
+
+    fn push_row(&mut self, row: Row<'_>) {
+        self.timestamp.push(row.timestamp);
+        self.edge_location.push(Some(row.edge_location));
+        self.bytes_sent.push(row.bytes_sent);
+        self.client_ip.push(row.client_ip);
+        self.method.push(Some(row.method));
+        self.host.push(Some(row.host));
+        self.uri_stem.push(Some(row.uri_stem));
+        self.status.push(row.status);
+        self.referer.push(row.referer);
+        self.user_agent.push(Some(row.user_agent));
+        self.uri_query.push(row.uri_query);
+        self.result_type.push(row.result_type.into_owned());
+        self.request_id.push(Some(row.request_id));
+        self.protocol.push(row.protocol);
+        self.bytes_received.push(row.bytes_received);
+        self.time_taken.push(row.time_taken);
+        self.time_to_first_byte.push(row.time_to_first_byte);
+        self.detailed_result_type
+            .push(row.detailed_result_type.into_owned());
+        self.content_type.push(row.content_type);
+        self.content_len.push(row.content_len);
+    }
+
+    /// The row at the index, `None` if out of bounds
+    pub fn get(&self, index: usize) -> Option<Row<'_>> {
+        Some(Row {
+            timestamp: *self.timestamp.get(index)?,
+            edge_location: self.edge_location.get(index)?,
+            bytes_sent: *self.bytes_sent.get(index)?,
+            client_ip: *self.client_ip.get(index)?,
+            method: self.method.get(index)?,
+            host: self.host.get(index)?,
+            uri_stem: self.uri_stem.get(index)?,
+            status: *self.status.get(index)?,
+            referer: self.referer.get(index),
+            user_agent: self.user_agent.get(index)?,
+            uri_query: self.uri_query.get(index),
+            result_type: self.result_type.get(index)?.as_borrowed(),
+            request_id: self.request_id.get(index)?,
+            protocol: *self.protocol.get(index)?,
+            bytes_received: *self.bytes_received.get(index)?,
+            time_taken: *self.time_taken.get(index)?,
+            time_to_first_byte: *self.time_to_first_byte.get(index)?,
+            detailed_result_type: self.detailed_result_type.get(index)?.as_borrowed(),
+            content_type: self.content_type.get(index),
+            content_len: *self.content_len.get(index)?,
+        })
+    }
+
+    /// Iterates over all rows
+    pub fn iter(&self) -> impl Iterator<Item = Row<'_>> {
+        (0..self.len()).filter_map(|index| self.get(index))
+    }
+
+    /// Evaluates the predicate for each row, the result can be passed to [`filter`](Self::filter)
+    pub fn mask(&self, mut predicate: impl FnMut(&Row<'_>) -> bool) -> Vec<bool> {
+        self.iter().map(|row| predicate(&row)).collect()
+    }
+
+    /// Copies the rows whose mask entry is `true` into a new store
+    ///
+    /// Rows without a mask entry (if the mask is shorter) are not copied.
+    pub fn filter(&self, mask: &[bool]) -> Self {
+        self.select(
+            mask.iter()
+                .enumerate()
+                .filter_map(|(index, keep)| keep.then_some(index)),
+        )
+    }
+
+    /// Copies the rows of the range into a new store
+    ///
+    /// The range is clamped to the number of rows.
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        let end = range.end.min(self.len());
+        self.select(range.start..end)
+    }
+
+    // the dictionaries of the new store only contain the values of the selected rows
+    fn select(&self, indices: impl Iterator<Item = usize>) -> Self {
+        let mut selected = Self::new();
+        for row in indices.filter_map(|index| self.get(index)) {
+            selected.push_row(row);
+        }
+        selected
+    }
+
+    pub fn timestamp(&self) -> &[i64] {
+        &self.timestamp
+    }
+
+    pub fn edge_location(&self) -> &DictColumn {
+        &self.edge_location
+    }
+
+    pub fn bytes_sent(&self) -> &[u64] {
+        &self.bytes_sent
+    }
+
+    pub fn client_ip(&self) -> &[IpAddr] {
+        &self.client_ip
+    }
+
+    pub fn method(&self) -> &DictColumn {
+        &self.method
+    }
+
+    pub fn host(&self) -> &DictColumn {
+        &self.host
+    }
+
+    pub fn uri_stem(&self) -> &StrColumn {
+        &self.uri_stem
+    }
+
+    pub fn status(&self) -> &[u16] {
+        &self.status
+    }
+
+    pub fn referer(&self) -> &DictColumn {
+        &self.referer
+    }
+
+    pub fn user_agent(&self) -> &DictColumn {
+        &self.user_agent
+    }
+
+    pub fn uri_query(&self) -> &StrColumn {
+        &self.uri_query
+    }
+
+    pub fn result_type(&self) -> &[EdgeResultType<'static>] {
+        &self.result_type
+    }
+
+    pub fn request_id(&self) -> &StrColumn {
+        &self.request_id
+    }
+
+    pub fn protocol(&self) -> &[CsProtocol] {
+        &self.protocol
+    }
+
+    pub fn bytes_received(&self) -> &[u64] {
+        &self.bytes_received
+    }
+
+    pub fn time_taken(&self) -> &[Duration] {
+        &self.time_taken
+    }
+
+    pub fn time_to_first_byte(&self) -> &[Duration] {
+        &self.time_to_first_byte
+    }
+
+    pub fn detailed_result_type(&self) -> &[DetailedEdgeResultType<'static>] {
+        &self.detailed_result_type
+    }
+
+    pub fn content_type(&self) -> &DictColumn {
+        &self.content_type
+    }
+
+    pub fn content_len(&self) -> &[Option<u64>] {
+        &self.content_len
+    }
+}
@@ -0,0 +1,367 @@
+//! [`serde`] support for deserializing a log line into user-defined structs
+//!
+//! Instead of picking one of the fixed logline types,
+//! you can derive [`Deserialize`](serde::Deserialize) for your own struct with any subset of the fields.
+//!
+//! * Struct fields are matched by their Rust name (like `cs_user_agent`) or their W3C header name (like `cs(User-Agent)`).
+//! * Only the requested fields are deserialized, all others are skipped.
+//! * A `-` is treated as `None` for `Option` fields.
+//! * Borrowed `&str` fields are supported, they point into the original log line.
+//! * Numbers are parsed from their text representation,
+//!   enums (including the ones from the [`types`](crate::types) module) from their names.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cloudfront_logs::{de, types::*};
+//! use std::net::IpAddr;
+//! use serde::Deserialize;
+//!
+//! #[derive(Debug, Deserialize)]
+//! struct Request<'a> {
+//!     date: &'a str,
+//!     cs_host: &'a str,
+//!     sc_status: u16,
+//!     sc_bytes: u64,
+//!     c_ip: IpAddr,
+//!     cs_referer: Option<&'a str>,
+//!     #[serde(rename = "x-edge-result-type")]
+//!     result_type: EdgeResultType<'static>,
+//! }
+//!
+//! let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+//!
+//! let item: Request<'_> = de::from_str(line).unwrap();
+//!
+//! assert_eq!(item.date, "2019-12-04");
+//! assert_eq!(item.cs_host, "d111111abcdef8.cloudfront.net");
+//! assert_eq!(item.sc_status, 200);
+//! assert_eq!(item.cs_referer, None);
+//! assert_eq!(item.result_type, EdgeResultType::Hit);
+//! ```
+
+use crate::{shared::*, types::*};
+use serde::de::{self, IntoDeserializer, value::BorrowedStrDeserializer};
+use std::fmt;
+
+/// Error type of the log line deserializer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+impl From<&'static str> for Error {
+    fn from(msg: &'static str) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// Deserializes a validated log line into `T`
+///
+/// The line is checked with [`validate_line`] first.
+pub fn from_str<'de, T: de::Deserialize<'de>>(line: &'de str) -> Result<T, Error> {
+    validate_line(line)?;
+    T::deserialize(Deserializer::new(line))
+}
+
+/// Deserializes a log line into `T` without validating it first
+///
+/// Use this if you already did a check before, for example skipped the comment lines.
+pub fn from_str_unvalidated<'de, T: de::Deserialize<'de>>(line: &'de str) -> Result<T, Error> {
+    T::deserialize(Deserializer::new(line))
+}
+
+/// Deserializer over a single log line
+///
+/// A log line can be deserialized into
+/// * a struct, fields are matched by name,
+/// * a map, like `HashMap<&str, &str>`, keyed by the Rust field names,
+/// * a sequence or tuple, containing the fields in log line order.
+#[derive(Debug, Clone)]
+pub struct Deserializer<'de> {
+    line: &'de str,
+}
+
+impl<'de> Deserializer<'de> {
+    pub fn new(line: &'de str) -> Self {
+        Self { line }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<Vis: de::Visitor<'de>>(self, visitor: Vis) -> Result<Vis::Value, Error> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_map<Vis: de::Visitor<'de>>(self, visitor: Vis) -> Result<Vis::Value, Error> {
+        visitor.visit_map(LineAccess::new(self.line, &[]))
+    }
+
+    fn deserialize_struct<Vis: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: Vis,
+    ) -> Result<Vis::Value, Error> {
+        visitor.visit_map(LineAccess::new(self.line, fields))
+    }
+
+    fn deserialize_seq<Vis: de::Visitor<'de>>(self, visitor: Vis) -> Result<Vis::Value, Error> {
+        visitor.visit_seq(LineAccess::new(self.line, &[]))
+    }
+
+    fn deserialize_tuple<Vis: de::Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: Vis,
+    ) -> Result<Vis::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<Vis: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: Vis,
+    ) -> Result<Vis::Value, Error> {
+        self.deserialize_seq(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct enum identifier ignored_any
+    }
+}
+
+// Iterates over the fields of the line;
+// if `wanted` is not empty, only fields with a matching name are yielded
+struct LineAccess<'de> {
+    fields: std::iter::Zip<
+        std::array::IntoIter<Field, { crate::consts::FIELDS }>,
+        MemchrTabSplitter<'de>,
+    >,
+    wanted: &'static [&'static str],
+    value: Option<&'de str>,
+}
+
+impl<'de> LineAccess<'de> {
+    fn new(line: &'de str, wanted: &'static [&'static str]) -> Self {
+        Self {
+            fields: Field::ALL.into_iter().zip(MemchrTabSplitter::new(line)),
+            wanted,
+            value: None,
+        }
+    }
+
+    // returns the key as requested by the caller (Rust name or header name)
+    fn wanted_key(&self, field: Field) -> Option<&'static str> {
+        if self.wanted.is_empty() {
+            return Some(field.name());
+        }
+        self.wanted
+            .iter()
+            .copied()
+            .find(|key| *key == field.name() || *key == field.header_name())
+    }
+}
+
+impl<'de> de::MapAccess<'de> for LineAccess<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        while let Some((field, value)) = self.fields.next() {
+            if let Some(key) = self.wanted_key(field) {
+                self.value = Some(value);
+                return seed
+                    .deserialize(BorrowedStrDeserializer::new(key))
+                    .map(Some);
+            }
+        }
+        Ok(None)
+    }
+
+    fn next_value_seed<Val: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: Val,
+    ) -> Result<Val::Value, Error> {
+        let value = self
+            .value
+            .take()
+            .ok_or_else(|| <Error as de::Error>::custom("value requested before key"))?;
+        seed.deserialize(FieldDeserializer(value))
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for LineAccess<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        self.fields
+            .next()
+            .map(|(_field, value)| seed.deserialize(FieldDeserializer(value)))
+            .transpose()
+    }
+}
+
+// Deserializer for a single field value
+struct FieldDeserializer<'de>(&'de str);
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident),* $(,)?) => {
+        $(
+            fn $method<Vis: de::Visitor<'de>>(self, visitor: Vis) -> Result<Vis::Value, Error> {
+                let value = self.0.parse().map_err(|_e| {
+                    <Error as de::Error>::invalid_value(de::Unexpected::Str(self.0), &visitor)
+                })?;
+                visitor.$visit(value)
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for FieldDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<Vis: de::Visitor<'de>>(self, visitor: Vis) -> Result<Vis::Value, Error> {
+        visitor.visit_borrowed_str(self.0)
+    }
+
+    deserialize_parsed!(
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    );
+
+    fn deserialize_option<Vis: de::Visitor<'de>>(self, visitor: Vis) -> Result<Vis::Value, Error> {
+        if self.0 == "-" {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<Vis: de::Visitor<'de>>(self, visitor: Vis) -> Result<Vis::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<Vis: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: Vis,
+    ) -> Result<Vis::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<Vis: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: Vis,
+    ) -> Result<Vis::Value, Error> {
+        visitor.visit_enum(IntoDeserializer::<Error>::into_deserializer(self.0))
+    }
+
+    serde::forward_to_deserialize_any! {
+        str string bytes byte_buf unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+// accepts borrowed and transient strings alike,
+// so the types below also deserialize from formats which cannot lend their input (like escaped JSON strings)
+struct StrVisitor;
+
+impl<'de> de::Visitor<'de> for StrVisitor {
+    type Value = Cow<'de, str>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, value: &'de str) -> Result<Self::Value, E> {
+        Ok(Cow::Borrowed(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(Cow::Owned(value))
+    }
+}
+
+fn deserialize_str<'de, D: de::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Cow<'de, str>, D::Error> {
+    deserializer.deserialize_str(StrVisitor)
+}
+
+// the types of this crate are deserialized from their log line representation
+macro_rules! impl_deserialize_from_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<'de> de::Deserialize<'de> for $ty {
+                fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let value = deserialize_str(deserializer)?;
+                    value.parse().map_err(de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+// the result types accept any name, unknown ones are copied
+macro_rules! impl_deserialize_result_type {
+    ($($ty:ident),* $(,)?) => {
+        $(
+            impl<'de, 'a> de::Deserialize<'de> for $ty<'a> {
+                fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let value = deserialize_str(deserializer)?;
+                    Ok($ty::from(&*value).into_owned())
+                }
+            }
+        )*
+    };
+}
+
+impl_deserialize_result_type!(EdgeResultType, DetailedEdgeResultType);
+
+impl_deserialize_from_str!(
+    CsProtocol,
+    CsProtocolVersion,
+    SslProtocol,
+    Addressable,
+    ForwardedForAddrs,
+    Field,
+);
@@ -2,8 +2,15 @@
 //!
 //! The log file format is described in the official documentation:
 //! <https://docs.aws.amazon.com/AmazonCloudFront/latest/DeveloperGuide/AccessLogs.html#LogFileFormat>
+//!
+//! # `no_std`
+//!
+//! With `default-features = false` the crate is `#![no_std]`;
+//! only [`validate_line`], the raw borrowed parsers ([`borrowed::raw`], [`borrowed::bytes::raw`]) and the [`LoglineView`] are available.
+//! The `alloc` feature adds the simple and owning variants, `std` everything which needs I/O or threads.
 
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![warn(
     clippy::all,
@@ -83,39 +90,80 @@
 // tabs_in_doc_comments: tab'ed CF log lines in examples
 #![allow(deprecated, clippy::tabs_in_doc_comments, clippy::mem_forget)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 // @@@ NEW STRUCTURE @@@
 
 mod shared;
 
 pub mod borrowed;
+#[cfg(feature = "bytes")]
+pub mod bytes_backed;
+#[cfg(feature = "alloc")]
+pub mod chunked;
+#[cfg(feature = "std")]
+pub mod codec;
+#[cfg(feature = "std")]
+pub mod columns;
 pub mod consts;
+#[cfg(feature = "serde")]
+pub mod de;
+#[cfg(feature = "alloc")]
 pub mod owned;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(feature = "std")]
+pub mod reader;
+#[cfg(feature = "alloc")]
+pub mod record;
+#[cfg(feature = "alloc")]
 pub mod referential; // not sure about the module name yet
 pub mod types;
 
+// without the date/time features, all constants are crate-internal
+#[allow(unused_imports)]
 pub use consts::*;
 pub use types::*;
 
 // useful helper function for minimizing validation needs
-pub use shared::validate_line;
+pub use shared::{validate_bytes, validate_line};
+
+#[cfg(feature = "alloc")]
+pub use record::CloudFrontRecord;
 
 #[doc(inline)]
-pub use borrowed::{
-    UnvalidatedRawLogline, UnvalidatedSimpleLogline, ValidatedRawLogline, ValidatedSimpleLogline,
-};
+pub use borrowed::{LoglineView, UnvalidatedRawLogline, ValidatedRawLogline};
+
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use borrowed::{UnvalidatedSimpleLogline, ValidatedSimpleLogline};
 
 #[cfg(feature = "chrono")]
 #[doc(inline)]
 pub use borrowed::typed::{UnvalidatedChronoLogline, ValidatedChronoLogline};
 
+#[cfg(feature = "chrono")]
+#[doc(inline)]
+pub use borrowed::{UnvalidatedProjectedLogline, ValidatedProjectedLogline};
+
 #[cfg(feature = "time")]
 #[doc(inline)]
 pub use borrowed::typed::{UnvalidatedTimeLogline, ValidatedTimeLogline};
 
+#[cfg(feature = "jiff")]
+#[doc(inline)]
+pub use borrowed::typed::{UnvalidatedJiffLogline, ValidatedJiffLogline};
+
 #[cfg(feature = "parquet")]
 #[doc(inline)]
 pub use borrowed::{UnvalidatedParquetLogline, ValidatedParquetLogline};
 
+#[cfg(feature = "parquet")]
+#[doc(inline)]
+pub use borrowed::{UnvalidatedParquetMillisLogline, ValidatedParquetMillisLogline};
+
+#[cfg(feature = "alloc")]
 #[doc(inline)]
 pub use referential::{
     UnvalidatedRawLogline as OwningUnvalidatedRawLogline,
@@ -138,6 +186,13 @@ pub use referential::typed::{
     ValidatedTimeLogline as OwningValidatedTimeLogline,
 };
 
+#[cfg(feature = "jiff")]
+#[doc(inline)]
+pub use referential::typed::{
+    UnvalidatedJiffLogline as OwningUnvalidatedJiffLogline,
+    ValidatedJiffLogline as OwningValidatedJiffLogline,
+};
+
 #[cfg(feature = "parquet")]
 #[doc(inline)]
 pub use referential::{
@@ -145,6 +200,20 @@ pub use referential::{
     ValidatedParquetLogline as OwningValidatedParquetLogline,
 };
 
+#[cfg(feature = "chrono")]
+#[doc(inline)]
+pub use owned::typed::{
+    UnvalidatedChronoLogline as OwnedUnvalidatedChronoLogline,
+    ValidatedChronoLogline as OwnedValidatedChronoLogline,
+};
+
+#[cfg(feature = "time")]
+#[doc(inline)]
+pub use owned::typed::{
+    UnvalidatedTimeLogline as OwnedUnvalidatedTimeLogline,
+    ValidatedTimeLogline as OwnedValidatedTimeLogline,
+};
+
 #[cfg(feature = "parquet")]
 #[doc(inline)]
 pub use owned::{
@@ -152,9 +221,16 @@ pub use owned::{
     ValidatedParquetLogline as OwnedValidatedParquetLogline,
 };
 
+#[cfg(feature = "parquet")]
+#[doc(inline)]
+pub use owned::{
+    UnvalidatedParquetMillisLogline as OwnedUnvalidatedParquetMillisLogline,
+    ValidatedParquetMillisLogline as OwnedValidatedParquetMillisLogline,
+};
+
 // === tests ===
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests;
 
 // !!! DEPRECATED !!!
@@ -163,18 +239,20 @@ mod tests;
     since = "0.7.0",
     note = "use new modules/types instead (borrowed, owned, referential)"
 )]
+#[cfg(feature = "std")]
 mod raw;
 #[deprecated(
     since = "0.7.0",
     note = "use new modules/types instead (borrowed, owned, referential)"
 )]
+#[cfg(feature = "std")]
 mod simple;
 
 #[deprecated(
     since = "0.7.0",
     note = "use new modules/types instead (borrowed, owned, referential)"
 )]
-#[cfg(feature = "time")]
+#[cfg(all(feature = "std", feature = "time"))]
 mod typed;
 
 #[deprecated(
@@ -188,10 +266,10 @@ mod parquet;
     since = "0.7.0",
     note = "use new modules/types instead (borrowed, owned, referential)"
 )]
+#[cfg(feature = "std")]
 pub mod deprecated {
     pub use crate::raw::{CheckedRawLogLine, CheckedRawLogLineView, SmartRawLogLineView};
 
-    #[cfg(feature = "alloc")]
     pub use crate::simple::SimpleLogLine;
 
     #[cfg(feature = "time")]
@@ -201,4 +279,5 @@ pub mod deprecated {
     pub use crate::parquet::ParquetLogLine;
 }
 
+#[cfg(feature = "std")]
 pub use deprecated::*;
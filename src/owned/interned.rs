@@ -0,0 +1,175 @@
+use crate::{borrowed::simple::Logline as BorrowedLogline, types::*};
+use std::collections::HashSet;
+
+pub type ValidatedLogline = Logline<Validated>;
+pub type UnvalidatedLogline = Logline<Unvalidated>;
+
+/// A set of shared strings, handing out the same [`Arc<str>`] for equal values
+///
+/// Pass the same interner to the parsing of many lines,
+/// so repeated values like edge locations, hosts and user agents are stored only once.
+/// Dropping the interner does not affect the loglines, they keep their strings alive.
+///
+/// # Examples
+///
+/// ```rust
+/// use cloudfront_logs::owned::interned::Interner;
+/// use std::sync::Arc;
+///
+/// let mut interner = Interner::new();
+/// let a = interner.intern("LAX1");
+/// let b = interner.intern("LAX1");
+///
+/// assert!(Arc::ptr_eq(&a, &b));
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    strings: HashSet<Arc<str>>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the shared string for the value, storing it on first use
+    pub fn intern(&mut self, value: &str) -> Arc<str> {
+        if let Some(interned) = self.strings.get(value) {
+            return Arc::clone(interned);
+        }
+        let interned: Arc<str> = value.into();
+        self.strings.insert(Arc::clone(&interned));
+        interned
+    }
+
+    /// Number of distinct strings
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Forgets all strings; loglines parsed so far keep theirs
+    pub fn clear(&mut self) {
+        self.strings.clear();
+    }
+}
+
+/// An owned log line, which shares the values of low-cardinality fields via an [`Interner`]
+///
+/// Same as the [simple](crate::owned::simple) variant,
+/// except for `x_edge_location`, `cs_host`, `cs_user_agent`, `x_host_header` and `sc_content_type`,
+/// which hold interned [`Arc<str>`] instead of [`String`] values.
+/// Other low-cardinality fields like `cs_protocol_version` are already stored as enums.
+///
+/// # Examples
+///
+/// ```rust
+/// use cloudfront_logs::owned::interned::{Interner, ValidatedLogline};
+/// use std::sync::Arc;
+///
+/// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let mut interner = Interner::new();
+/// let a = ValidatedLogline::parse(line, &mut interner).unwrap();
+/// let b = ValidatedLogline::parse(line, &mut interner).unwrap();
+///
+/// assert_eq!(&*a.x_edge_location, "LAX1");
+/// assert!(Arc::ptr_eq(&a.cs_user_agent, &b.cs_user_agent));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logline<V> {
+    pub date: String,
+    pub time: String,
+    pub x_edge_location: Arc<str>,
+    pub sc_bytes: u64,
+    pub c_ip: IpAddr,
+    pub cs_method: String,
+    pub cs_host: Arc<str>,
+    pub cs_uri_stem: String,
+    pub sc_status: u16,
+    pub cs_referer: Option<String>,
+    pub cs_user_agent: Arc<str>,
+    pub cs_uri_query: Option<String>,
+    pub cs_cookie: Option<String>,
+    pub x_edge_result_type: EdgeResultType<'static>,
+    pub x_edge_request_id: String,
+    pub x_host_header: Arc<str>,
+    pub cs_protocol: CsProtocol,
+    pub cs_bytes: u64,
+    pub time_taken: Duration,
+    pub x_forwarded_for: Option<ForwardedForAddrs>,
+    pub ssl_protocol: Option<SslProtocol>,
+    pub ssl_cipher: Option<String>,
+    pub x_edge_response_result_type: EdgeResultType<'static>,
+    pub cs_protocol_version: CsProtocolVersion,
+    pub fle_status: Option<String>,
+    pub fle_encrypted_fields: Option<u64>,
+    pub c_port: u16,
+    pub time_to_first_byte: Duration,
+    pub x_edge_detailed_result_type: DetailedEdgeResultType<'static>,
+    pub sc_content_type: Option<Arc<str>>,
+    pub sc_content_len: Option<u64>,
+    pub sc_range_start: Option<i64>,
+    pub sc_range_end: Option<i64>,
+    __marker: PhantomData<V>,
+}
+
+impl Logline<Validated> {
+    pub fn parse(line: &str, interner: &mut Interner) -> Result<Self, &'static str> {
+        let borrowed = BorrowedLogline::<Validated>::try_from(line)?;
+        Ok(Self::from_borrowed(borrowed, interner))
+    }
+}
+
+impl Logline<Unvalidated> {
+    pub fn parse(line: &str, interner: &mut Interner) -> Result<Self, &'static str> {
+        let borrowed = BorrowedLogline::<Unvalidated>::try_from(line)?;
+        Ok(Self::from_borrowed(borrowed, interner))
+    }
+}
+
+impl<V> Logline<V> {
+    /// Copies the fields of the [borrowed](crate::borrowed::simple) variant, interning the shared ones
+    pub fn from_borrowed(borrowed: BorrowedLogline<'_, V>, interner: &mut Interner) -> Self {
+        Self {
+            date: borrowed.date.to_string(),
+            time: borrowed.time.to_string(),
+            x_edge_location: interner.intern(borrowed.x_edge_location),
+            sc_bytes: borrowed.sc_bytes,
+            c_ip: borrowed.c_ip,
+            cs_method: borrowed.cs_method.to_string(),
+            cs_host: interner.intern(borrowed.cs_host),
+            cs_uri_stem: borrowed.cs_uri_stem.to_string(),
+            sc_status: borrowed.sc_status,
+            cs_referer: borrowed.cs_referer.map(ToString::to_string),
+            cs_user_agent: interner.intern(borrowed.cs_user_agent),
+            cs_uri_query: borrowed.cs_uri_query.map(ToString::to_string),
+            cs_cookie: borrowed.cs_cookie.map(ToString::to_string),
+            x_edge_result_type: borrowed.x_edge_result_type.into_owned(),
+            x_edge_request_id: borrowed.x_edge_request_id.to_string(),
+            x_host_header: interner.intern(borrowed.x_host_header),
+            cs_protocol: borrowed.cs_protocol,
+            cs_bytes: borrowed.cs_bytes,
+            time_taken: borrowed.time_taken,
+            x_forwarded_for: borrowed.x_forwarded_for,
+            ssl_protocol: borrowed.ssl_protocol,
+            ssl_cipher: borrowed.ssl_cipher.map(ToString::to_string),
+            x_edge_response_result_type: borrowed.x_edge_response_result_type.into_owned(),
+            cs_protocol_version: borrowed.cs_protocol_version,
+            fle_status: borrowed.fle_status.map(ToString::to_string),
+            fle_encrypted_fields: borrowed.fle_encrypted_fields,
+            c_port: borrowed.c_port,
+            time_to_first_byte: borrowed.time_to_first_byte,
+            x_edge_detailed_result_type: borrowed.x_edge_detailed_result_type.into_owned(),
+            sc_content_type: borrowed.sc_content_type.map(|value| interner.intern(value)),
+            sc_content_len: borrowed.sc_content_len,
+            sc_range_start: borrowed.sc_range_start,
+            sc_range_end: borrowed.sc_range_end,
+            __marker: PhantomData,
+        }
+    }
+}
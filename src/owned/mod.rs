@@ -1,15 +1,45 @@
-// todo: add more variants (raw, typed, parquet)
-
+#[cfg(feature = "std")]
+pub mod interned;
+pub mod raw;
 pub mod simple;
+pub mod typed;
+
+pub use raw::{
+    UnvalidatedLogline as UnvalidatedRawLogline, ValidatedLogline as ValidatedRawLogline,
+};
 
 pub use simple::{
     UnvalidatedLogline as UnvalidatedSimpleLogline, ValidatedLogline as ValidatedSimpleLogline,
 };
 
+#[cfg(feature = "std")]
+pub use interned::{
+    Interner, UnvalidatedLogline as UnvalidatedInternedLogline,
+    ValidatedLogline as ValidatedInternedLogline,
+};
+
+#[cfg(feature = "chrono")]
+pub use typed::chrono::{
+    UnvalidatedLogline as UnvalidatedChronoLogline, ValidatedLogline as ValidatedChronoLogline,
+};
+
+#[cfg(feature = "time")]
+pub use typed::time::{
+    UnvalidatedLogline as UnvalidatedTimeLogline, ValidatedLogline as ValidatedTimeLogline,
+};
+
 #[cfg(feature = "parquet")]
 pub mod parquet;
+#[cfg(feature = "parquet")]
+pub mod parquet_millis;
 
 #[cfg(feature = "parquet")]
 pub use parquet::{
     UnvalidatedLogline as UnvalidatedParquetLogline, ValidatedLogline as ValidatedParquetLogline,
 };
+
+#[cfg(feature = "parquet")]
+pub use parquet_millis::{
+    UnvalidatedLogline as UnvalidatedParquetMillisLogline,
+    ValidatedLogline as ValidatedParquetMillisLogline,
+};
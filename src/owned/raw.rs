@@ -0,0 +1,248 @@
+use crate::{borrowed::raw::Logline as BorrowedLogline, shared::*, types::*};
+
+/// The validated raw log line owning its field data
+///
+/// All fields are [`String`]s, copied from the original log line.
+/// Prefer the [borrowed](crate::borrowed::raw) variant if the source string lives long enough,
+/// or the [referential](crate::referential::raw) one if a single allocation per line is preferred.
+///
+/// On construction it checks if the line can be parsed.
+/// This is useful if you cannot skip the comment lines or have reason to not trust the input for format correctness.
+/// The latter should be only an issue if you do not use this crate on CloudFront logs directly.
+///
+/// # Examples
+///
+/// Use `.try_from()` or `.try_into()` to construct an instance, since action can fail.
+///
+/// ```rust
+/// use cloudfront_logs::{owned::raw::ValidatedLogline, types::*};
+///
+/// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let item = ValidatedLogline::try_from(line).unwrap();
+/// // alternative:
+/// let item: ValidatedLogline = line.try_into().unwrap();
+///
+/// assert_eq!(item.date, "2019-12-04");
+/// assert_eq!(item.sc_bytes, "392");
+/// assert_eq!(item.get(Field::CIp), "192.0.2.100");
+/// ```
+pub type ValidatedLogline = Logline<Validated>;
+
+/// The unvalidated raw log line owning its field data
+///
+/// All fields are [`String`]s, copied from the original log line.
+///
+/// Unlike [`ValidatedLogline`], this variant does not check if the line can be parsed.
+/// Use this if you already did a check before creating this struct.
+/// A common scenario is that you 1) trust the input data and 2) skipped the comment lines.
+///
+/// # Panics
+///
+/// Construction never panics.
+/// If the input has fewer fields than expected, the missing fields are empty strings.
+///
+/// # Examples
+///
+/// Use `.from()` or `.into()` to construct an instance, since no validation is done.
+///
+/// ```rust
+/// use cloudfront_logs::{owned::raw::UnvalidatedLogline, types::*};
+///
+/// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let item = UnvalidatedLogline::from(line);
+/// // alternative:
+/// let item: UnvalidatedLogline = line.into();
+///
+/// assert_eq!(item.date, "2019-12-04");
+/// assert_eq!(item.sc_bytes, "392");
+/// assert_eq!(item.c_ip, "192.0.2.100");
+/// ```
+pub type UnvalidatedLogline = Logline<Unvalidated>;
+
+/// The generic, raw and owned log line type
+///
+/// Do not use it directly, prefer [`ValidatedLogline`] or [`UnvalidatedLogline`] instead.
+#[must_use]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logline<V> {
+    pub date: String,
+    pub time: String,
+    pub x_edge_location: String,
+    pub sc_bytes: String,
+    pub c_ip: String,
+    pub cs_method: String,
+    pub cs_host: String,
+    pub cs_uri_stem: String,
+    pub sc_status: String,
+    pub cs_referer: String,
+    pub cs_user_agent: String,
+    pub cs_uri_query: String,
+    pub cs_cookie: String,
+    pub x_edge_result_type: String,
+    pub x_edge_request_id: String,
+    pub x_host_header: String,
+    pub cs_protocol: String,
+    pub cs_bytes: String,
+    pub time_taken: String,
+    pub x_forwarded_for: String,
+    pub ssl_protocol: String,
+    pub ssl_cipher: String,
+    pub x_edge_response_result_type: String,
+    pub cs_protocol_version: String,
+    pub fle_status: String,
+    pub fle_encrypted_fields: String,
+    pub c_port: String,
+    pub time_to_first_byte: String,
+    pub x_edge_detailed_result_type: String,
+    pub sc_content_type: String,
+    pub sc_content_len: String,
+    pub sc_range_start: String,
+    pub sc_range_end: String,
+    __marker: PhantomData<V>,
+}
+
+impl<V> Logline<V> {
+    /// Returns the field, see [`borrowed::raw::Logline::get`](crate::borrowed::raw::Logline::get)
+    pub fn get(&self, field: Field) -> &str {
+        self.as_borrowed().get(field)
+    }
+
+    /// Returns a borrowed view of the line, keeping the validation marker
+    pub fn as_borrowed(&self) -> BorrowedLogline<'_, V> {
+        BorrowedLogline::from(self)
+    }
+}
+
+impl TryFrom<&str> for Logline<Validated> {
+    type Error = &'static str;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        BorrowedLogline::<'_, Validated>::try_from(line).map(Self::from)
+    }
+}
+
+impl From<&str> for Logline<Unvalidated> {
+    fn from(line: &str) -> Self {
+        BorrowedLogline::<'_, Unvalidated>::from(line).into()
+    }
+}
+
+impl Logline<Validated> {
+    /// Parses the line into this struct, reusing the buffers of the [`String`] fields
+    ///
+    /// Use it instead of [`TryFrom`] to parse many lines in a loop without allocating for each of them.
+    /// On error, the line is left unchanged.
+    pub fn parse_into(&mut self, line: &str) -> Result<(), &'static str> {
+        let borrowed = BorrowedLogline::<'_, Validated>::try_from(line)?;
+        assign_from_borrowed(self, &borrowed);
+        Ok(())
+    }
+}
+
+impl Logline<Unvalidated> {
+    /// Parses the line into this struct, reusing the buffers of the [`String`] fields
+    ///
+    /// Like [`From`], missing fields become empty strings.
+    pub fn parse_into(&mut self, line: &str) {
+        let borrowed = BorrowedLogline::<'_, Unvalidated>::from(line);
+        assign_from_borrowed(self, &borrowed);
+    }
+}
+
+impl<V> From<BorrowedLogline<'_, V>> for Logline<V> {
+    fn from(borrowed: BorrowedLogline<'_, V>) -> Self {
+        from_borrowed(borrowed)
+    }
+}
+
+// copies the fields of any borrowed line;
+// the referential lines use it to keep their own validation marker
+pub(crate) fn from_borrowed<V, W>(borrowed: BorrowedLogline<'_, V>) -> Logline<W> {
+    Logline {
+        date: borrowed.date.to_string(),
+        time: borrowed.time.to_string(),
+        x_edge_location: borrowed.x_edge_location.to_string(),
+        sc_bytes: borrowed.sc_bytes.to_string(),
+        c_ip: borrowed.c_ip.to_string(),
+        cs_method: borrowed.cs_method.to_string(),
+        cs_host: borrowed.cs_host.to_string(),
+        cs_uri_stem: borrowed.cs_uri_stem.to_string(),
+        sc_status: borrowed.sc_status.to_string(),
+        cs_referer: borrowed.cs_referer.to_string(),
+        cs_user_agent: borrowed.cs_user_agent.to_string(),
+        cs_uri_query: borrowed.cs_uri_query.to_string(),
+        cs_cookie: borrowed.cs_cookie.to_string(),
+        x_edge_result_type: borrowed.x_edge_result_type.to_string(),
+        x_edge_request_id: borrowed.x_edge_request_id.to_string(),
+        x_host_header: borrowed.x_host_header.to_string(),
+        cs_protocol: borrowed.cs_protocol.to_string(),
+        cs_bytes: borrowed.cs_bytes.to_string(),
+        time_taken: borrowed.time_taken.to_string(),
+        x_forwarded_for: borrowed.x_forwarded_for.to_string(),
+        ssl_protocol: borrowed.ssl_protocol.to_string(),
+        ssl_cipher: borrowed.ssl_cipher.to_string(),
+        x_edge_response_result_type: borrowed.x_edge_response_result_type.to_string(),
+        cs_protocol_version: borrowed.cs_protocol_version.to_string(),
+        fle_status: borrowed.fle_status.to_string(),
+        fle_encrypted_fields: borrowed.fle_encrypted_fields.to_string(),
+        c_port: borrowed.c_port.to_string(),
+        time_to_first_byte: borrowed.time_to_first_byte.to_string(),
+        x_edge_detailed_result_type: borrowed.x_edge_detailed_result_type.to_string(),
+        sc_content_type: borrowed.sc_content_type.to_string(),
+        sc_content_len: borrowed.sc_content_len.to_string(),
+        sc_range_start: borrowed.sc_range_start.to_string(),
+        sc_range_end: borrowed.sc_range_end.to_string(),
+        __marker: PhantomData,
+    }
+}
+
+// the in-place counterpart of `from_borrowed`
+fn assign_from_borrowed<V, W>(target: &mut Logline<W>, borrowed: &BorrowedLogline<'_, V>) {
+    assign_string(&mut target.date, borrowed.date);
+    assign_string(&mut target.time, borrowed.time);
+    assign_string(&mut target.x_edge_location, borrowed.x_edge_location);
+    assign_string(&mut target.sc_bytes, borrowed.sc_bytes);
+    assign_string(&mut target.c_ip, borrowed.c_ip);
+    assign_string(&mut target.cs_method, borrowed.cs_method);
+    assign_string(&mut target.cs_host, borrowed.cs_host);
+    assign_string(&mut target.cs_uri_stem, borrowed.cs_uri_stem);
+    assign_string(&mut target.sc_status, borrowed.sc_status);
+    assign_string(&mut target.cs_referer, borrowed.cs_referer);
+    assign_string(&mut target.cs_user_agent, borrowed.cs_user_agent);
+    assign_string(&mut target.cs_uri_query, borrowed.cs_uri_query);
+    assign_string(&mut target.cs_cookie, borrowed.cs_cookie);
+    assign_string(&mut target.x_edge_result_type, borrowed.x_edge_result_type);
+    assign_string(&mut target.x_edge_request_id, borrowed.x_edge_request_id);
+    assign_string(&mut target.x_host_header, borrowed.x_host_header);
+    assign_string(&mut target.cs_protocol, borrowed.cs_protocol);
+    assign_string(&mut target.cs_bytes, borrowed.cs_bytes);
+    assign_string(&mut target.time_taken, borrowed.time_taken);
+    assign_string(&mut target.x_forwarded_for, borrowed.x_forwarded_for);
+    assign_string(&mut target.ssl_protocol, borrowed.ssl_protocol);
+    assign_string(&mut target.ssl_cipher, borrowed.ssl_cipher);
+    assign_string(
+        &mut target.x_edge_response_result_type,
+        borrowed.x_edge_response_result_type,
+    );
+    assign_string(
+        &mut target.cs_protocol_version,
+        borrowed.cs_protocol_version,
+    );
+    assign_string(&mut target.fle_status, borrowed.fle_status);
+    assign_string(
+        &mut target.fle_encrypted_fields,
+        borrowed.fle_encrypted_fields,
+    );
+    assign_string(&mut target.c_port, borrowed.c_port);
+    assign_string(&mut target.time_to_first_byte, borrowed.time_to_first_byte);
+    assign_string(
+        &mut target.x_edge_detailed_result_type,
+        borrowed.x_edge_detailed_result_type,
+    );
+    assign_string(&mut target.sc_content_type, borrowed.sc_content_type);
+    assign_string(&mut target.sc_content_len, borrowed.sc_content_len);
+    assign_string(&mut target.sc_range_start, borrowed.sc_range_start);
+    assign_string(&mut target.sc_range_end, borrowed.sc_range_end);
+}
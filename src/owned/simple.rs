@@ -1,5 +1,8 @@
 use crate::{
-    borrowed::raw::{UnvalidatedLogline as UnvalidatedRaw, ValidatedLogline as ValidatedRaw},
+    borrowed::{
+        raw::{UnvalidatedLogline as UnvalidatedRaw, ValidatedLogline as ValidatedRaw},
+        simple::Logline as BorrowedLogline,
+    },
     shared::*,
     types::*,
 };
@@ -11,6 +14,9 @@ pub type UnvalidatedLogline = Logline<Unvalidated>;
 ///
 /// Only primitive types from Rust's core/std library and types composable from them are used for the fields.
 /// Therefore types like Date and Time are not present, because they require external dependencies.
+///
+/// Like all other parsed variants, optional fields (including `sc_content_type` and `sc_content_len`)
+/// are `None` if the log line holds a `-`.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Logline<V> {
     pub date: String,
@@ -26,7 +32,7 @@ pub struct Logline<V> {
     pub cs_user_agent: String,
     pub cs_uri_query: Option<String>,
     pub cs_cookie: Option<String>,
-    pub x_edge_result_type: EdgeResultType,
+    pub x_edge_result_type: EdgeResultType<'static>,
     pub x_edge_request_id: String,
     pub x_host_header: String,
     pub cs_protocol: CsProtocol,
@@ -35,15 +41,15 @@ pub struct Logline<V> {
     pub x_forwarded_for: Option<ForwardedForAddrs>,
     pub ssl_protocol: Option<SslProtocol>,
     pub ssl_cipher: Option<String>, // *1
-    pub x_edge_response_result_type: EdgeResultType,
+    pub x_edge_response_result_type: EdgeResultType<'static>,
     pub cs_protocol_version: CsProtocolVersion,
     pub fle_status: Option<String>, // *1
     pub fle_encrypted_fields: Option<u64>,
     pub c_port: u16,
     pub time_to_first_byte: Duration,
-    pub x_edge_detailed_result_type: DetailedEdgeResultType,
-    pub sc_content_type: String,
-    pub sc_content_len: u64,
+    pub x_edge_detailed_result_type: DetailedEdgeResultType<'static>,
+    pub sc_content_type: Option<String>,
+    pub sc_content_len: Option<u64>,
     pub sc_range_start: Option<i64>,
     pub sc_range_end: Option<i64>,
     __marker: PhantomData<V>,
@@ -73,114 +79,198 @@ fn new_log_line<V>(line: &str) -> Result<Logline<V>, &'static str> {
     let mut iter = MemchrTabSplitter::new(line);
 
     let line = Logline {
-        date: iter.next().unwrap().to_string(),
-        time: iter.next().unwrap().to_string(),
-        x_edge_location: iter.next().unwrap().to_string(),
+        date: iter.field()?.to_string(),
+        time: iter.field()?.to_string(),
+        x_edge_location: iter.field()?.to_string(),
         sc_bytes: iter
-            .next()
-            .unwrap()
+            .field()?
             .parse::<u64>()
             .map_err(|_e| "sc_bytes invalid")?,
-        c_ip: iter.next().unwrap().parse().map_err(|_e| "c_ip invalid")?,
-        cs_method: iter.next().unwrap().to_string(),
-        cs_host: iter.next().unwrap().to_string(),
-        cs_uri_stem: iter.next().unwrap().to_string(),
+        c_ip: iter.field()?.parse().map_err(|_e| "c_ip invalid")?,
+        cs_method: iter.field()?.to_string(),
+        cs_host: iter.field()?.to_string(),
+        cs_uri_stem: iter.field()?.to_string(),
         sc_status: iter
-            .next()
-            .unwrap()
+            .field()?
             .parse::<u16>()
             .map_err(|_e| "sc_status invalid")?,
-        cs_referer: iter.next().unwrap().to_optional_string(),
-        cs_user_agent: iter.next().unwrap().to_string(),
-        cs_uri_query: iter.next().unwrap().to_optional_string(),
-        cs_cookie: iter.next().unwrap().to_optional_string(),
+        cs_referer: iter.field()?.to_optional_string(),
+        cs_user_agent: iter.field()?.to_string(),
+        cs_uri_query: iter.field()?.to_optional_string(),
+        cs_cookie: iter.field()?.to_optional_string(),
         x_edge_result_type: iter
-            .next()
-            .unwrap()
+            .field()?
             .parse()
             .map_err(|_e| "x_edge_result_type invalid")?,
-        x_edge_request_id: iter.next().unwrap().to_string(),
-        x_host_header: iter.next().unwrap().to_string(),
-        cs_protocol: iter
-            .next()
-            .unwrap()
-            .parse()
-            .map_err(|_e| "cs_protocol invalid")?,
+        x_edge_request_id: iter.field()?.to_string(),
+        x_host_header: iter.field()?.to_string(),
+        cs_protocol: iter.field()?.parse().map_err(|_e| "cs_protocol invalid")?,
         cs_bytes: iter
-            .next()
-            .unwrap()
+            .field()?
             .parse::<u64>()
             .map_err(|_e| "cs_bytes invalid")?,
-        time_taken: iter
-            .next()
-            .unwrap()
-            .parse::<f64>()
-            .map(Duration::from_secs_f64)
-            .map_err(|_e| "time_taken invalid")?,
-        x_forwarded_for: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "x_forwarded_for invalid")?,
-        ssl_protocol: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "ssl_protocol invalid")?,
-        ssl_cipher: iter.next().unwrap().to_optional_string(),
+        time_taken: parse_duration(iter.field()?).map_err(|_e| "time_taken invalid")?,
+        x_forwarded_for: parse_as_option(iter.field()?).map_err(|_e| "x_forwarded_for invalid")?,
+        ssl_protocol: parse_as_option(iter.field()?).map_err(|_e| "ssl_protocol invalid")?,
+        ssl_cipher: iter.field()?.to_optional_string(),
         x_edge_response_result_type: iter
-            .next()
-            .unwrap()
+            .field()?
             .parse()
             .map_err(|_e| "x_edge_response_result_type invalid")?,
         cs_protocol_version: iter
-            .next()
-            .unwrap()
+            .field()?
             .parse()
             .map_err(|_e| "cs_protocol_version invalid")?,
-        fle_status: iter.next().unwrap().to_optional_string(),
-        fle_encrypted_fields: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
+        fle_status: iter.field()?.to_optional_string(),
+        fle_encrypted_fields: parse_as_option(iter.field()?)
             .map_err(|_e| "fle_encrypted_fields invalid")?,
         c_port: iter
-            .next()
-            .unwrap()
+            .field()?
             .parse::<u16>()
             .map_err(|_e| "c_port invalid")?,
-        time_to_first_byte: iter
-            .next()
-            .unwrap()
-            .parse::<f64>()
-            .map(Duration::from_secs_f64)
+        time_to_first_byte: parse_duration(iter.field()?)
             .map_err(|_e| "time_to_first_byte invalid")?,
         x_edge_detailed_result_type: iter
-            .next()
-            .unwrap()
+            .field()?
             .parse()
             .map_err(|_e| "x_edge_detailed_result_type invalid")?,
-        sc_content_type: iter.next().unwrap().to_string(),
-        sc_content_len: iter
-            .next()
-            .unwrap()
-            .parse::<u64>()
-            .map_err(|_e| "sc_content_len invalid")?,
-        sc_range_start: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "sc_range_start invalid")?,
-        sc_range_end: iter
-            .next()
-            .and_then(as_optional_t)
-            .transpose()
-            .map_err(|_e| "sc_range_end invalid")?,
+        sc_content_type: iter.field()?.to_optional_string(),
+        sc_content_len: parse_as_option(iter.field()?).map_err(|_e| "sc_content_len invalid")?,
+        sc_range_start: parse_as_option(iter.field()?).map_err(|_e| "sc_range_start invalid")?,
+        sc_range_end: parse_as_option(iter.field()?).map_err(|_e| "sc_range_end invalid")?,
         __marker: PhantomData,
     };
     Ok(line)
 }
 
+impl Logline<Validated> {
+    /// Parses the line into this struct, reusing the buffers of the [`String`] and [`Vec`] fields
+    ///
+    /// Use it instead of [`TryFrom`] to parse many lines in a loop without allocating for each of them.
+    /// On error, the fields hold a mix of the previous and the new line and should not be used.
+    ///
+    /// Optional fields are set to `None` for `-`, which drops their buffer:
+    /// a value in a later line allocates again. Fields which are always present keep their buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use cloudfront_logs::{owned::simple::ValidatedLogline, types::*};
+    ///
+    /// let lines = [
+    ///     "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-",
+    ///     "2019-12-13	22:37:02	SEA19-C2	900	192.0.2.200	GET	d111111abcdef8.cloudfront.net	/	502	-	curl/7.55.1	-	-	Error	kBkDzGnceVtWHqSCqBUqtA_cEs2T3tFUBbnBNkB9El_uVRhHgcZfcw==	www.example.com	http	387	0.103	-	-	-	Error	HTTP/1.1	-	-	12644	0.103	OriginDnsError	text/html	507	-	-",
+    /// ];
+    ///
+    /// let mut item = ValidatedLogline::try_from(lines[0]).unwrap();
+    /// for line in lines {
+    ///     item.parse_into(line).unwrap();
+    ///     assert_eq!(item.cs_method, "GET");
+    /// }
+    /// assert_eq!(item.x_edge_location, "SEA19-C2");
+    /// ```
+    pub fn parse_into(&mut self, line: &str) -> Result<(), &'static str> {
+        validate_line(line)?;
+        parse_into(self, line)
+    }
+}
+
+impl Logline<Unvalidated> {
+    /// Parses the line into this struct, reusing the buffers of the [`String`] and [`Vec`] fields
+    ///
+    /// See [`ValidatedLogline::parse_into`] for details.
+    pub fn parse_into(&mut self, line: &str) -> Result<(), &'static str> {
+        parse_into(self, line)
+    }
+}
+
+// the in-place counterpart of the regular parsing
+fn parse_into<V>(target: &mut Logline<V>, line: &str) -> Result<(), &'static str> {
+    let mut iter = MemchrTabSplitter::new(line);
+
+    assign_string(&mut target.date, iter.field()?);
+    assign_string(&mut target.time, iter.field()?);
+    assign_string(&mut target.x_edge_location, iter.field()?);
+    target.sc_bytes = iter
+        .field()?
+        .parse::<u64>()
+        .map_err(|_e| "sc_bytes invalid")?;
+    target.c_ip = iter.field()?.parse().map_err(|_e| "c_ip invalid")?;
+    assign_string(&mut target.cs_method, iter.field()?);
+    assign_string(&mut target.cs_host, iter.field()?);
+    assign_string(&mut target.cs_uri_stem, iter.field()?);
+    target.sc_status = iter
+        .field()?
+        .parse::<u16>()
+        .map_err(|_e| "sc_status invalid")?;
+    assign_optional_string(&mut target.cs_referer, iter.field()?);
+    assign_string(&mut target.cs_user_agent, iter.field()?);
+    assign_optional_string(&mut target.cs_uri_query, iter.field()?);
+    assign_optional_string(&mut target.cs_cookie, iter.field()?);
+    target.x_edge_result_type.parse_into(iter.field()?);
+    assign_string(&mut target.x_edge_request_id, iter.field()?);
+    assign_string(&mut target.x_host_header, iter.field()?);
+    target.cs_protocol = iter.field()?.parse().map_err(|_e| "cs_protocol invalid")?;
+    target.cs_bytes = iter
+        .field()?
+        .parse::<u64>()
+        .map_err(|_e| "cs_bytes invalid")?;
+    target.time_taken = parse_duration(iter.field()?).map_err(|_e| "time_taken invalid")?;
+    assign_forwarded_for(&mut target.x_forwarded_for, iter.field()?)
+        .map_err(|_e| "x_forwarded_for invalid")?;
+    target.ssl_protocol = parse_as_option(iter.field()?).map_err(|_e| "ssl_protocol invalid")?;
+    assign_optional_string(&mut target.ssl_cipher, iter.field()?);
+    target.x_edge_response_result_type.parse_into(iter.field()?);
+    target.cs_protocol_version = iter
+        .field()?
+        .parse()
+        .map_err(|_e| "cs_protocol_version invalid")?;
+    assign_optional_string(&mut target.fle_status, iter.field()?);
+    target.fle_encrypted_fields =
+        parse_as_option(iter.field()?).map_err(|_e| "fle_encrypted_fields invalid")?;
+    target.c_port = iter
+        .field()?
+        .parse::<u16>()
+        .map_err(|_e| "c_port invalid")?;
+    target.time_to_first_byte =
+        parse_duration(iter.field()?).map_err(|_e| "time_to_first_byte invalid")?;
+    target.x_edge_detailed_result_type.parse_into(iter.field()?);
+    assign_optional_string(&mut target.sc_content_type, iter.field()?);
+    target.sc_content_len =
+        parse_as_option(iter.field()?).map_err(|_e| "sc_content_len invalid")?;
+    target.sc_range_start =
+        parse_as_option(iter.field()?).map_err(|_e| "sc_range_start invalid")?;
+    target.sc_range_end = parse_as_option(iter.field()?).map_err(|_e| "sc_range_end invalid")?;
+    Ok(())
+}
+
+impl Logline<Validated> {
+    /// Parses the line, falling back to placeholders for fields which cannot be parsed
+    ///
+    /// Unlike [`TryFrom`], a single corrupted field does not discard the whole line:
+    /// the returned [`Lenient`] holds the logline and the list of fields which could not be parsed.
+    /// See [`Lenient`] for the placeholder values.
+    /// The line itself still has to be valid, e.g. have the right number of fields.
+    pub fn parse_lenient(line: &str) -> Result<Lenient<Self>, &'static str> {
+        validate_line(line)?;
+        parse_lenient(line)
+    }
+}
+
+impl Logline<Unvalidated> {
+    /// Parses the line, falling back to placeholders for fields which cannot be parsed
+    ///
+    /// See [`ValidatedLogline::parse_lenient`] for details.
+    pub fn parse_lenient(line: &str) -> Result<Lenient<Self>, &'static str> {
+        parse_lenient(line)
+    }
+}
+
+// the borrowed lenient parsing, with the fields copied into the owned line
+fn parse_lenient<V>(line: &str) -> Result<Lenient<Logline<V>>, &'static str> {
+    crate::borrowed::simple::parse_lenient(line).map(|lenient| lenient.map(Into::into))
+}
+
 impl Logline<Validated> {
     pub fn try_from_with_raw(line: &str) -> Result<Self, &'static str> {
         let raw = ValidatedRaw::try_from(line)?;
@@ -235,11 +325,7 @@ impl TryFrom<ValidatedRaw<'_>> for Logline<Validated> {
                 .cs_bytes
                 .parse::<u64>()
                 .map_err(|_e| "cs_bytes invalid")?,
-            time_taken: raw
-                .time_taken
-                .parse::<f64>()
-                .map(Duration::from_secs_f64)
-                .map_err(|_e| "time_taken invalid")?,
+            time_taken: parse_duration(raw.time_taken).map_err(|_e| "time_taken invalid")?,
             x_forwarded_for: parse_as_option(raw.x_forwarded_for)
                 .map_err(|_e| "x_forwarded_for invalid")?,
             ssl_protocol: parse_as_option(raw.ssl_protocol).map_err(|_e| "ssl_protocol invalid")?,
@@ -256,19 +342,14 @@ impl TryFrom<ValidatedRaw<'_>> for Logline<Validated> {
             fle_encrypted_fields: parse_as_option(raw.fle_encrypted_fields)
                 .map_err(|_e| "fle_encrypted_fields invalid")?,
             c_port: raw.c_port.parse::<u16>().map_err(|_e| "c_port invalid")?,
-            time_to_first_byte: raw
-                .time_to_first_byte
-                .parse::<f64>()
-                .map(Duration::from_secs_f64)
+            time_to_first_byte: parse_duration(raw.time_to_first_byte)
                 .map_err(|_e| "time_to_first_byte invalid")?,
             x_edge_detailed_result_type: raw
                 .x_edge_detailed_result_type
                 .parse()
                 .map_err(|_e| "x_edge_detailed_result_type invalid")?,
-            sc_content_type: raw.sc_content_type.to_string(),
-            sc_content_len: raw
-                .sc_content_len
-                .parse::<u64>()
+            sc_content_type: raw.sc_content_type.to_optional_string(),
+            sc_content_len: parse_as_option(raw.sc_content_len)
                 .map_err(|_e| "sc_content_len invalid")?,
             sc_range_start: parse_as_option(raw.sc_range_start)
                 .map_err(|_e| "sc_range_start invalid")?,
@@ -317,11 +398,7 @@ impl TryFrom<UnvalidatedRaw<'_>> for Logline<Unvalidated> {
                 .cs_bytes
                 .parse::<u64>()
                 .map_err(|_e| "cs_bytes invalid")?,
-            time_taken: raw
-                .time_taken
-                .parse::<f64>()
-                .map(Duration::from_secs_f64)
-                .map_err(|_e| "time_taken invalid")?,
+            time_taken: parse_duration(raw.time_taken).map_err(|_e| "time_taken invalid")?,
             x_forwarded_for: parse_as_option(raw.x_forwarded_for)
                 .map_err(|_e| "x_forwarded_for invalid")?,
             ssl_protocol: parse_as_option(raw.ssl_protocol).map_err(|_e| "ssl_protocol invalid")?,
@@ -338,19 +415,14 @@ impl TryFrom<UnvalidatedRaw<'_>> for Logline<Unvalidated> {
             fle_encrypted_fields: parse_as_option(raw.fle_encrypted_fields)
                 .map_err(|_e| "fle_encrypted_fields invalid")?,
             c_port: raw.c_port.parse::<u16>().map_err(|_e| "c_port invalid")?,
-            time_to_first_byte: raw
-                .time_to_first_byte
-                .parse::<f64>()
-                .map(Duration::from_secs_f64)
+            time_to_first_byte: parse_duration(raw.time_to_first_byte)
                 .map_err(|_e| "time_to_first_byte invalid")?,
             x_edge_detailed_result_type: raw
                 .x_edge_detailed_result_type
                 .parse()
                 .map_err(|_e| "x_edge_detailed_result_type invalid")?,
-            sc_content_type: raw.sc_content_type.to_string(),
-            sc_content_len: raw
-                .sc_content_len
-                .parse::<u64>()
+            sc_content_type: raw.sc_content_type.to_optional_string(),
+            sc_content_len: parse_as_option(raw.sc_content_len)
                 .map_err(|_e| "sc_content_len invalid")?,
             sc_range_start: parse_as_option(raw.sc_range_start)
                 .map_err(|_e| "sc_range_start invalid")?,
@@ -360,3 +432,66 @@ impl TryFrom<UnvalidatedRaw<'_>> for Logline<Unvalidated> {
         Ok(line)
     }
 }
+
+impl<V> Logline<V> {
+    /// Returns a borrowed view of the line, keeping the validation marker
+    ///
+    /// The string fields are borrowed, the enum and address fields are cloned.
+    pub fn as_borrowed(&self) -> BorrowedLogline<'_, V> {
+        BorrowedLogline::from(self)
+    }
+}
+
+impl<V> From<BorrowedLogline<'_, V>> for Logline<V> {
+    fn from(borrowed: BorrowedLogline<'_, V>) -> Self {
+        from_borrowed(borrowed)
+    }
+}
+
+// copies the fields of any borrowed line;
+// the referential lines use it to keep their own validation marker
+pub(crate) fn from_borrowed<V, W>(borrowed: BorrowedLogline<'_, V>) -> Logline<W> {
+    Logline {
+        date: borrowed.date.to_string(),
+        time: borrowed.time.to_string(),
+        x_edge_location: borrowed.x_edge_location.to_string(),
+        sc_bytes: borrowed.sc_bytes,
+        c_ip: borrowed.c_ip,
+        cs_method: borrowed.cs_method.to_string(),
+        cs_host: borrowed.cs_host.to_string(),
+        cs_uri_stem: borrowed.cs_uri_stem.to_string(),
+        sc_status: borrowed.sc_status,
+        cs_referer: borrowed.cs_referer.map(ToString::to_string),
+        cs_user_agent: borrowed.cs_user_agent.to_string(),
+        cs_uri_query: borrowed.cs_uri_query.map(ToString::to_string),
+        cs_cookie: borrowed.cs_cookie.map(ToString::to_string),
+        x_edge_result_type: borrowed.x_edge_result_type.into_owned(),
+        x_edge_request_id: borrowed.x_edge_request_id.to_string(),
+        x_host_header: borrowed.x_host_header.to_string(),
+        cs_protocol: borrowed.cs_protocol,
+        cs_bytes: borrowed.cs_bytes,
+        time_taken: borrowed.time_taken,
+        x_forwarded_for: borrowed.x_forwarded_for,
+        ssl_protocol: borrowed.ssl_protocol,
+        ssl_cipher: borrowed.ssl_cipher.map(ToString::to_string),
+        x_edge_response_result_type: borrowed.x_edge_response_result_type.into_owned(),
+        cs_protocol_version: borrowed.cs_protocol_version,
+        fle_status: borrowed.fle_status.map(ToString::to_string),
+        fle_encrypted_fields: borrowed.fle_encrypted_fields,
+        c_port: borrowed.c_port,
+        time_to_first_byte: borrowed.time_to_first_byte,
+        x_edge_detailed_result_type: borrowed.x_edge_detailed_result_type.into_owned(),
+        sc_content_type: borrowed.sc_content_type.map(ToString::to_string),
+        sc_content_len: borrowed.sc_content_len,
+        sc_range_start: borrowed.sc_range_start,
+        sc_range_end: borrowed.sc_range_end,
+        __marker: PhantomData,
+    }
+}
+
+#[cfg(feature = "std")]
+crate::codec::impl_codec!(
+    Logline,
+    head: [date, time],
+    civil: civil_from_strings, strings_from_civil
+);
@@ -0,0 +1,486 @@
+use crate::{
+    borrowed::typed::time::Logline as BorrowedLogline, owned::simple::Logline as SimpleLogline,
+    shared::*, types::*,
+};
+
+/// The validated typed log line owning its field data, using [`time`](https://docs.rs/time/latest/time/index.html) crate for date and time
+///
+/// Most fields are parsed into more meaningful types.
+/// Unlike the [borrowed](crate::borrowed::typed::time) and [referential](crate::referential::typed::time) variants,
+/// it does not keep the source string alive and can be moved freely, even across threads.
+///
+/// On construction it checks if the line can be parsed.
+/// This is useful if you cannot skip the comment lines or have reason to not trust the input for format correctness.
+/// The latter should be only an issue if you do not use this crate on CloudFront logs directly.
+///
+/// # Errors
+///
+/// Construction never panics, an invalid log line results in an error instead.
+///
+/// # Examples
+///
+/// Use `.try_from()` or `.try_into()` to construct an instance, since action can fail.
+///
+/// ```rust
+/// use cloudfront_logs::{owned::typed::time::ValidatedLogline, types::*};
+///
+/// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let item = ValidatedLogline::try_from(line).unwrap();
+/// // alternative:
+/// let item: ValidatedLogline = line.try_into().unwrap();
+///
+/// assert_eq!(item.date, time::macros::date!(2019 - 12 - 04));
+/// assert_eq!(item.sc_bytes, 392u64);
+/// assert_eq!(item.cs_host, "d111111abcdef8.cloudfront.net");
+///
+/// // the line can outlive its source
+/// let handle = std::thread::spawn(move || item.sc_status);
+/// assert_eq!(handle.join().unwrap(), 200);
+/// ```
+pub type ValidatedLogline = Logline<Validated>;
+
+/// The unvalidated typed log line owning its field data, using [`time`](https://docs.rs/time/latest/time/index.html) crate for date and time
+///
+/// Most fields are parsed into more meaningful types.
+///
+/// Unlike [`ValidatedLogline`], this variant does not check if the line can be parsed.
+/// Use this if you already did a check before creating this struct.
+/// A common scenario is that you 1) trust the input data and 2) skipped the comment lines.
+///
+/// # Errors
+///
+/// Construction never panics, an invalid log line results in an error instead.
+///
+/// # Examples
+///
+/// Use `.try_from()` or `.try_into()` to construct an instance, since action can fail.
+///
+/// ```rust
+/// use cloudfront_logs::{owned::typed::time::UnvalidatedLogline, types::*};
+///
+/// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+///
+/// let item = UnvalidatedLogline::try_from(line).unwrap();
+/// // alternative:
+/// let item: UnvalidatedLogline = line.try_into().unwrap();
+///
+/// assert_eq!(item.date, time::macros::date!(2019 - 12 - 04));
+/// assert_eq!(item.sc_bytes, 392u64);
+/// assert_eq!(item.cs_protocol, CsProtocol::Https);
+/// ```
+pub type UnvalidatedLogline = Logline<Unvalidated>;
+
+/// The generic, typed and owned log line type
+///
+/// Do not use it directly, prefer [`ValidatedLogline`] or [`UnvalidatedLogline`] instead.
+///
+/// Besides parsing a line directly, it can be created from
+/// * the borrowed typed variant ([`From`]), which only copies the string fields,
+/// * the owned simple variant ([`TryFrom`]), which parses the date and time fields.
+#[must_use]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Logline<V> {
+    pub date: Date,
+    pub time: Time,
+    pub datetime: OffsetDateTime,
+    pub x_edge_location: String,
+    pub sc_bytes: u64,
+    pub c_ip: IpAddr,
+    pub cs_method: String,
+    pub cs_host: String,
+    pub cs_uri_stem: String,
+    pub sc_status: u16,
+    pub cs_referer: Option<String>,
+    pub cs_user_agent: String,
+    pub cs_uri_query: Option<String>,
+    pub cs_cookie: Option<String>,
+    pub x_edge_result_type: EdgeResultType<'static>,
+    pub x_edge_request_id: String,
+    pub x_host_header: String,
+    pub cs_protocol: CsProtocol,
+    pub cs_bytes: u64,
+    pub time_taken: Duration,
+    pub x_forwarded_for: Option<ForwardedForAddrs>,
+    pub ssl_protocol: Option<SslProtocol>,
+    pub ssl_cipher: Option<String>,
+    pub x_edge_response_result_type: EdgeResultType<'static>,
+    pub cs_protocol_version: CsProtocolVersion,
+    pub fle_status: Option<String>,
+    pub fle_encrypted_fields: Option<u64>,
+    pub c_port: u16,
+    pub time_to_first_byte: Duration,
+    pub x_edge_detailed_result_type: DetailedEdgeResultType<'static>,
+    pub sc_content_type: Option<String>,
+    pub sc_content_len: Option<u64>,
+    pub sc_range_start: Option<i64>,
+    pub sc_range_end: Option<i64>,
+    __marker: PhantomData<V>,
+}
+
+impl<V> Logline<V> {
+    /// The request time as UTC datetime
+    ///
+    /// The `datetime` field is already an [`OffsetDateTime`] with UTC offset,
+    /// this returns the dedicated [`UtcDateTime`] type instead.
+    pub fn datetime_utc(&self) -> UtcDateTime {
+        self.datetime.to_utc()
+    }
+
+    /// The request time as seconds since the Unix epoch
+    pub fn unix_timestamp(&self) -> i64 {
+        self.datetime.unix_timestamp()
+    }
+
+    /// The request time as milliseconds since the Unix epoch
+    pub fn unix_timestamp_millis(&self) -> i64 {
+        self.datetime.unix_timestamp() * 1_000 + i64::from(self.datetime.millisecond())
+    }
+
+    /// The request time at the given offset, e.g. for reporting in local time
+    ///
+    /// Returns `None` if the result would be out of the supported date range.
+    pub fn datetime_in(&self, offset: UtcOffset) -> Option<OffsetDateTime> {
+        self.datetime.checked_to_offset(offset)
+    }
+}
+
+impl TryFrom<&str> for Logline<Validated> {
+    type Error = &'static str;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        BorrowedLogline::<'_, Validated>::try_from(line).map(Self::from)
+    }
+}
+
+impl TryFrom<&str> for Logline<Unvalidated> {
+    type Error = &'static str;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        BorrowedLogline::<'_, Unvalidated>::try_from(line).map(Self::from)
+    }
+}
+
+impl Logline<Validated> {
+    /// Parses the line into this struct, reusing the buffers of the [`String`] and [`Vec`] fields
+    ///
+    /// Use it instead of [`TryFrom`] to parse many lines in a loop without allocating for each of them.
+    /// On error, the fields hold a mix of the previous and the new line and should not be used.
+    ///
+    /// Optional fields are set to `None` for `-`, which drops their buffer:
+    /// a value in a later line allocates again. Fields which are always present keep their buffers.
+    pub fn parse_into(&mut self, line: &str) -> Result<(), &'static str> {
+        validate_line(line)?;
+        parse_into(self, line)
+    }
+}
+
+impl Logline<Unvalidated> {
+    /// Parses the line into this struct, reusing the buffers of the [`String`] and [`Vec`] fields
+    ///
+    /// See [`ValidatedLogline::parse_into`] for details.
+    pub fn parse_into(&mut self, line: &str) -> Result<(), &'static str> {
+        parse_into(self, line)
+    }
+}
+
+// the in-place counterpart of the regular parsing
+fn parse_into<V>(target: &mut Logline<V>, line: &str) -> Result<(), &'static str> {
+    let mut iter = MemchrTabSplitter::new(line);
+
+    target.date = parse_time_date(iter.field()?).map_err(|_e| "date invalid")?;
+    target.time = parse_time_time(iter.field()?).map_err(|_e| "time invalid")?;
+    target.datetime = OffsetDateTime::new_utc(target.date, target.time);
+    assign_string(&mut target.x_edge_location, iter.field()?);
+    target.sc_bytes = iter
+        .field()?
+        .parse::<u64>()
+        .map_err(|_e| "sc_bytes invalid")?;
+    target.c_ip = iter.field()?.parse().map_err(|_e| "c_ip invalid")?;
+    assign_string(&mut target.cs_method, iter.field()?);
+    assign_string(&mut target.cs_host, iter.field()?);
+    assign_string(&mut target.cs_uri_stem, iter.field()?);
+    target.sc_status = iter
+        .field()?
+        .parse::<u16>()
+        .map_err(|_e| "sc_status invalid")?;
+    assign_optional_string(&mut target.cs_referer, iter.field()?);
+    assign_string(&mut target.cs_user_agent, iter.field()?);
+    assign_optional_string(&mut target.cs_uri_query, iter.field()?);
+    assign_optional_string(&mut target.cs_cookie, iter.field()?);
+    target.x_edge_result_type.parse_into(iter.field()?);
+    assign_string(&mut target.x_edge_request_id, iter.field()?);
+    assign_string(&mut target.x_host_header, iter.field()?);
+    target.cs_protocol = iter.field()?.parse().map_err(|_e| "cs_protocol invalid")?;
+    target.cs_bytes = iter
+        .field()?
+        .parse::<u64>()
+        .map_err(|_e| "cs_bytes invalid")?;
+    target.time_taken = parse_duration(iter.field()?).map_err(|_e| "time_taken invalid")?;
+    assign_forwarded_for(&mut target.x_forwarded_for, iter.field()?)
+        .map_err(|_e| "x_forwarded_for invalid")?;
+    target.ssl_protocol = parse_as_option(iter.field()?).map_err(|_e| "ssl_protocol invalid")?;
+    assign_optional_string(&mut target.ssl_cipher, iter.field()?);
+    target.x_edge_response_result_type.parse_into(iter.field()?);
+    target.cs_protocol_version = iter
+        .field()?
+        .parse()
+        .map_err(|_e| "cs_protocol_version invalid")?;
+    assign_optional_string(&mut target.fle_status, iter.field()?);
+    target.fle_encrypted_fields =
+        parse_as_option(iter.field()?).map_err(|_e| "fle_encrypted_fields invalid")?;
+    target.c_port = iter
+        .field()?
+        .parse::<u16>()
+        .map_err(|_e| "c_port invalid")?;
+    target.time_to_first_byte =
+        parse_duration(iter.field()?).map_err(|_e| "time_to_first_byte invalid")?;
+    target.x_edge_detailed_result_type.parse_into(iter.field()?);
+    assign_optional_string(&mut target.sc_content_type, iter.field()?);
+    target.sc_content_len =
+        parse_as_option(iter.field()?).map_err(|_e| "sc_content_len invalid")?;
+    target.sc_range_start =
+        parse_as_option(iter.field()?).map_err(|_e| "sc_range_start invalid")?;
+    target.sc_range_end = parse_as_option(iter.field()?).map_err(|_e| "sc_range_end invalid")?;
+    Ok(())
+}
+
+impl<V> Logline<V> {
+    /// Returns a borrowed view of the line, keeping the validation marker
+    ///
+    /// The string fields are borrowed, the enum and address fields are cloned.
+    pub fn as_borrowed(&self) -> BorrowedLogline<'_, V> {
+        BorrowedLogline::from(self)
+    }
+}
+
+impl<V> From<BorrowedLogline<'_, V>> for Logline<V> {
+    fn from(borrowed: BorrowedLogline<'_, V>) -> Self {
+        from_borrowed(borrowed)
+    }
+}
+
+// copies the fields of any borrowed line;
+// the referential lines use it to keep their own validation marker
+pub(crate) fn from_borrowed<V, W>(borrowed: BorrowedLogline<'_, V>) -> Logline<W> {
+    Logline {
+        date: borrowed.date,
+        time: borrowed.time,
+        datetime: borrowed.datetime,
+        x_edge_location: borrowed.x_edge_location.to_string(),
+        sc_bytes: borrowed.sc_bytes,
+        c_ip: borrowed.c_ip,
+        cs_method: borrowed.cs_method.to_string(),
+        cs_host: borrowed.cs_host.to_string(),
+        cs_uri_stem: borrowed.cs_uri_stem.to_string(),
+        sc_status: borrowed.sc_status,
+        cs_referer: borrowed.cs_referer.map(ToString::to_string),
+        cs_user_agent: borrowed.cs_user_agent.to_string(),
+        cs_uri_query: borrowed.cs_uri_query.map(ToString::to_string),
+        cs_cookie: borrowed.cs_cookie.map(ToString::to_string),
+        x_edge_result_type: borrowed.x_edge_result_type.into_owned(),
+        x_edge_request_id: borrowed.x_edge_request_id.to_string(),
+        x_host_header: borrowed.x_host_header.to_string(),
+        cs_protocol: borrowed.cs_protocol,
+        cs_bytes: borrowed.cs_bytes,
+        time_taken: borrowed.time_taken,
+        x_forwarded_for: borrowed.x_forwarded_for,
+        ssl_protocol: borrowed.ssl_protocol,
+        ssl_cipher: borrowed.ssl_cipher.map(ToString::to_string),
+        x_edge_response_result_type: borrowed.x_edge_response_result_type.into_owned(),
+        cs_protocol_version: borrowed.cs_protocol_version,
+        fle_status: borrowed.fle_status.map(ToString::to_string),
+        fle_encrypted_fields: borrowed.fle_encrypted_fields,
+        c_port: borrowed.c_port,
+        time_to_first_byte: borrowed.time_to_first_byte,
+        x_edge_detailed_result_type: borrowed.x_edge_detailed_result_type.into_owned(),
+        sc_content_type: borrowed.sc_content_type.map(ToString::to_string),
+        sc_content_len: borrowed.sc_content_len,
+        sc_range_start: borrowed.sc_range_start,
+        sc_range_end: borrowed.sc_range_end,
+        __marker: PhantomData,
+    }
+}
+
+impl<V> TryFrom<SimpleLogline<V>> for Logline<V> {
+    type Error = &'static str;
+
+    fn try_from(simple: SimpleLogline<V>) -> Result<Self, Self::Error> {
+        let date = parse_time_date(&simple.date).map_err(|_e| "date invalid")?;
+        let time = parse_time_time(&simple.time).map_err(|_e| "time invalid")?;
+        Ok(from_simple(simple, date, time))
+    }
+}
+
+impl Logline<Validated> {
+    /// Parses the line, falling back to placeholders for fields which cannot be parsed
+    ///
+    /// Unlike [`TryFrom`], a single corrupted field does not discard the whole line:
+    /// the returned [`Lenient`] holds the logline and the list of fields which could not be parsed.
+    /// An unparseable date or time is replaced by the Unix epoch, see [`Lenient`] for the other placeholders.
+    /// The line itself still has to be valid, e.g. have the right number of fields.
+    pub fn parse_lenient(line: &str) -> Result<Lenient<Self>, &'static str> {
+        SimpleLogline::<Validated>::parse_lenient(line).map(from_lenient_simple)
+    }
+}
+
+impl Logline<Unvalidated> {
+    /// Parses the line, falling back to placeholders for fields which cannot be parsed
+    ///
+    /// See [`ValidatedLogline::parse_lenient`] for details.
+    pub fn parse_lenient(line: &str) -> Result<Lenient<Self>, &'static str> {
+        SimpleLogline::<Unvalidated>::parse_lenient(line).map(from_lenient_simple)
+    }
+}
+
+fn from_lenient_simple<V>(simple: Lenient<SimpleLogline<V>>) -> Lenient<Logline<V>> {
+    let Lenient {
+        logline: simple,
+        errors: simple_errors,
+    } = simple;
+    // keep the errors in field order, date and time come first
+    let mut errors = FieldErrors::default();
+    let date = errors.parse(
+        Field::Date,
+        &simple.date,
+        parse_time_date,
+        OffsetDateTime::UNIX_EPOCH.date(),
+    );
+    let time = errors.parse(Field::Time, &simple.time, parse_time_time, Time::MIDNIGHT);
+    errors.0.extend(simple_errors);
+    errors.finish(from_simple(simple, date, time))
+}
+
+// moves the fields of the simple line, with its date and time already parsed
+fn from_simple<V>(simple: SimpleLogline<V>, date: Date, time: Time) -> Logline<V> {
+    let datetime = OffsetDateTime::new_utc(date, time);
+
+    Logline {
+        date,
+        time,
+        datetime,
+        x_edge_location: simple.x_edge_location,
+        sc_bytes: simple.sc_bytes,
+        c_ip: simple.c_ip,
+        cs_method: simple.cs_method,
+        cs_host: simple.cs_host,
+        cs_uri_stem: simple.cs_uri_stem,
+        sc_status: simple.sc_status,
+        cs_referer: simple.cs_referer,
+        cs_user_agent: simple.cs_user_agent,
+        cs_uri_query: simple.cs_uri_query,
+        cs_cookie: simple.cs_cookie,
+        x_edge_result_type: simple.x_edge_result_type,
+        x_edge_request_id: simple.x_edge_request_id,
+        x_host_header: simple.x_host_header,
+        cs_protocol: simple.cs_protocol,
+        cs_bytes: simple.cs_bytes,
+        time_taken: simple.time_taken,
+        x_forwarded_for: simple.x_forwarded_for,
+        ssl_protocol: simple.ssl_protocol,
+        ssl_cipher: simple.ssl_cipher,
+        x_edge_response_result_type: simple.x_edge_response_result_type,
+        cs_protocol_version: simple.cs_protocol_version,
+        fle_status: simple.fle_status,
+        fle_encrypted_fields: simple.fle_encrypted_fields,
+        c_port: simple.c_port,
+        time_to_first_byte: simple.time_to_first_byte,
+        x_edge_detailed_result_type: simple.x_edge_detailed_result_type,
+        sc_content_type: simple.sc_content_type,
+        sc_content_len: simple.sc_content_len,
+        sc_range_start: simple.sc_range_start,
+        sc_range_end: simple.sc_range_end,
+        __marker: PhantomData,
+    }
+}
+
+impl From<Logline<Validated>> for Logline<Unvalidated> {
+    fn from(validated: Logline<Validated>) -> Self {
+        Logline {
+            date: validated.date,
+            time: validated.time,
+            datetime: validated.datetime,
+            x_edge_location: validated.x_edge_location,
+            sc_bytes: validated.sc_bytes,
+            c_ip: validated.c_ip,
+            cs_method: validated.cs_method,
+            cs_host: validated.cs_host,
+            cs_uri_stem: validated.cs_uri_stem,
+            sc_status: validated.sc_status,
+            cs_referer: validated.cs_referer,
+            cs_user_agent: validated.cs_user_agent,
+            cs_uri_query: validated.cs_uri_query,
+            cs_cookie: validated.cs_cookie,
+            x_edge_result_type: validated.x_edge_result_type,
+            x_edge_request_id: validated.x_edge_request_id,
+            x_host_header: validated.x_host_header,
+            cs_protocol: validated.cs_protocol,
+            cs_bytes: validated.cs_bytes,
+            time_taken: validated.time_taken,
+            x_forwarded_for: validated.x_forwarded_for,
+            ssl_protocol: validated.ssl_protocol,
+            ssl_cipher: validated.ssl_cipher,
+            x_edge_response_result_type: validated.x_edge_response_result_type,
+            cs_protocol_version: validated.cs_protocol_version,
+            fle_status: validated.fle_status,
+            fle_encrypted_fields: validated.fle_encrypted_fields,
+            c_port: validated.c_port,
+            time_to_first_byte: validated.time_to_first_byte,
+            x_edge_detailed_result_type: validated.x_edge_detailed_result_type,
+            sc_content_type: validated.sc_content_type,
+            sc_content_len: validated.sc_content_len,
+            sc_range_start: validated.sc_range_start,
+            sc_range_end: validated.sc_range_end,
+            __marker: PhantomData,
+        }
+    }
+}
+
+impl From<Logline<Unvalidated>> for Logline<Validated> {
+    fn from(unvalidated: Logline<Unvalidated>) -> Self {
+        Logline {
+            date: unvalidated.date,
+            time: unvalidated.time,
+            datetime: unvalidated.datetime,
+            x_edge_location: unvalidated.x_edge_location,
+            sc_bytes: unvalidated.sc_bytes,
+            c_ip: unvalidated.c_ip,
+            cs_method: unvalidated.cs_method,
+            cs_host: unvalidated.cs_host,
+            cs_uri_stem: unvalidated.cs_uri_stem,
+            sc_status: unvalidated.sc_status,
+            cs_referer: unvalidated.cs_referer,
+            cs_user_agent: unvalidated.cs_user_agent,
+            cs_uri_query: unvalidated.cs_uri_query,
+            cs_cookie: unvalidated.cs_cookie,
+            x_edge_result_type: unvalidated.x_edge_result_type,
+            x_edge_request_id: unvalidated.x_edge_request_id,
+            x_host_header: unvalidated.x_host_header,
+            cs_protocol: unvalidated.cs_protocol,
+            cs_bytes: unvalidated.cs_bytes,
+            time_taken: unvalidated.time_taken,
+            x_forwarded_for: unvalidated.x_forwarded_for,
+            ssl_protocol: unvalidated.ssl_protocol,
+            ssl_cipher: unvalidated.ssl_cipher,
+            x_edge_response_result_type: unvalidated.x_edge_response_result_type,
+            cs_protocol_version: unvalidated.cs_protocol_version,
+            fle_status: unvalidated.fle_status,
+            fle_encrypted_fields: unvalidated.fle_encrypted_fields,
+            c_port: unvalidated.c_port,
+            time_to_first_byte: unvalidated.time_to_first_byte,
+            x_edge_detailed_result_type: unvalidated.x_edge_detailed_result_type,
+            sc_content_type: unvalidated.sc_content_type,
+            sc_content_len: unvalidated.sc_content_len,
+            sc_range_start: unvalidated.sc_range_start,
+            sc_range_end: unvalidated.sc_range_end,
+            __marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+crate::codec::impl_codec!(
+    Logline,
+    head: [date, time, datetime],
+    civil: civil_from_time, time_from_civil?
+);
@@ -0,0 +1,289 @@
+//! Parallel parsing of large buffers with [`rayon`]
+//!
+//! The input is split into newline-aligned chunks, which are then parsed in parallel.
+//! Any logline variant can be used as target, as long as it can be constructed via `TryFrom<&str>`.
+//!
+//! Empty lines and comment lines (like the `#Version` and `#Fields` headers) are skipped.
+//! Lines which cannot be parsed are reported with their (1-based) line number,
+//! the errors of all chunks are merged and sorted by line number.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cloudfront_logs::{ValidatedSimpleLogline, parallel::{ParParser, par_parse}};
+//!
+//! let input = "#Version: 1.0
+//! 2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-
+//! not a log line
+//! 2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-
+//! ";
+//!
+//! // collect all lines, in input order
+//! let report = par_parse::<ValidatedSimpleLogline<'_>>(input);
+//! assert_eq!(report.output.len(), 2);
+//! assert_eq!(report.errors.len(), 1);
+//! assert_eq!(report.errors[0].line, 3);
+//!
+//! // or aggregate them directly
+//! let report = ParParser::new(input).fold(
+//!     || 0,
+//!     |sum, line: ValidatedSimpleLogline<'_>| sum + line.sc_bytes,
+//!     |a, b| a + b,
+//! );
+//! assert_eq!(report.output, 784);
+//! ```
+
+use crate::COMMENT_U8;
+use rayon::prelude::*;
+
+/// Default chunk size in bytes (1 MiB)
+pub const DEFAULT_CHUNK_SIZE: usize = 1 << 20;
+
+/// Parses all lines of the input in parallel, in input order
+///
+/// Shorthand for `ParParser::new(input).parse()`.
+pub fn par_parse<'a, T>(input: &'a str) -> ParseReport<Vec<T>, T::Error>
+where
+    T: TryFrom<&'a str> + Send,
+    T::Error: Send,
+{
+    ParParser::new(input).parse()
+}
+
+/// A line which could not be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineError<E> {
+    /// 1-based line number in the input
+    pub line: usize,
+    pub error: E,
+}
+
+/// Result of a parallel parse run
+///
+/// The errors of all chunks are merged and sorted by line number.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseReport<O, E> {
+    pub output: O,
+    pub errors: Vec<LineError<E>>,
+}
+
+impl<O, E> ParseReport<O, E> {
+    /// Returns `true` if all lines were parsed successfully
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Configurable parallel parser
+///
+/// # Examples
+///
+/// ```rust
+/// use cloudfront_logs::{owned::ValidatedSimpleLogline, parallel::ParParser};
+///
+/// let input = std::iter::repeat("2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-\n")
+///     .take(1000)
+///     .collect::<String>();
+///
+/// let report = ParParser::new(&input)
+///     .chunk_size(16 * 1024)
+///     .unordered()
+///     .parse::<ValidatedSimpleLogline>();
+///
+/// assert!(report.is_ok());
+/// assert_eq!(report.output.len(), 1000);
+/// ```
+#[must_use]
+#[derive(Debug, Clone, Copy)]
+pub struct ParParser<'a> {
+    input: &'a str,
+    chunk_size: usize,
+    ordered: bool,
+}
+
+impl<'a> ParParser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            ordered: true,
+        }
+    }
+
+    /// Sets the target chunk size in bytes
+    ///
+    /// Chunks are extended to the end of the line, so they can be slightly larger.
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Returns the parsed lines in input order (default)
+    pub fn ordered(mut self) -> Self {
+        self.ordered = true;
+        self
+    }
+
+    /// Returns the parsed lines in any order, which avoids keeping the chunk results apart
+    pub fn unordered(mut self) -> Self {
+        self.ordered = false;
+        self
+    }
+
+    /// Parses all lines into `T`
+    pub fn parse<T>(self) -> ParseReport<Vec<T>, T::Error>
+    where
+        T: TryFrom<&'a str> + Send,
+        T::Error: Send,
+    {
+        let chunks = self.chunks();
+        let parse_chunk = |chunk: &Chunk<'a>| {
+            let mut output = Vec::new();
+            let mut errors = Vec::new();
+            chunk.for_each_line(|line, result| match result {
+                Ok(item) => output.push(item),
+                Err(error) => errors.push(LineError { line, error }),
+            });
+            ParseReport { output, errors }
+        };
+
+        let mut report = if self.ordered {
+            let reports: Vec<_> = chunks.par_iter().map(parse_chunk).collect();
+            let mut merged = ParseReport {
+                output: Vec::with_capacity(reports.iter().map(|r| r.output.len()).sum()),
+                errors: Vec::new(),
+            };
+            for mut report in reports {
+                merged.output.append(&mut report.output);
+                merged.errors.append(&mut report.errors);
+            }
+            merged
+        } else {
+            chunks.par_iter().map(parse_chunk).reduce(
+                || ParseReport {
+                    output: Vec::new(),
+                    errors: Vec::new(),
+                },
+                |mut a, mut b| {
+                    if a.output.len() < b.output.len() {
+                        std::mem::swap(&mut a, &mut b);
+                    }
+                    a.output.append(&mut b.output);
+                    a.errors.append(&mut b.errors);
+                    a
+                },
+            )
+        };
+        report.errors.sort_by_key(|error| error.line);
+        report
+    }
+
+    /// Parses all lines into `T` and aggregates them, see [`rayon::iter::ParallelIterator::fold`]
+    ///
+    /// `fold_op` folds the lines of a chunk into an accumulator created by `identity`,
+    /// `reduce_op` combines the accumulators of different chunks.
+    /// The order in which the chunks are combined is not specified.
+    pub fn fold<T, R, ID, F, RF>(
+        self,
+        identity: ID,
+        fold_op: F,
+        reduce_op: RF,
+    ) -> ParseReport<R, T::Error>
+    where
+        T: TryFrom<&'a str>,
+        T::Error: Send,
+        R: Send,
+        ID: Fn() -> R + Sync + Send,
+        F: Fn(R, T) -> R + Sync + Send,
+        RF: Fn(R, R) -> R + Sync + Send,
+    {
+        let chunks = self.chunks();
+        let mut report = chunks
+            .par_iter()
+            .map(|chunk| {
+                let mut output = Some(identity());
+                let mut errors = Vec::new();
+                chunk.for_each_line(|line, result| match result {
+                    Ok(item) => output = output.take().map(|acc| fold_op(acc, item)),
+                    Err(error) => errors.push(LineError { line, error }),
+                });
+                ParseReport { output, errors }
+            })
+            .reduce(
+                || ParseReport {
+                    output: None,
+                    errors: Vec::new(),
+                },
+                |mut a, mut b| {
+                    a.errors.append(&mut b.errors);
+                    let output = match (a.output, b.output) {
+                        (Some(x), Some(y)) => Some(reduce_op(x, y)),
+                        (x, y) => x.or(y),
+                    };
+                    ParseReport {
+                        output,
+                        errors: a.errors,
+                    }
+                },
+            );
+        report.errors.sort_by_key(|error| error.line);
+        ParseReport {
+            output: report.output.unwrap_or_else(identity),
+            errors: report.errors,
+        }
+    }
+
+    // splits the input into newline-aligned chunks and determines their first line numbers
+    fn chunks(&self) -> Vec<Chunk<'a>> {
+        let mut chunks = Vec::new();
+        let mut rest = self.input;
+        while !rest.is_empty() {
+            let end = match rest.as_bytes().get(self.chunk_size..) {
+                Some(tail) => {
+                    memchr::memchr(b'\n', tail).map_or(rest.len(), |idx| self.chunk_size + idx + 1)
+                }
+                None => rest.len(),
+            };
+            // newlines are ASCII, so the split is always on a char boundary
+            let (data, tail) = rest.split_at(end);
+            chunks.push(Chunk {
+                data,
+                first_line: 0,
+            });
+            rest = tail;
+        }
+
+        let line_counts: Vec<usize> = chunks
+            .par_iter()
+            .map(|chunk| memchr::memchr_iter(b'\n', chunk.data.as_bytes()).count())
+            .collect();
+        let mut first_line = 1;
+        for (chunk, count) in chunks.iter_mut().zip(line_counts) {
+            chunk.first_line = first_line;
+            first_line += count;
+        }
+        chunks
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Chunk<'a> {
+    data: &'a str,
+    first_line: usize,
+}
+
+impl<'a> Chunk<'a> {
+    // calls f with the line number and parse result of each (non-empty, non-comment) line
+    fn for_each_line<T, F>(&self, mut f: F)
+    where
+        T: TryFrom<&'a str>,
+        F: FnMut(usize, Result<T, T::Error>),
+    {
+        for (offset, line) in self.data.lines().enumerate() {
+            if line.is_empty() || line.as_bytes().first() == Some(&COMMENT_U8) {
+                continue;
+            }
+            f(self.first_line + offset, T::try_from(line));
+        }
+    }
+}
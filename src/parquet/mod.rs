@@ -52,9 +52,9 @@ impl<'a> TryFrom<&'a str> for LogLine<'a> {
 
         let mut iter = MemchrTabSplitter::new(line);
 
-        let date = NaiveDate::parse_from_str(iter.next().unwrap(), "%Y-%m-%d")
-            .map_err(|_e| "date invalid")?;
-        let raw_time = iter.next().unwrap();
+        let date =
+            NaiveDate::parse_from_str(iter.field()?, "%Y-%m-%d").map_err(|_e| "date invalid")?;
+        let raw_time = iter.field()?;
         let time = NaiveTime::parse_from_str(raw_time, "%H:%M:%S").map_err(|_e| "time invalid")?;
         let datetime = NaiveDateTime::new(date, time);
 
@@ -62,77 +62,60 @@ impl<'a> TryFrom<&'a str> for LogLine<'a> {
             date,
             time: raw_time,
             datetime,
-            x_edge_location: iter.next().unwrap(),
+            x_edge_location: iter.field()?,
             sc_bytes: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse::<u64>()
                 .map_err(|_e| "sc_bytes invalid")?,
-            c_ip: iter.next().unwrap(),
-            cs_method: iter.next().unwrap(),
-            cs_host: iter.next().unwrap(),
-            cs_uri_stem: iter.next().unwrap(),
+            c_ip: iter.field()?,
+            cs_method: iter.field()?,
+            cs_host: iter.field()?,
+            cs_uri_stem: iter.field()?,
             sc_status: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse::<u16>()
                 .map_err(|_e| "sc_status invalid")?,
-            cs_referer: iter.next().and_then(str::as_optional_str),
-            cs_user_agent: iter.next().unwrap(),
-            cs_uri_query: iter.next().and_then(str::as_optional_str),
-            cs_cookie: iter.next().and_then(str::as_optional_str),
-            x_edge_result_type: iter.next().unwrap(),
-            x_edge_request_id: iter.next().unwrap(),
-            x_host_header: iter.next().unwrap(),
-            cs_protocol: iter.next().unwrap(),
+            cs_referer: iter.field()?.as_optional_str(),
+            cs_user_agent: iter.field()?,
+            cs_uri_query: iter.field()?.as_optional_str(),
+            cs_cookie: iter.field()?.as_optional_str(),
+            x_edge_result_type: iter.field()?,
+            x_edge_request_id: iter.field()?,
+            x_host_header: iter.field()?,
+            cs_protocol: iter.field()?,
             cs_bytes: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse::<u64>()
                 .map_err(|_e| "cs_bytes invalid")?,
             time_taken: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse::<f64>()
                 .map_err(|_e| "time_taken invalid")?,
-            x_forwarded_for: iter.next().and_then(str::as_optional_str),
-            ssl_protocol: iter.next().and_then(str::as_optional_str),
-            ssl_cipher: iter.next().and_then(str::as_optional_str),
-            x_edge_response_result_type: iter.next().unwrap(),
-            cs_protocol_version: iter.next().unwrap(),
-            fle_status: iter.next().and_then(str::as_optional_str),
-            fle_encrypted_fields: iter
-                .next()
-                .and_then(as_optional_t)
-                .transpose()
+            x_forwarded_for: iter.field()?.as_optional_str(),
+            ssl_protocol: iter.field()?.as_optional_str(),
+            ssl_cipher: iter.field()?.as_optional_str(),
+            x_edge_response_result_type: iter.field()?,
+            cs_protocol_version: iter.field()?,
+            fle_status: iter.field()?.as_optional_str(),
+            fle_encrypted_fields: parse_as_option(iter.field()?)
                 .map_err(|_e| "fle_encrypted_fields invalid")?,
             c_port: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse::<u16>()
                 .map_err(|_e| "c_port invalid")?,
             time_to_first_byte: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse::<f64>()
                 .map_err(|_e| "time_to_first_byte invalid")?,
-            x_edge_detailed_result_type: iter.next().unwrap(),
-            sc_content_type: iter.next().unwrap(),
+            x_edge_detailed_result_type: iter.field()?,
+            sc_content_type: iter.field()?,
             sc_content_len: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse::<u64>()
                 .map_err(|_e| "sc_content_len invalid")?,
-            sc_range_start: iter
-                .next()
-                .and_then(as_optional_t)
-                .transpose()
+            sc_range_start: parse_as_option(iter.field()?)
                 .map_err(|_e| "sc_range_start invalid")?,
-            sc_range_end: iter
-                .next()
-                .and_then(as_optional_t)
-                .transpose()
-                .map_err(|_e| "sc_range_end invalid")?,
+            sc_range_end: parse_as_option(iter.field()?).map_err(|_e| "sc_range_end invalid")?,
         };
         Ok(line)
     }
@@ -46,39 +46,39 @@ impl<'a> TryFrom<&'a str> for LogLine<'a> {
         let mut iter = MemchrTabSplitter::new(line);
 
         Ok(Self {
-            date: iter.next().unwrap(),
-            time: iter.next().unwrap(),
-            x_edge_location: iter.next().unwrap(),
-            sc_bytes: iter.next().unwrap(),
-            c_ip: iter.next().unwrap(),
-            cs_method: iter.next().unwrap(),
-            cs_host: iter.next().unwrap(),
-            cs_uri_stem: iter.next().unwrap(),
-            sc_status: iter.next().unwrap(),
-            cs_referer: iter.next().unwrap(),
-            cs_user_agent: iter.next().unwrap(),
-            cs_uri_query: iter.next().unwrap(),
-            cs_cookie: iter.next().unwrap(),
-            x_edge_result_type: iter.next().unwrap(),
-            x_edge_request_id: iter.next().unwrap(),
-            x_host_header: iter.next().unwrap(),
-            cs_protocol: iter.next().unwrap(),
-            cs_bytes: iter.next().unwrap(),
-            time_taken: iter.next().unwrap(),
-            x_forwarded_for: iter.next().unwrap(),
-            ssl_protocol: iter.next().unwrap(),
-            ssl_cipher: iter.next().unwrap(),
-            x_edge_response_result_type: iter.next().unwrap(),
-            cs_protocol_version: iter.next().unwrap(),
-            fle_status: iter.next().unwrap(),
-            fle_encrypted_fields: iter.next().unwrap(),
-            c_port: iter.next().unwrap(),
-            time_to_first_byte: iter.next().unwrap(),
-            x_edge_detailed_result_type: iter.next().unwrap(),
-            sc_content_type: iter.next().unwrap(),
-            sc_content_len: iter.next().unwrap(),
-            sc_range_start: iter.next().unwrap(),
-            sc_range_end: iter.next().unwrap(),
+            date: iter.field()?,
+            time: iter.field()?,
+            x_edge_location: iter.field()?,
+            sc_bytes: iter.field()?,
+            c_ip: iter.field()?,
+            cs_method: iter.field()?,
+            cs_host: iter.field()?,
+            cs_uri_stem: iter.field()?,
+            sc_status: iter.field()?,
+            cs_referer: iter.field()?,
+            cs_user_agent: iter.field()?,
+            cs_uri_query: iter.field()?,
+            cs_cookie: iter.field()?,
+            x_edge_result_type: iter.field()?,
+            x_edge_request_id: iter.field()?,
+            x_host_header: iter.field()?,
+            cs_protocol: iter.field()?,
+            cs_bytes: iter.field()?,
+            time_taken: iter.field()?,
+            x_forwarded_for: iter.field()?,
+            ssl_protocol: iter.field()?,
+            ssl_cipher: iter.field()?,
+            x_edge_response_result_type: iter.field()?,
+            cs_protocol_version: iter.field()?,
+            fle_status: iter.field()?,
+            fle_encrypted_fields: iter.field()?,
+            c_port: iter.field()?,
+            time_to_first_byte: iter.field()?,
+            x_edge_detailed_result_type: iter.field()?,
+            sc_content_type: iter.field()?,
+            sc_content_len: iter.field()?,
+            sc_range_start: iter.field()?,
+            sc_range_end: iter.field()?,
         })
     }
 }
@@ -27,7 +27,7 @@ impl<'a> LogLineView<'a> {
 
     #[inline]
     fn field(&self, index: usize) -> &'a str {
-        split(self.line).nth(index).unwrap()
+        split(self.line).nth(index).unwrap_or_default()
     }
 
     /// Returns the date field of the log line
@@ -49,14 +49,14 @@ impl<'a> LogLineView<'a> {
             Ordering::Greater => {
                 let rel = index - *prev - 1;
                 *prev = index;
-                self.iter.borrow_mut().nth(rel).unwrap()
+                self.iter.borrow_mut().nth(rel).unwrap_or_default()
             }
             Ordering::Equal => *last,
             Ordering::Less => {
                 let mut iter = self.iter.borrow_mut();
                 *iter = split(self.line);
                 *prev = index;
-                iter.nth(index).unwrap()
+                iter.nth(index).unwrap_or_default()
             }
         };
         *last = v;
@@ -0,0 +1,173 @@
+//! Reading log files line by line into owning loglines
+//!
+//! [`LogReader`] wraps any [`BufRead`], `LogStream` (with the `tokio` feature) any tokio `AsyncBufRead`.
+//! Both handle the header lines of a CloudFront log file the same way and yield the same items:
+//!
+//! * empty lines are skipped,
+//! * the `#Version:` header is recorded, see [`LogReader::version`],
+//! * the `#Fields:` header is checked against the supported field list,
+//!   an unexpected list results in a [`ReadError::UnexpectedFields`] item (reading continues),
+//! * other comment lines are skipped,
+//! * all other lines are parsed into the target logline type,
+//!   failures result in a [`ReadError::Parse`] item with the (1-based) line number.
+//!
+//! The target type defaults to [`ValidatedRawLogline`],
+//! but any type which can be constructed via `TryFrom<String>` can be used,
+//! like all [`referential`](crate::referential) variants.
+//!
+//! To check the data quality of a log file instead, use a [`QualityReport`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cloudfront_logs::{reader::LogReader, referential::ValidatedSimpleLogline};
+//!
+//! let input = "#Version: 1.0
+//! 2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-
+//! ";
+//!
+//! let mut reader = LogReader::<_, ValidatedSimpleLogline>::new(input.as_bytes());
+//! let line = reader.next().unwrap().unwrap();
+//!
+//! assert_eq!(line.view().sc_bytes, 392);
+//! assert_eq!(reader.version(), Some("1.0"));
+//! assert!(reader.next().is_none());
+//! ```
+
+mod quality;
+#[cfg(feature = "tokio")]
+mod stream;
+
+pub use quality::{BadLine, Issue, QualityReport};
+#[cfg(feature = "tokio")]
+pub use stream::LogStream;
+
+use crate::{COMMENT, FIELDS_COMMENT, referential::ValidatedRawLogline};
+use std::{fmt, io::BufRead, marker::PhantomData};
+
+const VERSION_PREFIX: &str = "#Version:";
+const FIELDS_PREFIX: &str = "#Fields:";
+
+/// Error item of [`LogReader`] and `LogStream` (with the `tokio` feature)
+#[derive(Debug)]
+pub enum ReadError {
+    /// Reading from the underlying source failed
+    Io(std::io::Error),
+    /// The `#Fields:` header does not match the supported field list
+    UnexpectedFields { line: usize },
+    /// The line could not be parsed
+    Parse { line: usize, error: &'static str },
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "I/O error: {error}"),
+            Self::UnexpectedFields { line } => write!(f, "unexpected fields header in line {line}"),
+            Self::Parse { line, error } => write!(f, "{error} in line {line}"),
+        }
+    }
+}
+
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::UnexpectedFields { .. } | Self::Parse { .. } => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ReadError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+// header and line handling, shared by the sync and async readers
+#[derive(Debug, Default)]
+struct LineHandler {
+    line: usize,
+    version: Option<String>,
+}
+
+impl LineHandler {
+    // an unreadable line (e.g. invalid UTF-8) still counts, so the numbers of the later lines stay right
+    fn read_error(&mut self, error: std::io::Error) -> ReadError {
+        self.line += 1;
+        error.into()
+    }
+
+    fn handle<L>(&mut self, line: String) -> Option<Result<L, ReadError>>
+    where
+        L: TryFrom<String, Error = &'static str>,
+    {
+        self.line += 1;
+        if line.is_empty() {
+            return None;
+        }
+        if line.starts_with(COMMENT) {
+            if let Some(version) = line.strip_prefix(VERSION_PREFIX) {
+                self.version = Some(version.trim().to_string());
+            } else if line.starts_with(FIELDS_PREFIX) && line.trim_end() != FIELDS_COMMENT {
+                return Some(Err(ReadError::UnexpectedFields { line: self.line }));
+            }
+            return None;
+        }
+        let number = self.line;
+        Some(L::try_from(line).map_err(|error| ReadError::Parse {
+            line: number,
+            error,
+        }))
+    }
+}
+
+/// Iterator over the loglines of a [`BufRead`]
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug)]
+pub struct LogReader<R, L = ValidatedRawLogline> {
+    lines: std::io::Lines<R>,
+    handler: LineHandler,
+    _marker: PhantomData<fn() -> L>,
+}
+
+impl<R: BufRead, L> LogReader<R, L> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            handler: LineHandler::default(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the value of the `#Version:` header, if already read
+    pub fn version(&self) -> Option<&str> {
+        self.handler.version.as_deref()
+    }
+
+    /// Returns the number of lines read so far
+    pub fn lines_read(&self) -> usize {
+        self.handler.line
+    }
+}
+
+impl<R, L> Iterator for LogReader<R, L>
+where
+    R: BufRead,
+    L: TryFrom<String, Error = &'static str>,
+{
+    type Item = Result<L, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let line = match self.lines.next()? {
+                Ok(line) => line,
+                Err(error) => return Some(Err(self.handler.read_error(error))),
+            };
+            if let Some(item) = self.handler.handle(line) {
+                return Some(item);
+            }
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use crate::{
+    COMMENT_U8,
+    owned::UnvalidatedSimpleLogline,
+    shared::{parse_hms, parse_ymd, validate_line},
+    types::{DetailedEdgeResultType, EdgeResultType, Field, FieldError, FieldErrors},
+};
+use std::{collections::BTreeMap, fmt, io::BufRead};
+
+const DEFAULT_MAX_SAMPLES: usize = 10;
+
+/// Why a line was counted as bad by a [`QualityReport`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Issue {
+    /// The line is not valid UTF-8
+    NotUtf8,
+    /// The line does not have the expected number of fields
+    WrongFieldCount,
+    /// Some fields could not be parsed, see [lenient parsing](crate::types::Lenient)
+    FieldErrors(Vec<FieldError>),
+}
+
+/// A sample of a bad line, kept by a [`QualityReport`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BadLine {
+    /// The (1-based) line number
+    pub line: usize,
+    pub issue: Issue,
+    /// The line itself, invalid UTF-8 is replaced
+    pub content: String,
+}
+
+/// Accumulates the data quality of log lines, beyond pass or fail
+///
+/// Each line is counted by its outcome:
+/// valid, comment, empty, not UTF-8, wrong field count, or with field errors.
+/// For the latter, the fields which could not be parsed are counted as well;
+/// such lines are parsed leniently, so they still contribute to the other statistics.
+///
+/// The first bad lines are kept as samples (10 by default, see [`with_max_samples`](Self::with_max_samples)).
+///
+/// Result types which are not known to [`EdgeResultType`] and [`DetailedEdgeResultType`]
+/// are counted by value, which shows when CloudFront introduces new ones.
+///
+/// Line numbers count all lines added to the report, starting at 1.
+///
+/// # Examples
+///
+/// ```rust
+/// use cloudfront_logs::{reader::{Issue, QualityReport}, types::Field};
+///
+/// let input = "#Version: 1.0
+/// 2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-
+/// 2019-12-04	21:02:31	LAX1	392	192.0.2.???	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Teleported	text/html	78	-	-
+/// 2019-12-04	21:02:31	LAX1
+/// ";
+///
+/// let report = QualityReport::from_reader(input.as_bytes()).unwrap();
+///
+/// assert_eq!(report.lines(), 4);
+/// assert_eq!(report.valid, 1);
+/// assert_eq!(report.comment, 1);
+/// assert_eq!(report.with_field_errors, 1);
+/// assert_eq!(report.wrong_field_count, 1);
+/// assert_eq!(report.field_errors.get(&Field::CIp), Some(&1));
+/// assert_eq!(report.unknown_detailed_result_types.get("Teleported"), Some(&1));
+///
+/// assert_eq!(report.samples.len(), 2);
+/// assert_eq!(report.samples[1].line, 4);
+/// assert_eq!(report.samples[1].issue, Issue::WrongFieldCount);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct QualityReport {
+    pub valid: usize,
+    pub comment: usize,
+    pub empty: usize,
+    pub not_utf8: usize,
+    pub wrong_field_count: usize,
+    /// Lines with at least one field which could not be parsed
+    pub with_field_errors: usize,
+    /// Number of lines in which the field could not be parsed
+    pub field_errors: BTreeMap<Field, usize>,
+    /// Unknown values of `x-edge-result-type` and `x-edge-response-result-type`
+    pub unknown_result_types: BTreeMap<String, usize>,
+    /// Unknown values of `x-edge-detailed-result-type`
+    pub unknown_detailed_result_types: BTreeMap<String, usize>,
+    /// The first bad lines
+    pub samples: Vec<BadLine>,
+    max_samples: usize,
+    line: usize,
+}
+
+impl Default for QualityReport {
+    fn default() -> Self {
+        Self::with_max_samples(DEFAULT_MAX_SAMPLES)
+    }
+}
+
+impl QualityReport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty report which keeps at most the given number of bad lines as samples
+    pub fn with_max_samples(max_samples: usize) -> Self {
+        Self {
+            valid: 0,
+            comment: 0,
+            empty: 0,
+            not_utf8: 0,
+            wrong_field_count: 0,
+            with_field_errors: 0,
+            field_errors: BTreeMap::new(),
+            unknown_result_types: BTreeMap::new(),
+            unknown_detailed_result_types: BTreeMap::new(),
+            samples: Vec::new(),
+            max_samples,
+            line: 0,
+        }
+    }
+
+    /// Reads all lines of the reader into a new report
+    ///
+    /// Only I/O errors fail, invalid UTF-8 is counted like any other bad line.
+    pub fn from_reader<R: BufRead>(reader: R) -> std::io::Result<Self> {
+        let mut report = Self::new();
+        report.read_from(reader)?;
+        Ok(report)
+    }
+
+    /// Adds all lines of the reader to the report
+    ///
+    /// Lines are terminated by `\n` or `\r\n`.
+    pub fn read_from<R: BufRead>(&mut self, mut reader: R) -> std::io::Result<()> {
+        let mut buffer = Vec::new();
+        loop {
+            buffer.clear();
+            if reader.read_until(b'\n', &mut buffer)? == 0 {
+                return Ok(());
+            }
+            let line = buffer.strip_suffix(b"\n").unwrap_or(&buffer);
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            self.add_bytes(line);
+        }
+    }
+
+    /// Adds a single line, given without its line terminator, to the report
+    pub fn add_bytes(&mut self, line: &[u8]) {
+        match core::str::from_utf8(line) {
+            Ok(line) => self.add_line(line),
+            Err(_e) => {
+                self.line += 1;
+                self.not_utf8 += 1;
+                self.sample(Issue::NotUtf8, &String::from_utf8_lossy(line));
+            }
+        }
+    }
+
+    /// Adds a single line, given without its line terminator, to the report
+    pub fn add_line(&mut self, line: &str) {
+        self.line += 1;
+        if line.is_empty() {
+            self.empty += 1;
+            return;
+        }
+        if line.as_bytes().first() == Some(&COMMENT_U8) {
+            self.comment += 1;
+            return;
+        }
+        if validate_line(line).is_err() {
+            self.wrong_field_count += 1;
+            self.sample(Issue::WrongFieldCount, line);
+            return;
+        }
+        // the field count was checked, so the lenient parsing cannot fail
+        let Ok(lenient) = UnvalidatedSimpleLogline::parse_lenient(line) else {
+            return;
+        };
+        let logline = lenient.logline;
+        // the simple logline keeps date and time as strings, so they are checked here;
+        // keep the errors in field order, date and time come first
+        let mut errors = FieldErrors::default();
+        errors.parse(
+            Field::Date,
+            &logline.date,
+            |date| parse_ymd(date.as_bytes()),
+            (0, 0, 0),
+        );
+        errors.parse(
+            Field::Time,
+            &logline.time,
+            |time| parse_hms(time.as_bytes()),
+            (0, 0, 0),
+        );
+        errors.0.extend(lenient.errors);
+        let errors = errors.0;
+        for result_type in [
+            logline.x_edge_result_type,
+            logline.x_edge_response_result_type,
+        ] {
+            if let EdgeResultType::Other(other) = result_type {
+                *self
+                    .unknown_result_types
+                    .entry(other.into_owned())
+                    .or_default() += 1;
+            }
+        }
+        if let DetailedEdgeResultType::Other(other) = logline.x_edge_detailed_result_type {
+            *self
+                .unknown_detailed_result_types
+                .entry(other.into_owned())
+                .or_default() += 1;
+        }
+
+        if errors.is_empty() {
+            self.valid += 1;
+            return;
+        }
+        self.with_field_errors += 1;
+        for error in &errors {
+            *self.field_errors.entry(error.field).or_default() += 1;
+        }
+        self.sample(Issue::FieldErrors(errors), line);
+    }
+
+    fn sample(&mut self, issue: Issue, line: &str) {
+        if self.samples.len() < self.max_samples {
+            self.samples.push(BadLine {
+                line: self.line,
+                issue,
+                content: line.to_string(),
+            });
+        }
+    }
+
+    /// Returns the number of lines added so far
+    pub fn lines(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the number of lines which are neither valid, comments nor empty
+    pub fn bad_lines(&self) -> usize {
+        self.not_utf8 + self.wrong_field_count + self.with_field_errors
+    }
+
+    /// Returns true if there are no bad lines and no unknown result types
+    pub fn is_clean(&self) -> bool {
+        self.bad_lines() == 0
+            && self.unknown_result_types.is_empty()
+            && self.unknown_detailed_result_types.is_empty()
+    }
+}
+
+impl fmt::Display for QualityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "lines: {}", self.line)?;
+        writeln!(f, "  valid: {}", self.valid)?;
+        writeln!(f, "  comment: {}", self.comment)?;
+        writeln!(f, "  empty: {}", self.empty)?;
+        writeln!(f, "  not UTF-8: {}", self.not_utf8)?;
+        writeln!(f, "  wrong field count: {}", self.wrong_field_count)?;
+        writeln!(f, "  with field errors: {}", self.with_field_errors)?;
+        for (field, count) in &self.field_errors {
+            writeln!(f, "    {field}: {count}")?;
+        }
+        for (name, types) in [
+            ("unknown result types", &self.unknown_result_types),
+            (
+                "unknown detailed result types",
+                &self.unknown_detailed_result_types,
+            ),
+        ] {
+            if !types.is_empty() {
+                writeln!(f, "{name}:")?;
+                for (value, count) in types {
+                    writeln!(f, "  {value}: {count}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use super::{LineHandler, ReadError};
+use crate::referential::ValidatedRawLogline;
+use futures_core::Stream;
+use std::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, Lines};
+
+#[cfg(feature = "gzip")]
+use tokio::io::BufReader;
+
+/// Stream of the loglines of a tokio [`AsyncBufRead`]
+///
+/// The async counterpart of [`LogReader`](super::LogReader), yielding the same items.
+/// Lines are only read when the stream is polled, so a slow consumer applies backpressure to the source.
+///
+/// # Examples
+///
+/// ```rust
+/// use cloudfront_logs::{reader::LogStream, referential::ValidatedChronoLogline};
+/// use futures::StreamExt;
+///
+/// # futures::executor::block_on(async {
+/// let input = "#Version: 1.0
+/// 2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-
+/// ";
+///
+/// let mut stream = LogStream::<_, ValidatedChronoLogline>::new(input.as_bytes());
+/// let line = stream.next().await.unwrap().unwrap();
+///
+/// assert_eq!(line.view().sc_status, 200);
+/// assert!(stream.next().await.is_none());
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct LogStream<R, L = ValidatedRawLogline> {
+    lines: Lines<R>,
+    handler: LineHandler,
+    _marker: PhantomData<fn() -> L>,
+}
+
+impl<R: AsyncBufRead, L> LogStream<R, L> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            lines: reader.lines(),
+            handler: LineHandler::default(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the value of the `#Version:` header, if already read
+    pub fn version(&self) -> Option<&str> {
+        self.handler.version.as_deref()
+    }
+
+    /// Returns the number of lines read so far
+    pub fn lines_read(&self) -> usize {
+        self.handler.line
+    }
+}
+
+#[cfg(feature = "gzip")]
+impl<R: AsyncBufRead, L>
+    LogStream<BufReader<async_compression::tokio::bufread::GzipDecoder<R>>, L>
+{
+    /// Creates a stream over gzip compressed input, like the log files delivered by CloudFront
+    pub fn gzip(reader: R) -> Self {
+        let mut decoder = async_compression::tokio::bufread::GzipDecoder::new(reader);
+        // log files can be concatenated gzip members
+        decoder.multiple_members(true);
+        Self::new(BufReader::new(decoder))
+    }
+}
+
+impl<R, L> Stream for LogStream<R, L>
+where
+    R: AsyncBufRead + Unpin,
+    L: TryFrom<String, Error = &'static str>,
+{
+    type Item = Result<L, ReadError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        // all fields are Unpin, since R is Unpin and the marker does not own an L
+        let this = self.get_mut();
+        loop {
+            let line = match ready!(Pin::new(&mut this.lines).poll_next_line(cx)) {
+                Ok(Some(line)) => line,
+                Ok(None) => return Poll::Ready(None),
+                Err(error) => return Poll::Ready(Some(Err(this.handler.read_error(error)))),
+            };
+            if let Some(item) = this.handler.handle(line) {
+                return Poll::Ready(Some(item));
+            }
+        }
+    }
+}
@@ -0,0 +1,576 @@
+//! Common accessors across all logline variants
+//!
+//! Every logline type of the [`borrowed`](crate::borrowed), [`owned`](crate::owned), [`referential`](crate::referential) and [`chunked`](crate::chunked) families
+//! (as well as the [`LoglineView`](crate::LoglineView)) implements [`CloudFrontRecord`],
+//! so filters, aggregators and writers can be written once against `impl CloudFrontRecord`.
+//!
+//! The accessors return the same types for every variant.
+//! Typed variants return their already parsed values, raw variants parse the field on every call;
+//! that's why all non-string accessors return a [`Result`].
+//!
+//! The byte-slice variants ([`borrowed::bytes`](crate::borrowed::bytes)) are not covered,
+//! since their text fields are not guaranteed to be valid UTF-8.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use cloudfront_logs::{record::CloudFrontRecord, *};
+//!
+//! // works with any variant
+//! fn bytes_of_errors(records: &[impl CloudFrontRecord]) -> u64 {
+//!     records
+//!         .iter()
+//!         .filter(|r| r.status().is_ok_and(|status| status >= 500))
+//!         .filter_map(|r| r.bytes_sent().ok())
+//!         .sum()
+//! }
+//!
+//! let line = "2019-12-13	22:36:27	SEA19-C1	900	192.0.2.200	GET	d111111abcdef8.cloudfront.net	/favicon.ico	502	http://www.example.com/	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Error	1pkpNfBQ39sYMnjjUQjmH2w1wdJnbHYTbag21o_3OfcQgPzdL2RSSQ==	www.example.com	http	675	0.102	-	-	-	Error	HTTP/1.1	-	-	25260	0.102	OriginDnsError	text/html	507	-	-";
+//!
+//! let raw = [ValidatedRawLogline::try_from(line).unwrap()];
+//! let typed = [OwningValidatedChronoLogline::try_from(line).unwrap()];
+//!
+//! assert_eq!(bytes_of_errors(&raw), 900);
+//! assert_eq!(bytes_of_errors(&typed), 900);
+//! assert_eq!(typed[0].timestamp(), Ok(1576276587));
+//! ```
+
+use crate::{shared::*, types::*};
+
+/// Common accessors of a CloudFront log line
+///
+/// See the [module documentation](self) for details.
+pub trait CloudFrontRecord {
+    /// Request time (`date` and `time` fields) in seconds since the Unix epoch
+    fn timestamp(&self) -> Result<i64, &'static str>;
+
+    /// `x-edge-location`
+    fn edge_location(&self) -> &str;
+
+    /// `sc-bytes`
+    fn bytes_sent(&self) -> Result<u64, &'static str>;
+
+    /// `c-ip`
+    fn client_ip(&self) -> Result<IpAddr, &'static str>;
+
+    /// `cs-method`
+    fn method(&self) -> &str;
+
+    /// `cs(Host)`
+    fn host(&self) -> &str;
+
+    /// `cs-uri-stem`
+    fn uri_stem(&self) -> &str;
+
+    /// `sc-status`
+    fn status(&self) -> Result<u16, &'static str>;
+
+    /// `cs(Referer)`, `None` if not set
+    fn referer(&self) -> Option<&str>;
+
+    /// `cs(User-Agent)`
+    fn user_agent(&self) -> &str;
+
+    /// `cs-uri-query`, `None` if not set
+    fn uri_query(&self) -> Option<&str>;
+
+    /// `x-edge-result-type`
+    fn result_type(&self) -> Result<EdgeResultType<'_>, &'static str>;
+
+    /// `x-edge-request-id`
+    fn request_id(&self) -> &str;
+
+    /// `cs-protocol`
+    fn protocol(&self) -> Result<CsProtocol, &'static str>;
+
+    /// `cs-bytes`
+    fn bytes_received(&self) -> Result<u64, &'static str>;
+
+    /// `time-taken`
+    fn time_taken(&self) -> Result<Duration, &'static str>;
+
+    /// `time-to-first-byte`
+    fn time_to_first_byte(&self) -> Result<Duration, &'static str>;
+
+    /// `x-edge-detailed-result-type`
+    fn detailed_result_type(&self) -> Result<DetailedEdgeResultType<'_>, &'static str>;
+
+    /// `sc-content-type`, `None` if not set
+    fn content_type(&self) -> Option<&str>;
+
+    /// `sc-content-len`, `None` if not set
+    fn content_len(&self) -> Result<Option<u64>, &'static str>;
+}
+
+// raw variants: all fields are strings, accessible via `get`
+macro_rules! impl_for_raw {
+    ([$($gen:tt)*] $ty:ty) => {
+        impl<$($gen)*> CloudFrontRecord for $ty {
+            fn timestamp(&self) -> Result<i64, &'static str> {
+                parse_timestamp(self.get(Field::Date), self.get(Field::Time))
+                    .map_err(|_e| "date/time invalid")
+            }
+
+            fn edge_location(&self) -> &str {
+                self.get(Field::XEdgeLocation)
+            }
+
+            fn bytes_sent(&self) -> Result<u64, &'static str> {
+                self.get(Field::ScBytes).parse().map_err(|_e| "sc_bytes invalid")
+            }
+
+            fn client_ip(&self) -> Result<IpAddr, &'static str> {
+                self.get(Field::CIp).parse().map_err(|_e| "c_ip invalid")
+            }
+
+            fn method(&self) -> &str {
+                self.get(Field::CsMethod)
+            }
+
+            fn host(&self) -> &str {
+                self.get(Field::CsHost)
+            }
+
+            fn uri_stem(&self) -> &str {
+                self.get(Field::CsUriStem)
+            }
+
+            fn status(&self) -> Result<u16, &'static str> {
+                self.get(Field::ScStatus).parse().map_err(|_e| "sc_status invalid")
+            }
+
+            fn referer(&self) -> Option<&str> {
+                self.get(Field::CsReferer).as_optional_str()
+            }
+
+            fn user_agent(&self) -> &str {
+                self.get(Field::CsUserAgent)
+            }
+
+            fn uri_query(&self) -> Option<&str> {
+                self.get(Field::CsUriQuery).as_optional_str()
+            }
+
+            fn result_type(&self) -> Result<EdgeResultType<'_>, &'static str> {
+                Ok(EdgeResultType::from(self.get(Field::XEdgeResultType)))
+            }
+
+            fn request_id(&self) -> &str {
+                self.get(Field::XEdgeRequestId)
+            }
+
+            fn protocol(&self) -> Result<CsProtocol, &'static str> {
+                self.get(Field::CsProtocol).parse().map_err(|_e| "cs_protocol invalid")
+            }
+
+            fn bytes_received(&self) -> Result<u64, &'static str> {
+                self.get(Field::CsBytes).parse().map_err(|_e| "cs_bytes invalid")
+            }
+
+            fn time_taken(&self) -> Result<Duration, &'static str> {
+                parse_duration(self.get(Field::TimeTaken)).map_err(|_e| "time_taken invalid")
+            }
+
+            fn time_to_first_byte(&self) -> Result<Duration, &'static str> {
+                parse_duration(self.get(Field::TimeToFirstByte))
+                    .map_err(|_e| "time_to_first_byte invalid")
+            }
+
+            fn detailed_result_type(&self) -> Result<DetailedEdgeResultType<'_>, &'static str> {
+                Ok(DetailedEdgeResultType::from(
+                    self.get(Field::XEdgeDetailedResultType),
+                ))
+            }
+
+            fn content_type(&self) -> Option<&str> {
+                self.get(Field::ScContentType).as_optional_str()
+            }
+
+            fn content_len(&self) -> Result<Option<u64>, &'static str> {
+                parse_as_option(self.get(Field::ScContentLen)).map_err(|_e| "sc_content_len invalid")
+            }
+        }
+    };
+}
+
+// typed variants: the fields are already parsed, only the timestamp differs
+macro_rules! impl_for_typed {
+    ([$($gen:tt)*] $ty:ty, timestamp: $kind:ident) => {
+        impl<$($gen)*> CloudFrontRecord for $ty {
+            fn timestamp(&self) -> Result<i64, &'static str> {
+                impl_for_typed!(@timestamp $kind self)
+            }
+
+            fn edge_location(&self) -> &str {
+                &*self.x_edge_location
+            }
+
+            fn bytes_sent(&self) -> Result<u64, &'static str> {
+                Ok(self.sc_bytes)
+            }
+
+            fn client_ip(&self) -> Result<IpAddr, &'static str> {
+                Ok(self.c_ip)
+            }
+
+            fn method(&self) -> &str {
+                &*self.cs_method
+            }
+
+            fn host(&self) -> &str {
+                &*self.cs_host
+            }
+
+            fn uri_stem(&self) -> &str {
+                &*self.cs_uri_stem
+            }
+
+            fn status(&self) -> Result<u16, &'static str> {
+                Ok(self.sc_status)
+            }
+
+            fn referer(&self) -> Option<&str> {
+                self.cs_referer.as_deref()
+            }
+
+            fn user_agent(&self) -> &str {
+                &*self.cs_user_agent
+            }
+
+            fn uri_query(&self) -> Option<&str> {
+                self.cs_uri_query.as_deref()
+            }
+
+            fn result_type(&self) -> Result<EdgeResultType<'_>, &'static str> {
+                Ok(self.x_edge_result_type.as_borrowed())
+            }
+
+            fn request_id(&self) -> &str {
+                &*self.x_edge_request_id
+            }
+
+            fn protocol(&self) -> Result<CsProtocol, &'static str> {
+                Ok(self.cs_protocol)
+            }
+
+            fn bytes_received(&self) -> Result<u64, &'static str> {
+                Ok(self.cs_bytes)
+            }
+
+            fn time_taken(&self) -> Result<Duration, &'static str> {
+                Ok(self.time_taken)
+            }
+
+            fn time_to_first_byte(&self) -> Result<Duration, &'static str> {
+                Ok(self.time_to_first_byte)
+            }
+
+            fn detailed_result_type(&self) -> Result<DetailedEdgeResultType<'_>, &'static str> {
+                Ok(self.x_edge_detailed_result_type.as_borrowed())
+            }
+
+            fn content_type(&self) -> Option<&str> {
+                self.sc_content_type.as_deref()
+            }
+
+            fn content_len(&self) -> Result<Option<u64>, &'static str> {
+                Ok(self.sc_content_len)
+            }
+        }
+    };
+    (@timestamp strings $self:ident) => {
+        parse_timestamp(&$self.date, &$self.time).map_err(|_e| "date/time invalid")
+    };
+    (@timestamp chrono $self:ident) => {
+        Ok($self.datetime.and_utc().timestamp())
+    };
+    (@timestamp time $self:ident) => {
+        Ok($self.datetime.unix_timestamp())
+    };
+    (@timestamp jiff $self:ident) => {
+        Ok($self.datetime.as_second())
+    };
+}
+
+// parquet variants: enums, addresses and durations are lowered to strings and numbers
+#[cfg(feature = "parquet")]
+macro_rules! impl_for_parquet {
+    ([$($gen:tt)*] $ty:ty, durations: $durations:ident) => {
+        impl<$($gen)*> CloudFrontRecord for $ty {
+            fn timestamp(&self) -> Result<i64, &'static str> {
+                Ok(self.datetime.and_utc().timestamp())
+            }
+
+            fn edge_location(&self) -> &str {
+                &*self.x_edge_location
+            }
+
+            fn bytes_sent(&self) -> Result<u64, &'static str> {
+                Ok(self.sc_bytes)
+            }
+
+            fn client_ip(&self) -> Result<IpAddr, &'static str> {
+                self.c_ip.parse().map_err(|_e| "c_ip invalid")
+            }
+
+            fn method(&self) -> &str {
+                &*self.cs_method
+            }
+
+            fn host(&self) -> &str {
+                &*self.cs_host
+            }
+
+            fn uri_stem(&self) -> &str {
+                &*self.cs_uri_stem
+            }
+
+            fn status(&self) -> Result<u16, &'static str> {
+                Ok(self.sc_status)
+            }
+
+            fn referer(&self) -> Option<&str> {
+                self.cs_referer.as_deref()
+            }
+
+            fn user_agent(&self) -> &str {
+                &*self.cs_user_agent
+            }
+
+            fn uri_query(&self) -> Option<&str> {
+                self.cs_uri_query.as_deref()
+            }
+
+            fn result_type(&self) -> Result<EdgeResultType<'_>, &'static str> {
+                Ok(EdgeResultType::from(&*self.x_edge_result_type))
+            }
+
+            fn request_id(&self) -> &str {
+                &*self.x_edge_request_id
+            }
+
+            fn protocol(&self) -> Result<CsProtocol, &'static str> {
+                self.cs_protocol.parse().map_err(|_e| "cs_protocol invalid")
+            }
+
+            fn bytes_received(&self) -> Result<u64, &'static str> {
+                Ok(self.cs_bytes)
+            }
+
+            fn time_taken(&self) -> Result<Duration, &'static str> {
+                impl_for_parquet!(@time_taken $durations self)
+            }
+
+            fn time_to_first_byte(&self) -> Result<Duration, &'static str> {
+                impl_for_parquet!(@time_to_first_byte $durations self)
+            }
+
+            fn detailed_result_type(&self) -> Result<DetailedEdgeResultType<'_>, &'static str> {
+                Ok(DetailedEdgeResultType::from(
+                    &*self.x_edge_detailed_result_type,
+                ))
+            }
+
+            fn content_type(&self) -> Option<&str> {
+                self.sc_content_type.as_deref()
+            }
+
+            fn content_len(&self) -> Result<Option<u64>, &'static str> {
+                Ok(self.sc_content_len)
+            }
+        }
+    };
+    (@time_taken secs $self:ident) => {
+        Duration::try_from_secs_f64($self.time_taken).map_err(|_e| "time_taken invalid")
+    };
+    (@time_taken millis $self:ident) => {
+        Ok(Duration::from_millis($self.time_taken_ms))
+    };
+    (@time_to_first_byte secs $self:ident) => {
+        Duration::try_from_secs_f64($self.time_to_first_byte)
+            .map_err(|_e| "time_to_first_byte invalid")
+    };
+    (@time_to_first_byte millis $self:ident) => {
+        Ok(Duration::from_millis($self.time_to_first_byte_ms))
+    };
+}
+
+// referential variants: delegate to the borrowed view
+macro_rules! impl_by_view {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<V> CloudFrontRecord for $ty {
+                fn timestamp(&self) -> Result<i64, &'static str> {
+                    self.view().timestamp()
+                }
+
+                fn edge_location(&self) -> &str {
+                    self.view().edge_location()
+                }
+
+                fn bytes_sent(&self) -> Result<u64, &'static str> {
+                    self.view().bytes_sent()
+                }
+
+                fn client_ip(&self) -> Result<IpAddr, &'static str> {
+                    self.view().client_ip()
+                }
+
+                fn method(&self) -> &str {
+                    self.view().method()
+                }
+
+                fn host(&self) -> &str {
+                    self.view().host()
+                }
+
+                fn uri_stem(&self) -> &str {
+                    self.view().uri_stem()
+                }
+
+                fn status(&self) -> Result<u16, &'static str> {
+                    self.view().status()
+                }
+
+                fn referer(&self) -> Option<&str> {
+                    self.view().referer()
+                }
+
+                fn user_agent(&self) -> &str {
+                    self.view().user_agent()
+                }
+
+                fn uri_query(&self) -> Option<&str> {
+                    self.view().uri_query()
+                }
+
+                fn result_type(&self) -> Result<EdgeResultType<'_>, &'static str> {
+                    self.view().result_type()
+                }
+
+                fn request_id(&self) -> &str {
+                    self.view().request_id()
+                }
+
+                fn protocol(&self) -> Result<CsProtocol, &'static str> {
+                    self.view().protocol()
+                }
+
+                fn bytes_received(&self) -> Result<u64, &'static str> {
+                    self.view().bytes_received()
+                }
+
+                fn time_taken(&self) -> Result<Duration, &'static str> {
+                    self.view().time_taken()
+                }
+
+                fn time_to_first_byte(&self) -> Result<Duration, &'static str> {
+                    self.view().time_to_first_byte()
+                }
+
+                fn detailed_result_type(&self) -> Result<DetailedEdgeResultType<'_>, &'static str> {
+                    self.view().detailed_result_type()
+                }
+
+                fn content_type(&self) -> Option<&str> {
+                    self.view().content_type()
+                }
+
+                fn content_len(&self) -> Result<Option<u64>, &'static str> {
+                    self.view().content_len()
+                }
+            }
+        )*
+    };
+}
+
+impl_for_raw!([] crate::borrowed::LoglineView<'_>);
+impl_for_raw!([V] crate::borrowed::raw::Logline<'_, V>);
+impl_for_raw!([V] crate::owned::raw::Logline<V>);
+
+impl_for_typed!([V] crate::borrowed::simple::Logline<'_, V>, timestamp: strings);
+impl_for_typed!([V] crate::owned::simple::Logline<V>, timestamp: strings);
+#[cfg(feature = "std")]
+impl_for_typed!([V] crate::owned::interned::Logline<V>, timestamp: strings);
+
+#[cfg(feature = "chrono")]
+impl_for_typed!([V] crate::borrowed::typed::chrono::Logline<'_, V>, timestamp: chrono);
+#[cfg(feature = "chrono")]
+impl_for_typed!([V] crate::owned::typed::chrono::Logline<V>, timestamp: chrono);
+
+#[cfg(feature = "time")]
+impl_for_typed!([V] crate::borrowed::typed::time::Logline<'_, V>, timestamp: time);
+#[cfg(feature = "time")]
+impl_for_typed!([V] crate::owned::typed::time::Logline<V>, timestamp: time);
+
+#[cfg(feature = "jiff")]
+impl_for_typed!([V] crate::borrowed::typed::jiff::Logline<'_, V>, timestamp: jiff);
+
+#[cfg(feature = "parquet")]
+impl_for_parquet!([] crate::borrowed::parquet::ValidatedLogline<'_>, durations: secs);
+#[cfg(feature = "parquet")]
+impl_for_parquet!([] crate::borrowed::parquet::UnvalidatedLogline<'_>, durations: secs);
+#[cfg(feature = "parquet")]
+impl_for_parquet!([] crate::owned::parquet::ValidatedLogline, durations: secs);
+#[cfg(feature = "parquet")]
+impl_for_parquet!([] crate::owned::parquet::UnvalidatedLogline, durations: secs);
+#[cfg(feature = "parquet")]
+impl_for_parquet!([] crate::borrowed::parquet_millis::ValidatedLogline<'_>, durations: millis);
+#[cfg(feature = "parquet")]
+impl_for_parquet!([] crate::borrowed::parquet_millis::UnvalidatedLogline<'_>, durations: millis);
+#[cfg(feature = "parquet")]
+impl_for_parquet!([] crate::owned::parquet_millis::ValidatedLogline, durations: millis);
+#[cfg(feature = "parquet")]
+impl_for_parquet!([] crate::owned::parquet_millis::UnvalidatedLogline, durations: millis);
+
+impl_by_view!(
+    crate::referential::raw::Logline<V>,
+    crate::referential::simple::Logline<V>,
+);
+
+#[cfg(feature = "chrono")]
+impl_by_view!(crate::referential::typed::chrono::Logline<V>);
+
+#[cfg(feature = "time")]
+impl_by_view!(crate::referential::typed::time::Logline<V>);
+
+#[cfg(feature = "jiff")]
+impl_by_view!(crate::referential::typed::jiff::Logline<V>);
+
+#[cfg(feature = "parquet")]
+impl_by_view!(crate::referential::parquet::Logline<V>);
+
+impl_by_view!(
+    crate::chunked::raw::Logline<V>,
+    crate::chunked::simple::Logline<V>,
+);
+
+#[cfg(feature = "chrono")]
+impl_by_view!(crate::chunked::typed::chrono::Logline<V>);
+
+#[cfg(feature = "time")]
+impl_by_view!(crate::chunked::typed::time::Logline<V>);
+
+#[cfg(feature = "jiff")]
+impl_by_view!(crate::chunked::typed::jiff::Logline<V>);
+
+#[cfg(feature = "parquet")]
+impl_by_view!(crate::chunked::parquet::Logline<V>);
+
+#[cfg(feature = "bytes")]
+impl_by_view!(
+    crate::bytes_backed::raw::Logline<V>,
+    crate::bytes_backed::simple::Logline<V>,
+);
+
+#[cfg(all(feature = "bytes", feature = "chrono"))]
+impl_by_view!(crate::bytes_backed::typed::chrono::Logline<V>);
+
+#[cfg(all(feature = "bytes", feature = "time"))]
+impl_by_view!(crate::bytes_backed::typed::time::Logline<V>);
+
+#[cfg(all(feature = "bytes", feature = "jiff"))]
+impl_by_view!(crate::bytes_backed::typed::jiff::Logline<V>);
+
+#[cfg(all(feature = "bytes", feature = "parquet"))]
+impl_by_view!(crate::bytes_backed::parquet::Logline<V>);
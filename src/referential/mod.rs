@@ -35,6 +35,11 @@ pub use typed::time::{
     UnvalidatedLogline as UnvalidatedTimeLogline, ValidatedLogline as ValidatedTimeLogline,
 };
 
+#[cfg(feature = "jiff")]
+pub use typed::jiff::{
+    UnvalidatedLogline as UnvalidatedJiffLogline, ValidatedLogline as ValidatedJiffLogline,
+};
+
 #[cfg(feature = "parquet")]
 pub use parquet::{
     UnvalidatedLogline as UnvalidatedParquetLogline, ValidatedLogline as ValidatedParquetLogline,
@@ -26,7 +26,8 @@ impl Clone for Container {
     fn clone(&self) -> Self {
         let input = Arc::clone(self.borrow_owner());
         Self::new(input, |line| {
-            BorrowedLine::try_from(line.as_ref()).expect("invalid line input despite validation")
+            BorrowedLine::try_from(line.as_ref())
+                .expect("an already parsed line cannot fail to parse")
         })
     }
 }
@@ -62,6 +63,34 @@ impl<V> Logline<V> {
     }
 }
 
+impl Logline<Validated> {
+    /// Copies the fields into the [owned](crate::owned::parquet) variant
+    pub fn to_owned_logline(&self) -> crate::owned::parquet::ValidatedLogline {
+        crate::owned::parquet::UnvalidatedLogline::from(self.view().clone()).into()
+    }
+
+    /// Converts the line into the [owned](crate::owned::parquet) variant
+    ///
+    /// The line string is dropped afterwards.
+    pub fn into_owned(self) -> crate::owned::parquet::ValidatedLogline {
+        self.to_owned_logline()
+    }
+}
+
+impl Logline<Unvalidated> {
+    /// Copies the fields into the [owned](crate::owned::parquet) variant
+    pub fn to_owned_logline(&self) -> crate::owned::parquet::UnvalidatedLogline {
+        self.view().clone().into()
+    }
+
+    /// Converts the line into the [owned](crate::owned::parquet) variant
+    ///
+    /// The line string is dropped afterwards.
+    pub fn into_owned(self) -> crate::owned::parquet::UnvalidatedLogline {
+        self.to_owned_logline()
+    }
+}
+
 macro_rules! impl_try_from {
     ($in:ty, $out_v:ident, $out_u:ident) => {
         impl TryFrom<$in> for $out_v {
@@ -70,10 +99,8 @@ macro_rules! impl_try_from {
             fn try_from(line: $in) -> Result<Self, Self::Error> {
                 let line: LineStr = line.into();
                 validate_line(&line)?;
-                let container = Container::new(line, |line| {
-                    BorrowedLine::try_from(line.as_ref())
-                        .expect("invalid line input despite validation")
-                });
+                let container =
+                    Container::try_new(line, |line| BorrowedLine::try_from(line.as_ref()))?;
                 Ok($out_v {
                     inner: container,
                     _marker: PhantomData,
@@ -85,10 +112,8 @@ macro_rules! impl_try_from {
             type Error = &'static str;
 
             fn try_from(line: $in) -> Result<Self, Self::Error> {
-                let container = Container::new(line.into(), |line| {
-                    BorrowedLine::try_from(line.as_ref())
-                        .expect("invalid line input despite validation")
-                });
+                let container =
+                    Container::try_new(line.into(), |line| BorrowedLine::try_from(line.as_ref()))?;
                 Ok($out_u {
                     inner: container,
                     _marker: PhantomData,
@@ -48,6 +48,23 @@ impl<V> Logline<V> {
     pub fn into_raw(self) -> LineStr {
         self.inner.into_owner()
     }
+
+    /// Copies the fields into the [owned](crate::owned::raw) variant, keeping the validation marker
+    pub fn to_owned_logline(&self) -> crate::owned::raw::Logline<V> {
+        crate::owned::raw::from_borrowed(*self.view())
+    }
+
+    /// Converts the line into the [owned](crate::owned::raw) variant, keeping the validation marker
+    ///
+    /// The line string is dropped afterwards.
+    pub fn into_owned(self) -> crate::owned::raw::Logline<V> {
+        self.to_owned_logline()
+    }
+
+    /// Returns the field, see [`BorrowedLine::get`]
+    pub fn get(&self, field: Field) -> &str {
+        self.view().get(field)
+    }
 }
 
 macro_rules! impl_try_from {
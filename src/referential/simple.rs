@@ -24,7 +24,8 @@ impl Clone for Container {
     fn clone(&self) -> Self {
         let input = Arc::clone(self.borrow_owner());
         Self::new(input, |line| {
-            BorrowedLine::try_from(line.as_ref()).expect("invalid line input despite validation")
+            BorrowedLine::try_from(line.as_ref())
+                .expect("an already parsed line cannot fail to parse")
         })
     }
 }
@@ -50,6 +51,18 @@ impl<V> Logline<V> {
     pub fn into_raw(self) -> LineStr {
         self.inner.into_owner()
     }
+
+    /// Copies the fields into the [owned](crate::owned::simple) variant, keeping the validation marker
+    pub fn to_owned_logline(&self) -> crate::owned::simple::Logline<V> {
+        crate::owned::simple::from_borrowed(self.view().clone())
+    }
+
+    /// Converts the line into the [owned](crate::owned::simple) variant, keeping the validation marker
+    ///
+    /// The line string is dropped afterwards.
+    pub fn into_owned(self) -> crate::owned::simple::Logline<V> {
+        self.to_owned_logline()
+    }
 }
 
 macro_rules! impl_try_from {
@@ -60,10 +73,8 @@ macro_rules! impl_try_from {
             fn try_from(line: $in) -> Result<Self, Self::Error> {
                 let line: LineStr = line.into();
                 validate_line(&line)?;
-                let container = Container::new(line, |line| {
-                    BorrowedLine::try_from(line.as_ref())
-                        .expect("invalid line input despite validation")
-                });
+                let container =
+                    Container::try_new(line, |line| BorrowedLine::try_from(line.as_ref()))?;
                 Ok($out_v {
                     inner: container,
                     _marker: PhantomData,
@@ -75,10 +86,8 @@ macro_rules! impl_try_from {
             type Error = &'static str;
 
             fn try_from(line: $in) -> Result<Self, Self::Error> {
-                let container = Container::new(line.into(), |line| {
-                    BorrowedLine::try_from(line.as_ref())
-                        .expect("invalid line input despite validation")
-                });
+                let container =
+                    Container::try_new(line.into(), |line| BorrowedLine::try_from(line.as_ref()))?;
                 Ok($out_u {
                     inner: container,
                     _marker: PhantomData,
@@ -1,5 +1,8 @@
 use crate::{COMMENT_U8, TAB_U8, TABS};
 
+#[cfg(feature = "alloc")]
+use crate::types::{String, ToString};
+
 /// Validates a log line
 ///
 /// This function checks if
@@ -24,7 +27,15 @@ use crate::{COMMENT_U8, TAB_U8, TABS};
 /// ```
 #[inline]
 pub fn validate_line(line: &str) -> Result<(), &'static str> {
-    let bytes = line.as_bytes();
+    validate_bytes(line.as_bytes())
+}
+
+/// Validates a log line given as bytes
+///
+/// Same checks as [`validate_line`], but without requiring the input to be valid UTF-8.
+/// Use it together with the [`bytes`](crate::borrowed::bytes) logline variants.
+#[inline]
+pub fn validate_bytes(bytes: &[u8]) -> Result<(), &'static str> {
     if bytes.is_empty() {
         return Err("Invalid log line (empty)");
     }
@@ -37,6 +48,7 @@ pub fn validate_line(line: &str) -> Result<(), &'static str> {
     Ok(())
 }
 
+#[cfg(feature = "std")]
 #[inline]
 pub(crate) fn split(line: &str) -> MemchrTabSplitter<'_> {
     MemchrTabSplitter::new(line)
@@ -64,35 +76,111 @@ impl<'a> MemchrTabSplitter<'a> {
     }
 }
 
+impl<'a> MemchrTabSplitter<'a> {
+    // returns the next field, or an error if the line has not enough fields;
+    // used by the parsers to stay panic-free on any input
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub(crate) fn field(&mut self) -> Result<&'a str, &'static str> {
+        self.next().ok_or("Invalid log line (field count)")
+    }
+}
+
 impl<'a> Iterator for MemchrTabSplitter<'a> {
     type Item = &'a str;
 
     fn next(&mut self) -> Option<Self::Item> {
         let current_tab = self.iter.next();
         if let Some(tab_idx) = current_tab {
-            assert!(tab_idx > 0, "Found tab stop at index 0 (invalid log line)");
-
             let from = self.prev;
-            let to = tab_idx;
-            self.prev = to + 1;
-            Some(&self.data[from..to])
+            self.prev = tab_idx + 1;
+            // tab stops are ASCII, so the range is always on char boundaries
+            self.data.get(from..tab_idx)
+        } else if self.prev <= self.end {
+            // get field after the last tab stop (can be empty)
+            let from = self.prev;
+            self.prev = self.end + 1;
+            self.data.get(from..)
         } else {
-            // get field after the last tab stop
-            if self.prev < self.end {
-                let from = self.prev;
-                self.prev = self.end;
-                Some(&self.data[from..])
-            } else {
-                None
-            }
+            None
         }
     }
 }
 
+#[derive(Debug, Clone)]
+pub(crate) struct MemchrByteSplitter<'a> {
+    pub(crate) data: &'a [u8],
+    pub(crate) prev: usize,
+    pub(crate) iter: memchr::Memchr<'a>,
+}
+
+impl<'a> MemchrByteSplitter<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        let iter = memchr::memchr_iter(TAB_U8, data);
+        Self {
+            data,
+            prev: 0,
+            iter,
+        }
+    }
+}
+
+impl<'a> Iterator for MemchrByteSplitter<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(tab_idx) = self.iter.next() {
+            let field = self.data.get(self.prev..tab_idx);
+            self.prev = tab_idx + 1;
+            field
+        } else {
+            // get field after the last tab stop (can be empty);
+            // get() returns None once the position is past the end
+            let field = self.data.get(self.prev..);
+            self.prev = self.data.len() + 1;
+            field
+        }
+    }
+}
+
+// parses a field given as bytes;
+// the UTF-8 check is cheap for the short, structured fields
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn parse_bytes<T: core::str::FromStr>(b: &[u8]) -> Result<T, ()> {
+    let s = str_from_bytes(b).map_err(|_e| ())?;
+    s.parse().map_err(|_e| ())
+}
+
+// returns the field as str, for the structured fields which are expected to be ASCII
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn str_from_bytes(b: &[u8]) -> Result<&str, core::str::Utf8Error> {
+    core::str::from_utf8(b)
+}
+
+// like str_from_bytes, but returns None if the input is "-"
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn str_from_bytes_as_option(b: &[u8]) -> Result<Option<&str>, core::str::Utf8Error> {
+    if b == b"-" {
+        Ok(None)
+    } else {
+        str_from_bytes(b).map(Some)
+    }
+}
+
+// like parse_as_option, but for fields given as bytes
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn parse_bytes_as_option<T: core::str::FromStr>(b: &[u8]) -> Result<Option<T>, ()> {
+    if b == b"-" {
+        Ok(None)
+    } else {
+        parse_bytes(b).map(Some)
+    }
+}
+
 // if the input is "-", return Ok(None), otherwise parse the input as T;
-// -> parse_as_option(iter.next().unwrap()).map_err(|_e| "…")?
+// -> parse_as_option(iter.field()?).map_err(|_e| "…")?
 // -> parse_as_option(str_input).map_err(|_e| "…")?
-pub(crate) fn parse_as_option<T: std::str::FromStr>(s: &str) -> Result<Option<T>, T::Err> {
+pub(crate) fn parse_as_option<T: core::str::FromStr>(s: &str) -> Result<Option<T>, T::Err> {
     if s == "-" {
         Ok(None)
     } else {
@@ -100,18 +188,187 @@ pub(crate) fn parse_as_option<T: std::str::FromStr>(s: &str) -> Result<Option<T>
     }
 }
 
-// better chainable version of parse_as_option;
-// -> iter.next().and_then(as_optional_t).transpose().map_err(|_e| "…")?
-pub(crate) fn as_optional_t<T: std::str::FromStr>(s: &str) -> Option<Result<T, T::Err>> {
-    if s == "-" { None } else { Some(s.parse()) }
+// parses the seconds of the time fields (like "0.001") into a Duration;
+// CloudFront writes them as fixed-point decimals, so they are parsed exactly as integers
+// instead of going through f64, which is slower and cannot represent most decimal fractions;
+// signs, exponents, more than 9 fractional digits and too large values are rejected
+pub(crate) fn parse_duration(s: &str) -> Result<core::time::Duration, ()> {
+    let (secs, fraction) = s.split_once('.').unwrap_or((s, ""));
+    if (secs.is_empty() && fraction.is_empty()) || fraction.len() > 9 {
+        return Err(());
+    }
+    let mut nanos = decimal(fraction.as_bytes())?;
+    for _ in fraction.len()..9 {
+        nanos *= 10;
+    }
+    let nanos = u32::try_from(nanos).map_err(|_e| ())?;
+    Ok(core::time::Duration::new(decimal(secs.as_bytes())?, nanos))
+}
+
+// parses the seconds of the time fields (like "0.001") into whole milliseconds;
+// values with sub-millisecond precision are rejected instead of being truncated
+#[cfg(feature = "parquet")]
+pub(crate) fn parse_millis(s: &str) -> Result<u64, ()> {
+    let duration = parse_duration(s)?;
+    if !duration.subsec_nanos().is_multiple_of(1_000_000) {
+        return Err(());
+    }
+    u64::try_from(duration.as_millis()).map_err(|_e| ())
+}
+
+// unsigned decimal digits without sign; empty input is zero
+fn decimal(digits: &[u8]) -> Result<u64, ()> {
+    digits.iter().try_fold(0u64, |acc, &digit| {
+        if !digit.is_ascii_digit() {
+            return Err(());
+        }
+        acc.checked_mul(10)
+            .and_then(|acc| acc.checked_add(u64::from(digit - b'0')))
+            .ok_or(())
+    })
+}
+
+// parses the date ("2019-12-04") and time ("21:02:31") fields into seconds since the Unix epoch;
+// CloudFront logs are always in UTC, so no date/time crate is needed for this
+#[cfg(feature = "alloc")]
+pub(crate) fn parse_timestamp(date: &str, time: &str) -> Result<i64, ()> {
+    let (year, month, day) = parse_ymd(date.as_bytes())?;
+    let (hour, minute, second) = parse_hms(time.as_bytes())?;
+    let days = days_from_civil(i64::from(year), i64::from(month), i64::from(day));
+    Ok(days * 86_400 + i64::from(hour) * 3_600 + i64::from(minute) * 60 + i64::from(second))
+}
+
+// fast path for the fixed-width date field ("2019-12-04");
+// much cheaper than the format string based parsers of the date/time crates,
+// and the result is already a valid calendar date for all of them
+#[cfg(feature = "alloc")]
+pub(crate) fn parse_ymd(date: &[u8]) -> Result<(u16, u8, u8), ()> {
+    let &[y0, y1, y2, y3, b'-', m0, m1, b'-', d0, d1] = date else {
+        return Err(());
+    };
+    let year = u16::from(digits(y0, y1)?) * 100 + u16::from(digits(y2, y3)?);
+    let month = digits(m0, m1)?;
+    let day = digits(d0, d1)?;
+    check_ymd(year, month, day)?;
+    Ok((year, month, day))
+}
+
+// the range checks of `parse_ymd`, for dates which do not come from a string
+#[cfg(feature = "alloc")]
+pub(crate) fn check_ymd(year: u16, month: u8, day: u8) -> Result<(), ()> {
+    if year > 9999 || !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return Err(());
+    }
+    Ok(())
+}
+
+// fast path for the fixed-width time field ("21:02:31"); leap seconds do not occur in the logs
+#[cfg(feature = "alloc")]
+pub(crate) fn parse_hms(time: &[u8]) -> Result<(u8, u8, u8), ()> {
+    let &[h0, h1, b':', m0, m1, b':', s0, s1] = time else {
+        return Err(());
+    };
+    let (hour, minute, second) = (digits(h0, h1)?, digits(m0, m1)?, digits(s0, s1)?);
+    check_hms(hour, minute, second)?;
+    Ok((hour, minute, second))
+}
+
+// the range checks of `parse_hms`, for times which do not come from a string
+#[cfg(feature = "alloc")]
+pub(crate) fn check_hms(hour: u8, minute: u8, second: u8) -> Result<(), ()> {
+    if hour > 23 || minute > 59 || second > 59 {
+        return Err(());
+    }
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_chrono_date(date: &str) -> Result<chrono::NaiveDate, ()> {
+    let (year, month, day) = parse_ymd(date.as_bytes())?;
+    chrono::NaiveDate::from_ymd_opt(i32::from(year), u32::from(month), u32::from(day)).ok_or(())
+}
+
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_chrono_time(time: &str) -> Result<chrono::NaiveTime, ()> {
+    let (hour, minute, second) = parse_hms(time.as_bytes())?;
+    chrono::NaiveTime::from_hms_opt(u32::from(hour), u32::from(minute), u32::from(second)).ok_or(())
+}
+
+#[cfg(feature = "time")]
+pub(crate) fn parse_time_date(date: &str) -> Result<time::Date, ()> {
+    let (year, month, day) = parse_ymd(date.as_bytes())?;
+    let month = time::Month::try_from(month).map_err(|_e| ())?;
+    time::Date::from_calendar_date(i32::from(year), month, day).map_err(|_e| ())
+}
+
+#[cfg(feature = "time")]
+pub(crate) fn parse_time_time(time: &str) -> Result<time::Time, ()> {
+    let (hour, minute, second) = parse_hms(time.as_bytes())?;
+    time::Time::from_hms(hour, minute, second).map_err(|_e| ())
+}
+
+#[cfg(feature = "jiff")]
+pub(crate) fn parse_jiff_date(date: &str) -> Result<jiff::civil::Date, ()> {
+    let (year, month, day) = parse_ymd(date.as_bytes())?;
+    let year = i16::try_from(year).map_err(|_e| ())?;
+    let (month, day) = (month.cast_signed(), day.cast_signed());
+    jiff::civil::Date::new(year, month, day).map_err(|_e| ())
+}
+
+#[cfg(feature = "jiff")]
+pub(crate) fn parse_jiff_time(time: &str) -> Result<jiff::civil::Time, ()> {
+    let (hour, minute, second) = parse_hms(time.as_bytes())?;
+    jiff::civil::Time::new(
+        hour.cast_signed(),
+        minute.cast_signed(),
+        second.cast_signed(),
+        0,
+    )
+    .map_err(|_e| ())
+}
+
+// two ASCII digits into their value
+#[cfg(feature = "alloc")]
+fn digits(tens: u8, ones: u8) -> Result<u8, ()> {
+    if tens.is_ascii_digit() && ones.is_ascii_digit() {
+        Ok((tens - b'0') * 10 + (ones - b'0'))
+    } else {
+        Err(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// days since 1970-01-01 of a proleptic Gregorian date,
+// see <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+#[cfg(feature = "alloc")]
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
 }
 
 // String type extension trait;
 // returns None if the input is "-", otherwise Some(String)
+#[cfg(feature = "alloc")]
 pub(crate) trait ToOptionalString {
     fn to_optional_string(&self) -> Option<String>;
 }
 
+#[cfg(feature = "alloc")]
 impl ToOptionalString for &str {
     fn to_optional_string(&self) -> Option<String> {
         if self == &"-" {
@@ -124,14 +381,890 @@ impl ToOptionalString for &str {
 
 // str type extension trait;
 // returns None if the input is "-", otherwise Some(&str)
-#[cfg(feature = "parquet")]
+#[cfg(feature = "alloc")]
 pub(crate) trait AsOptionalStr {
     fn as_optional_str(&self) -> Option<&str>;
 }
 
-#[cfg(feature = "parquet")]
+#[cfg(feature = "alloc")]
 impl AsOptionalStr for str {
     fn as_optional_str(&self) -> Option<&str> {
         if self == "-" { None } else { Some(self) }
     }
 }
+
+// in-place counterparts of `to_string`/`to_optional_string` for the `parse_into` methods;
+// the existing buffers are reused, only a `None` field needs a new allocation.
+// A `-` sets the field to `None` and so drops its buffer, `None` cannot keep a capacity;
+// this is documented on the `parse_into` methods.
+#[cfg(feature = "alloc")]
+pub(crate) fn assign_string(target: &mut String, value: &str) {
+    target.clear();
+    target.push_str(value);
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn assign_optional_string(target: &mut Option<String>, value: &str) {
+    match (value.as_optional_str(), target) {
+        (None, target) => *target = None,
+        (Some(value), Some(target)) => assign_string(target, value),
+        (Some(value), target) => *target = Some(value.to_string()),
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn assign_forwarded_for(
+    target: &mut Option<crate::types::ForwardedForAddrs>,
+    value: &str,
+) -> Result<(), &'static str> {
+    match (value.as_optional_str(), target) {
+        (None, target) => *target = None,
+        (Some(value), Some(target)) => target.parse_into(value)?,
+        (Some(value), target) => *target = Some(value.parse()?),
+    }
+    Ok(())
+}
+
+// implements the validated and unvalidated parquet loglines of the `borrowed` or `owned` family;
+// the variants of a family only differ in how the durations are stored, see the `parquet_millis` modules.
+// `input` is the lifetime of the parsed line, `strings` the type of the text fields.
+#[cfg(feature = "parquet")]
+macro_rules! impl_parquet_loglines {
+    (
+        family: $family:ident<$($lt:lifetime)?>,
+        input: $input:lifetime,
+        strings: [$($string:tt)+], optional: $optional:ident,
+        module: $module:ident,
+        schema: $schema:ident,
+        durations: $duration:ident as $duration_doc:literal, parsed by $parse:expr,
+        fields: $time_taken:ident, $time_to_first_byte:ident,
+        title: $title:literal,
+        docs: [$($doc:literal),* $(,)?],
+        example: [$($example:literal),* $(,)?] $(,)?
+    ) => {
+        #[doc = concat!(" The validated log line for [`parquet`] usage", $title)]
+        $(#[doc = $doc])*
+        ///
+        /// Most fields are parsed into more meaningful types.
+        /// Unfortunately, [`parquet_derive`] does not support all the types;
+        /// thus we lower some fields down to:
+        /// * &str / Option<&str> (instead of enums, NaiveTime, IpAddr)
+        #[doc = concat!(" * ", $duration_doc, " (instead of Duration)")]
+        ///
+        /// On construction it checks if the line can be parsed.
+        /// This is useful if you cannot skip the comment lines or have reason to not trust the input for format correctness.
+        /// The latter should be only an issue if you do not use this crate on CloudFront logs directly.
+        ///
+        /// # Errors
+        ///
+        /// Construction never panics, an invalid log line results in an error instead.
+        ///
+        /// # Examples
+        ///
+        /// Use `.try_from()` or `.try_into()` to construct an instance, since action can fail.
+        ///
+        /// ```rust
+        #[doc = concat!(" use cloudfront_logs::{", stringify!($family), "::", stringify!($module), "::ValidatedLogline, types::*};")]
+        ///
+        /// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+        ///
+        /// let item = ValidatedLogline::try_from(line).unwrap();
+        /// // alternative:
+        #[doc = concat!(" let item: ValidatedLogline", $crate::shared::impl_parquet_loglines!(@elided $family), " = line.try_into().unwrap();")]
+        ///
+        /// assert_eq!(item.date, NaiveDate::from_ymd_opt(2019, 12, 4).unwrap());
+        /// assert_eq!(item.sc_bytes, 392u64);
+        /// assert_eq!(item.cs_protocol, "https");
+        $(#[doc = $example])*
+        /// ```
+        #[must_use]
+        #[derive(Debug, Clone, PartialEq, parquet_derive::ParquetRecordWriter)]
+        pub struct ValidatedLogline<$($lt)?> {
+            pub date: NaiveDate,
+            pub time: $($string)+, // not supported: NaiveTime
+            pub datetime: NaiveDateTime,
+            pub x_edge_location: $($string)+,
+            pub sc_bytes: u64,
+            pub c_ip: $($string)+,
+            pub cs_method: $($string)+,
+            pub cs_host: $($string)+,
+            pub cs_uri_stem: $($string)+,
+            pub sc_status: u16,
+            pub cs_referer: Option<$($string)+>,
+            pub cs_user_agent: $($string)+,
+            pub cs_uri_query: Option<$($string)+>,
+            pub cs_cookie: Option<$($string)+>,
+            pub x_edge_result_type: $($string)+,
+            pub x_edge_request_id: $($string)+,
+            pub x_host_header: $($string)+,
+            pub cs_protocol: $($string)+,
+            pub cs_bytes: u64,
+            pub $time_taken: $duration,
+            pub x_forwarded_for: Option<$($string)+>,
+            pub ssl_protocol: Option<$($string)+>,
+            pub ssl_cipher: Option<$($string)+>,
+            pub x_edge_response_result_type: $($string)+,
+            pub cs_protocol_version: $($string)+,
+            pub fle_status: Option<$($string)+>,
+            pub fle_encrypted_fields: Option<u64>,
+            pub c_port: u16,
+            pub $time_to_first_byte: $duration,
+            pub x_edge_detailed_result_type: $($string)+,
+            pub sc_content_type: Option<$($string)+>,
+            pub sc_content_len: Option<u64>,
+            pub sc_range_start: Option<i64>,
+            pub sc_range_end: Option<i64>,
+        }
+
+        impl<$($lt)?> ValidatedLogline<$($lt)?> {
+            pub fn schema() -> &'static str {
+                crate::consts::parquet_schemata::$schema
+            }
+
+            pub fn schema_as_type() -> parquet::schema::types::Type {
+                parquet::schema::parser::parse_message_type(crate::consts::parquet_schemata::$schema).unwrap()
+            }
+        }
+
+        impl<$($lt)?> ValidatedLogline<$($lt)?> {
+            /// The request time as UTC datetime
+            ///
+            /// CloudFront logs are always in UTC; the `datetime` field just does not carry the timezone.
+            pub fn datetime_utc(&self) -> DateTime<Utc> {
+                self.datetime.and_utc()
+            }
+
+            /// The request time as seconds since the Unix epoch
+            pub fn unix_timestamp(&self) -> i64 {
+                self.datetime.and_utc().timestamp()
+            }
+
+            /// The request time as milliseconds since the Unix epoch
+            pub fn unix_timestamp_millis(&self) -> i64 {
+                self.datetime.and_utc().timestamp_millis()
+            }
+
+            /// The request time in the given timezone, e.g. for reporting in local time
+            pub fn datetime_in<Tz: TimeZone>(&self, tz: &Tz) -> DateTime<Tz> {
+                self.datetime.and_utc().with_timezone(tz)
+            }
+        }
+
+        impl<$($lt)?> TryFrom<&$input str> for ValidatedLogline<$($lt)?> {
+            type Error = &'static str;
+
+            fn try_from(line: &$input str) -> Result<Self, Self::Error> {
+                validate_line(line)?;
+                let mut iter = MemchrTabSplitter::new(line);
+
+                let date = parse_chrono_date(iter.field()?).map_err(|_e| "date invalid")?;
+                let raw_time = iter.field()?;
+                let time = parse_chrono_time(raw_time).map_err(|_e| "time invalid")?;
+                let datetime = NaiveDateTime::new(date, time);
+
+                let line = Self {
+                    date,
+                    time: raw_time.into(),
+                    datetime,
+                    x_edge_location: iter.field()?.into(),
+                    sc_bytes: iter
+                        .field()?
+                        .parse::<u64>()
+                        .map_err(|_e| "sc_bytes invalid")?,
+                    c_ip: iter.field()?.into(),
+                    cs_method: iter.field()?.into(),
+                    cs_host: iter.field()?.into(),
+                    cs_uri_stem: iter.field()?.into(),
+                    sc_status: iter
+                        .field()?
+                        .parse::<u16>()
+                        .map_err(|_e| "sc_status invalid")?,
+                    cs_referer: iter.field()?.$optional(),
+                    cs_user_agent: iter.field()?.into(),
+                    cs_uri_query: iter.field()?.$optional(),
+                    cs_cookie: iter.field()?.$optional(),
+                    x_edge_result_type: iter.field()?.into(),
+                    x_edge_request_id: iter.field()?.into(),
+                    x_host_header: iter.field()?.into(),
+                    cs_protocol: iter.field()?.into(),
+                    cs_bytes: iter
+                        .field()?
+                        .parse::<u64>()
+                        .map_err(|_e| "cs_bytes invalid")?,
+                    $time_taken: ($parse)(iter.field()?).map_err(|_e| "time_taken invalid")?,
+                    x_forwarded_for: iter.field()?.$optional(),
+                    ssl_protocol: iter.field()?.$optional(),
+                    ssl_cipher: iter.field()?.$optional(),
+                    x_edge_response_result_type: iter.field()?.into(),
+                    cs_protocol_version: iter.field()?.into(),
+                    fle_status: iter.field()?.$optional(),
+                    fle_encrypted_fields: parse_as_option(iter.field()?)
+                        .map_err(|_e| "fle_encrypted_fields invalid")?,
+                    c_port: iter
+                        .field()?
+                        .parse::<u16>()
+                        .map_err(|_e| "c_port invalid")?,
+                    $time_to_first_byte: ($parse)(iter.field()?).map_err(|_e| "time_to_first_byte invalid")?,
+                    x_edge_detailed_result_type: iter.field()?.into(),
+                    sc_content_type: iter.field()?.$optional(),
+                    sc_content_len: parse_as_option(iter.field()?)
+                        .map_err(|_e| "sc_content_len invalid")?,
+                    sc_range_start: parse_as_option(iter.field()?)
+                        .map_err(|_e| "sc_range_start invalid")?,
+                    sc_range_end: parse_as_option(iter.field()?).map_err(|_e| "sc_range_end invalid")?,
+                };
+                Ok(line)
+            }
+        }
+
+        impl<$($lt)?> TryFrom<ValidatedRaw<$input>> for ValidatedLogline<$($lt)?> {
+            type Error = &'static str;
+
+            fn try_from(raw: ValidatedRaw<$input>) -> Result<Self, Self::Error> {
+                let date = parse_chrono_date(raw.date).map_err(|_e| "date invalid")?;
+                let time = parse_chrono_time(raw.time).map_err(|_e| "time invalid")?;
+                let datetime = NaiveDateTime::new(date, time);
+
+                let line = Self {
+                    date,
+                    time: raw.time.into(),
+                    datetime,
+                    x_edge_location: raw.x_edge_location.into(),
+                    sc_bytes: raw
+                        .sc_bytes
+                        .parse::<u64>()
+                        .map_err(|_e| "sc_bytes invalid")?,
+                    c_ip: raw.c_ip.into(),
+                    cs_method: raw.cs_method.into(),
+                    cs_host: raw.cs_host.into(),
+                    cs_uri_stem: raw.cs_uri_stem.into(),
+                    sc_status: raw
+                        .sc_status
+                        .parse::<u16>()
+                        .map_err(|_e| "sc_status invalid")?,
+                    cs_referer: raw.cs_referer.$optional(),
+                    cs_user_agent: raw.cs_user_agent.into(),
+                    cs_uri_query: raw.cs_uri_query.$optional(),
+                    cs_cookie: raw.cs_cookie.$optional(),
+                    x_edge_result_type: raw.x_edge_result_type.into(),
+                    x_edge_request_id: raw.x_edge_request_id.into(),
+                    x_host_header: raw.x_host_header.into(),
+                    cs_protocol: raw.cs_protocol.into(),
+                    cs_bytes: raw
+                        .cs_bytes
+                        .parse::<u64>()
+                        .map_err(|_e| "cs_bytes invalid")?,
+                    $time_taken: ($parse)(raw.time_taken).map_err(|_e| "time_taken invalid")?,
+                    x_forwarded_for: raw.x_forwarded_for.$optional(),
+                    ssl_protocol: raw.ssl_protocol.$optional(),
+                    ssl_cipher: raw.ssl_cipher.$optional(),
+                    x_edge_response_result_type: raw.x_edge_response_result_type.into(),
+                    cs_protocol_version: raw.cs_protocol_version.into(),
+                    fle_status: raw.fle_status.$optional(),
+                    fle_encrypted_fields: parse_as_option(raw.fle_encrypted_fields)
+                        .map_err(|_e| "fle_encrypted_fields invalid")?,
+                    c_port: raw.c_port.parse::<u16>().map_err(|_e| "c_port invalid")?,
+                    $time_to_first_byte: ($parse)(raw.time_to_first_byte).map_err(|_e| "time_to_first_byte invalid")?,
+                    x_edge_detailed_result_type: raw.x_edge_detailed_result_type.into(),
+                    sc_content_type: raw.sc_content_type.$optional(),
+                    sc_content_len: parse_as_option(raw.sc_content_len)
+                        .map_err(|_e| "sc_content_len invalid")?,
+                    sc_range_start: parse_as_option(raw.sc_range_start)
+                        .map_err(|_e| "sc_range_start invalid")?,
+                    sc_range_end: parse_as_option(raw.sc_range_end).map_err(|_e| "sc_range_end invalid")?,
+                };
+                Ok(line)
+            }
+        }
+
+        #[doc = concat!(" The unvalidated log line for [`parquet`] usage", $title)]
+        $(#[doc = $doc])*
+        ///
+        /// Most fields are parsed into more meaningful types.
+        ///
+        /// Unlike [`ValidatedLogline`], this variant does not check if the line can be parsed.
+        /// Use this if you already did a check before creating this struct.
+        /// A common scenario is that you 1) trust the input data and 2) skipped the comment lines.
+        ///
+        /// Note: This is the only variant which can use the `From` trait instead of `TryFrom`,
+        /// because validation is skipped and the input data does not need to be parsed into other types.
+        ///
+        /// # Errors
+        ///
+        /// Construction never panics, an invalid log line results in an error instead.
+        ///
+        /// # Examples
+        ///
+        /// Use `.try_from()` or `.try_into()` to construct an instance, since action can fail.
+        ///
+        /// ```rust
+        #[doc = concat!(" use cloudfront_logs::{", stringify!($family), "::", stringify!($module), "::UnvalidatedLogline, types::*};")]
+        ///
+        /// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.100	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+        ///
+        /// let item = UnvalidatedLogline::try_from(line).unwrap();
+        /// // alternative:
+        #[doc = concat!(" let item: UnvalidatedLogline", $crate::shared::impl_parquet_loglines!(@elided $family), " = line.try_into().unwrap();")]
+        ///
+        /// assert_eq!(item.date, NaiveDate::from_ymd_opt(2019, 12, 4).unwrap());
+        /// assert_eq!(item.sc_bytes, 392u64);
+        /// assert_eq!(item.cs_protocol, "https");
+        $(#[doc = $example])*
+        /// ```
+        #[must_use]
+        #[derive(Debug, Clone, PartialEq, parquet_derive::ParquetRecordWriter)]
+        pub struct UnvalidatedLogline<$($lt)?> {
+            pub date: NaiveDate,
+            pub time: $($string)+, // not supported: NaiveTime
+            pub datetime: NaiveDateTime,
+            pub x_edge_location: $($string)+,
+            pub sc_bytes: u64,
+            pub c_ip: $($string)+,
+            pub cs_method: $($string)+,
+            pub cs_host: $($string)+,
+            pub cs_uri_stem: $($string)+,
+            pub sc_status: u16,
+            pub cs_referer: Option<$($string)+>,
+            pub cs_user_agent: $($string)+,
+            pub cs_uri_query: Option<$($string)+>,
+            pub cs_cookie: Option<$($string)+>,
+            pub x_edge_result_type: $($string)+,
+            pub x_edge_request_id: $($string)+,
+            pub x_host_header: $($string)+,
+            pub cs_protocol: $($string)+,
+            pub cs_bytes: u64,
+            pub $time_taken: $duration,
+            pub x_forwarded_for: Option<$($string)+>,
+            pub ssl_protocol: Option<$($string)+>,
+            pub ssl_cipher: Option<$($string)+>,
+            pub x_edge_response_result_type: $($string)+,
+            pub cs_protocol_version: $($string)+,
+            pub fle_status: Option<$($string)+>,
+            pub fle_encrypted_fields: Option<u64>,
+            pub c_port: u16,
+            pub $time_to_first_byte: $duration,
+            pub x_edge_detailed_result_type: $($string)+,
+            pub sc_content_type: Option<$($string)+>,
+            pub sc_content_len: Option<u64>,
+            pub sc_range_start: Option<i64>,
+            pub sc_range_end: Option<i64>,
+        }
+
+        impl<$($lt)?> UnvalidatedLogline<$($lt)?> {
+            pub fn schema() -> &'static str {
+                crate::consts::parquet_schemata::$schema
+            }
+
+            pub fn schema_as_type() -> parquet::schema::types::Type {
+                parquet::schema::parser::parse_message_type(crate::consts::parquet_schemata::$schema).unwrap()
+            }
+        }
+
+        impl<$($lt)?> UnvalidatedLogline<$($lt)?> {
+            /// The request time as UTC datetime
+            ///
+            /// CloudFront logs are always in UTC; the `datetime` field just does not carry the timezone.
+            pub fn datetime_utc(&self) -> DateTime<Utc> {
+                self.datetime.and_utc()
+            }
+
+            /// The request time as seconds since the Unix epoch
+            pub fn unix_timestamp(&self) -> i64 {
+                self.datetime.and_utc().timestamp()
+            }
+
+            /// The request time as milliseconds since the Unix epoch
+            pub fn unix_timestamp_millis(&self) -> i64 {
+                self.datetime.and_utc().timestamp_millis()
+            }
+
+            /// The request time in the given timezone, e.g. for reporting in local time
+            pub fn datetime_in<Tz: TimeZone>(&self, tz: &Tz) -> DateTime<Tz> {
+                self.datetime.and_utc().with_timezone(tz)
+            }
+        }
+
+        impl<$($lt)?> TryFrom<&$input str> for UnvalidatedLogline<$($lt)?> {
+            type Error = &'static str;
+
+            fn try_from(line: &$input str) -> Result<Self, Self::Error> {
+                let mut iter = MemchrTabSplitter::new(line);
+
+                let date =
+                    NaiveDate::parse_from_str(iter.field()?, "%Y-%m-%d").map_err(|_e| "date invalid")?;
+                let raw_time = iter.field()?;
+                let time = NaiveTime::parse_from_str(raw_time, "%H:%M:%S").map_err(|_e| "time invalid")?;
+                let datetime = NaiveDateTime::new(date, time);
+
+                let line = Self {
+                    date,
+                    time: raw_time.into(),
+                    datetime,
+                    x_edge_location: iter.field()?.into(),
+                    sc_bytes: iter
+                        .field()?
+                        .parse::<u64>()
+                        .map_err(|_e| "sc_bytes invalid")?,
+                    c_ip: iter.field()?.into(),
+                    cs_method: iter.field()?.into(),
+                    cs_host: iter.field()?.into(),
+                    cs_uri_stem: iter.field()?.into(),
+                    sc_status: iter
+                        .field()?
+                        .parse::<u16>()
+                        .map_err(|_e| "sc_status invalid")?,
+                    cs_referer: iter.field()?.$optional(),
+                    cs_user_agent: iter.field()?.into(),
+                    cs_uri_query: iter.field()?.$optional(),
+                    cs_cookie: iter.field()?.$optional(),
+                    x_edge_result_type: iter.field()?.into(),
+                    x_edge_request_id: iter.field()?.into(),
+                    x_host_header: iter.field()?.into(),
+                    cs_protocol: iter.field()?.into(),
+                    cs_bytes: iter
+                        .field()?
+                        .parse::<u64>()
+                        .map_err(|_e| "cs_bytes invalid")?,
+                    $time_taken: ($parse)(iter.field()?).map_err(|_e| "time_taken invalid")?,
+                    x_forwarded_for: iter.field()?.$optional(),
+                    ssl_protocol: iter.field()?.$optional(),
+                    ssl_cipher: iter.field()?.$optional(),
+                    x_edge_response_result_type: iter.field()?.into(),
+                    cs_protocol_version: iter.field()?.into(),
+                    fle_status: iter.field()?.$optional(),
+                    fle_encrypted_fields: parse_as_option(iter.field()?)
+                        .map_err(|_e| "fle_encrypted_fields invalid")?,
+                    c_port: iter
+                        .field()?
+                        .parse::<u16>()
+                        .map_err(|_e| "c_port invalid")?,
+                    $time_to_first_byte: ($parse)(iter.field()?).map_err(|_e| "time_to_first_byte invalid")?,
+                    x_edge_detailed_result_type: iter.field()?.into(),
+                    sc_content_type: iter.field()?.$optional(),
+                    sc_content_len: parse_as_option(iter.field()?)
+                        .map_err(|_e| "sc_content_len invalid")?,
+                    sc_range_start: parse_as_option(iter.field()?)
+                        .map_err(|_e| "sc_range_start invalid")?,
+                    sc_range_end: parse_as_option(iter.field()?).map_err(|_e| "sc_range_end invalid")?,
+                };
+                Ok(line)
+            }
+        }
+
+        impl<$($lt)?> TryFrom<UnvalidatedRaw<$input>> for UnvalidatedLogline<$($lt)?> {
+            type Error = &'static str;
+
+            fn try_from(raw: UnvalidatedRaw<$input>) -> Result<Self, Self::Error> {
+                let date = parse_chrono_date(raw.date).map_err(|_e| "date invalid")?;
+                let time = parse_chrono_time(raw.time).map_err(|_e| "time invalid")?;
+                let datetime = NaiveDateTime::new(date, time);
+
+                let line = Self {
+                    date,
+                    time: raw.time.into(),
+                    datetime,
+                    x_edge_location: raw.x_edge_location.into(),
+                    sc_bytes: raw
+                        .sc_bytes
+                        .parse::<u64>()
+                        .map_err(|_e| "sc_bytes invalid")?,
+                    c_ip: raw.c_ip.into(),
+                    cs_method: raw.cs_method.into(),
+                    cs_host: raw.cs_host.into(),
+                    cs_uri_stem: raw.cs_uri_stem.into(),
+                    sc_status: raw
+                        .sc_status
+                        .parse::<u16>()
+                        .map_err(|_e| "sc_status invalid")?,
+                    cs_referer: raw.cs_referer.$optional(),
+                    cs_user_agent: raw.cs_user_agent.into(),
+                    cs_uri_query: raw.cs_uri_query.$optional(),
+                    cs_cookie: raw.cs_cookie.$optional(),
+                    x_edge_result_type: raw.x_edge_result_type.into(),
+                    x_edge_request_id: raw.x_edge_request_id.into(),
+                    x_host_header: raw.x_host_header.into(),
+                    cs_protocol: raw.cs_protocol.into(),
+                    cs_bytes: raw
+                        .cs_bytes
+                        .parse::<u64>()
+                        .map_err(|_e| "cs_bytes invalid")?,
+                    $time_taken: ($parse)(raw.time_taken).map_err(|_e| "time_taken invalid")?,
+                    x_forwarded_for: raw.x_forwarded_for.$optional(),
+                    ssl_protocol: raw.ssl_protocol.$optional(),
+                    ssl_cipher: raw.ssl_cipher.$optional(),
+                    x_edge_response_result_type: raw.x_edge_response_result_type.into(),
+                    cs_protocol_version: raw.cs_protocol_version.into(),
+                    fle_status: raw.fle_status.$optional(),
+                    fle_encrypted_fields: parse_as_option(raw.fle_encrypted_fields)
+                        .map_err(|_e| "fle_encrypted_fields invalid")?,
+                    c_port: raw.c_port.parse::<u16>().map_err(|_e| "c_port invalid")?,
+                    $time_to_first_byte: ($parse)(raw.time_to_first_byte).map_err(|_e| "time_to_first_byte invalid")?,
+                    x_edge_detailed_result_type: raw.x_edge_detailed_result_type.into(),
+                    sc_content_type: raw.sc_content_type.$optional(),
+                    sc_content_len: parse_as_option(raw.sc_content_len)
+                        .map_err(|_e| "sc_content_len invalid")?,
+                    sc_range_start: parse_as_option(raw.sc_range_start)
+                        .map_err(|_e| "sc_range_start invalid")?,
+                    sc_range_end: parse_as_option(raw.sc_range_end).map_err(|_e| "sc_range_end invalid")?,
+                };
+                Ok(line)
+            }
+        }
+
+        impl<$($lt)?> TryFrom<ValidatedRaw<$input>> for UnvalidatedLogline<$($lt)?> {
+            type Error = &'static str;
+
+            fn try_from(raw: ValidatedRaw<$input>) -> Result<Self, Self::Error> {
+                let date = parse_chrono_date(raw.date).map_err(|_e| "date invalid")?;
+                let time = parse_chrono_time(raw.time).map_err(|_e| "time invalid")?;
+                let datetime = NaiveDateTime::new(date, time);
+
+                let line = Self {
+                    date,
+                    time: raw.time.into(),
+                    datetime,
+                    x_edge_location: raw.x_edge_location.into(),
+                    sc_bytes: raw
+                        .sc_bytes
+                        .parse::<u64>()
+                        .map_err(|_e| "sc_bytes invalid")?,
+                    c_ip: raw.c_ip.into(),
+                    cs_method: raw.cs_method.into(),
+                    cs_host: raw.cs_host.into(),
+                    cs_uri_stem: raw.cs_uri_stem.into(),
+                    sc_status: raw
+                        .sc_status
+                        .parse::<u16>()
+                        .map_err(|_e| "sc_status invalid")?,
+                    cs_referer: raw.cs_referer.$optional(),
+                    cs_user_agent: raw.cs_user_agent.into(),
+                    cs_uri_query: raw.cs_uri_query.$optional(),
+                    cs_cookie: raw.cs_cookie.$optional(),
+                    x_edge_result_type: raw.x_edge_result_type.into(),
+                    x_edge_request_id: raw.x_edge_request_id.into(),
+                    x_host_header: raw.x_host_header.into(),
+                    cs_protocol: raw.cs_protocol.into(),
+                    cs_bytes: raw
+                        .cs_bytes
+                        .parse::<u64>()
+                        .map_err(|_e| "cs_bytes invalid")?,
+                    $time_taken: ($parse)(raw.time_taken).map_err(|_e| "time_taken invalid")?,
+                    x_forwarded_for: raw.x_forwarded_for.$optional(),
+                    ssl_protocol: raw.ssl_protocol.$optional(),
+                    ssl_cipher: raw.ssl_cipher.$optional(),
+                    x_edge_response_result_type: raw.x_edge_response_result_type.into(),
+                    cs_protocol_version: raw.cs_protocol_version.into(),
+                    fle_status: raw.fle_status.$optional(),
+                    fle_encrypted_fields: parse_as_option(raw.fle_encrypted_fields)
+                        .map_err(|_e| "fle_encrypted_fields invalid")?,
+                    c_port: raw.c_port.parse::<u16>().map_err(|_e| "c_port invalid")?,
+                    $time_to_first_byte: ($parse)(raw.time_to_first_byte).map_err(|_e| "time_to_first_byte invalid")?,
+                    x_edge_detailed_result_type: raw.x_edge_detailed_result_type.into(),
+                    sc_content_type: raw.sc_content_type.$optional(),
+                    sc_content_len: parse_as_option(raw.sc_content_len)
+                        .map_err(|_e| "sc_content_len invalid")?,
+                    sc_range_start: parse_as_option(raw.sc_range_start)
+                        .map_err(|_e| "sc_range_start invalid")?,
+                    sc_range_end: parse_as_option(raw.sc_range_end).map_err(|_e| "sc_range_end invalid")?,
+                };
+                Ok(line)
+            }
+        }
+
+        impl<$($lt)?> From<ValidatedLogline<$($lt)?>> for UnvalidatedLogline<$($lt)?> {
+            fn from(validated: ValidatedLogline<$($lt)?>) -> Self {
+                UnvalidatedLogline {
+                    date: validated.date,
+                    time: validated.time,
+                    datetime: validated.datetime,
+                    x_edge_location: validated.x_edge_location,
+                    sc_bytes: validated.sc_bytes,
+                    c_ip: validated.c_ip,
+                    cs_method: validated.cs_method,
+                    cs_host: validated.cs_host,
+                    cs_uri_stem: validated.cs_uri_stem,
+                    sc_status: validated.sc_status,
+                    cs_referer: validated.cs_referer,
+                    cs_user_agent: validated.cs_user_agent,
+                    cs_uri_query: validated.cs_uri_query,
+                    cs_cookie: validated.cs_cookie,
+                    x_edge_result_type: validated.x_edge_result_type,
+                    x_edge_request_id: validated.x_edge_request_id,
+                    x_host_header: validated.x_host_header,
+                    cs_protocol: validated.cs_protocol,
+                    cs_bytes: validated.cs_bytes,
+                    $time_taken: validated.$time_taken,
+                    x_forwarded_for: validated.x_forwarded_for,
+                    ssl_protocol: validated.ssl_protocol,
+                    ssl_cipher: validated.ssl_cipher,
+                    x_edge_response_result_type: validated.x_edge_response_result_type,
+                    cs_protocol_version: validated.cs_protocol_version,
+                    fle_status: validated.fle_status,
+                    fle_encrypted_fields: validated.fle_encrypted_fields,
+                    c_port: validated.c_port,
+                    $time_to_first_byte: validated.$time_to_first_byte,
+                    x_edge_detailed_result_type: validated.x_edge_detailed_result_type,
+                    sc_content_type: validated.sc_content_type,
+                    sc_content_len: validated.sc_content_len,
+                    sc_range_start: validated.sc_range_start,
+                    sc_range_end: validated.sc_range_end,
+                }
+            }
+        }
+
+        impl<$($lt)?> From<UnvalidatedLogline<$($lt)?>> for ValidatedLogline<$($lt)?> {
+            fn from(unvalidated: UnvalidatedLogline<$($lt)?>) -> Self {
+                ValidatedLogline {
+                    date: unvalidated.date,
+                    time: unvalidated.time,
+                    datetime: unvalidated.datetime,
+                    x_edge_location: unvalidated.x_edge_location,
+                    sc_bytes: unvalidated.sc_bytes,
+                    c_ip: unvalidated.c_ip,
+                    cs_method: unvalidated.cs_method,
+                    cs_host: unvalidated.cs_host,
+                    cs_uri_stem: unvalidated.cs_uri_stem,
+                    sc_status: unvalidated.sc_status,
+                    cs_referer: unvalidated.cs_referer,
+                    cs_user_agent: unvalidated.cs_user_agent,
+                    cs_uri_query: unvalidated.cs_uri_query,
+                    cs_cookie: unvalidated.cs_cookie,
+                    x_edge_result_type: unvalidated.x_edge_result_type,
+                    x_edge_request_id: unvalidated.x_edge_request_id,
+                    x_host_header: unvalidated.x_host_header,
+                    cs_protocol: unvalidated.cs_protocol,
+                    cs_bytes: unvalidated.cs_bytes,
+                    $time_taken: unvalidated.$time_taken,
+                    x_forwarded_for: unvalidated.x_forwarded_for,
+                    ssl_protocol: unvalidated.ssl_protocol,
+                    ssl_cipher: unvalidated.ssl_cipher,
+                    x_edge_response_result_type: unvalidated.x_edge_response_result_type,
+                    cs_protocol_version: unvalidated.cs_protocol_version,
+                    fle_status: unvalidated.fle_status,
+                    fle_encrypted_fields: unvalidated.fle_encrypted_fields,
+                    c_port: unvalidated.c_port,
+                    $time_to_first_byte: unvalidated.$time_to_first_byte,
+                    x_edge_detailed_result_type: unvalidated.x_edge_detailed_result_type,
+                    sc_content_type: unvalidated.sc_content_type,
+                    sc_content_len: unvalidated.sc_content_len,
+                    sc_range_start: unvalidated.sc_range_start,
+                    sc_range_end: unvalidated.sc_range_end,
+                }
+            }
+        }
+
+        $crate::shared::impl_parquet_loglines!(@conversions $family, $module, $time_taken, $time_to_first_byte);
+    };
+
+    (@elided borrowed) => { "<'_>" };
+    (@elided owned) => { "" };
+
+    (@conversions borrowed, $module:ident, $time_taken:ident, $time_to_first_byte:ident) => {
+        impl ValidatedLogline<'_> {
+            #[doc = concat!(" Converts the line into the [owned](crate::owned::", stringify!($module), ") variant")]
+            pub fn into_owned(self) -> crate::owned::$module::ValidatedLogline {
+                self.into()
+            }
+
+            #[doc = concat!(" Copies the line into the [owned](crate::owned::", stringify!($module), ") variant")]
+            pub fn to_owned_logline(&self) -> crate::owned::$module::ValidatedLogline {
+                self.clone().into()
+            }
+        }
+
+        impl<'a> From<&'a crate::owned::$module::ValidatedLogline> for ValidatedLogline<'a> {
+            fn from(owned: &'a crate::owned::$module::ValidatedLogline) -> Self {
+                Self {
+                    date: owned.date,
+                    time: owned.time.as_str(),
+                    datetime: owned.datetime,
+                    x_edge_location: owned.x_edge_location.as_str(),
+                    sc_bytes: owned.sc_bytes,
+                    c_ip: owned.c_ip.as_str(),
+                    cs_method: owned.cs_method.as_str(),
+                    cs_host: owned.cs_host.as_str(),
+                    cs_uri_stem: owned.cs_uri_stem.as_str(),
+                    sc_status: owned.sc_status,
+                    cs_referer: owned.cs_referer.as_deref(),
+                    cs_user_agent: owned.cs_user_agent.as_str(),
+                    cs_uri_query: owned.cs_uri_query.as_deref(),
+                    cs_cookie: owned.cs_cookie.as_deref(),
+                    x_edge_result_type: owned.x_edge_result_type.as_str(),
+                    x_edge_request_id: owned.x_edge_request_id.as_str(),
+                    x_host_header: owned.x_host_header.as_str(),
+                    cs_protocol: owned.cs_protocol.as_str(),
+                    cs_bytes: owned.cs_bytes,
+                    $time_taken: owned.$time_taken,
+                    x_forwarded_for: owned.x_forwarded_for.as_deref(),
+                    ssl_protocol: owned.ssl_protocol.as_deref(),
+                    ssl_cipher: owned.ssl_cipher.as_deref(),
+                    x_edge_response_result_type: owned.x_edge_response_result_type.as_str(),
+                    cs_protocol_version: owned.cs_protocol_version.as_str(),
+                    fle_status: owned.fle_status.as_deref(),
+                    fle_encrypted_fields: owned.fle_encrypted_fields,
+                    c_port: owned.c_port,
+                    $time_to_first_byte: owned.$time_to_first_byte,
+                    x_edge_detailed_result_type: owned.x_edge_detailed_result_type.as_str(),
+                    sc_content_type: owned.sc_content_type.as_deref(),
+                    sc_content_len: owned.sc_content_len,
+                    sc_range_start: owned.sc_range_start,
+                    sc_range_end: owned.sc_range_end,
+                }
+            }
+        }
+
+        impl UnvalidatedLogline<'_> {
+            #[doc = concat!(" Converts the line into the [owned](crate::owned::", stringify!($module), ") variant")]
+            pub fn into_owned(self) -> crate::owned::$module::UnvalidatedLogline {
+                self.into()
+            }
+
+            #[doc = concat!(" Copies the line into the [owned](crate::owned::", stringify!($module), ") variant")]
+            pub fn to_owned_logline(&self) -> crate::owned::$module::UnvalidatedLogline {
+                self.clone().into()
+            }
+        }
+
+        impl<'a> From<&'a crate::owned::$module::UnvalidatedLogline> for UnvalidatedLogline<'a> {
+            fn from(owned: &'a crate::owned::$module::UnvalidatedLogline) -> Self {
+                Self {
+                    date: owned.date,
+                    time: owned.time.as_str(),
+                    datetime: owned.datetime,
+                    x_edge_location: owned.x_edge_location.as_str(),
+                    sc_bytes: owned.sc_bytes,
+                    c_ip: owned.c_ip.as_str(),
+                    cs_method: owned.cs_method.as_str(),
+                    cs_host: owned.cs_host.as_str(),
+                    cs_uri_stem: owned.cs_uri_stem.as_str(),
+                    sc_status: owned.sc_status,
+                    cs_referer: owned.cs_referer.as_deref(),
+                    cs_user_agent: owned.cs_user_agent.as_str(),
+                    cs_uri_query: owned.cs_uri_query.as_deref(),
+                    cs_cookie: owned.cs_cookie.as_deref(),
+                    x_edge_result_type: owned.x_edge_result_type.as_str(),
+                    x_edge_request_id: owned.x_edge_request_id.as_str(),
+                    x_host_header: owned.x_host_header.as_str(),
+                    cs_protocol: owned.cs_protocol.as_str(),
+                    cs_bytes: owned.cs_bytes,
+                    $time_taken: owned.$time_taken,
+                    x_forwarded_for: owned.x_forwarded_for.as_deref(),
+                    ssl_protocol: owned.ssl_protocol.as_deref(),
+                    ssl_cipher: owned.ssl_cipher.as_deref(),
+                    x_edge_response_result_type: owned.x_edge_response_result_type.as_str(),
+                    cs_protocol_version: owned.cs_protocol_version.as_str(),
+                    fle_status: owned.fle_status.as_deref(),
+                    fle_encrypted_fields: owned.fle_encrypted_fields,
+                    c_port: owned.c_port,
+                    $time_to_first_byte: owned.$time_to_first_byte,
+                    x_edge_detailed_result_type: owned.x_edge_detailed_result_type.as_str(),
+                    sc_content_type: owned.sc_content_type.as_deref(),
+                    sc_content_len: owned.sc_content_len,
+                    sc_range_start: owned.sc_range_start,
+                    sc_range_end: owned.sc_range_end,
+                }
+            }
+        }
+    };
+
+    (@conversions owned, $module:ident, $time_taken:ident, $time_to_first_byte:ident) => {
+        impl ValidatedLogline {
+            /// Returns a borrowed view of the line
+            pub fn as_borrowed(&self) -> crate::borrowed::$module::ValidatedLogline<'_> {
+                self.into()
+            }
+        }
+
+        impl From<crate::borrowed::$module::ValidatedLogline<'_>> for ValidatedLogline {
+            fn from(borrowed: crate::borrowed::$module::ValidatedLogline<'_>) -> Self {
+                Self {
+                    date: borrowed.date,
+                    time: borrowed.time.to_string(),
+                    datetime: borrowed.datetime,
+                    x_edge_location: borrowed.x_edge_location.to_string(),
+                    sc_bytes: borrowed.sc_bytes,
+                    c_ip: borrowed.c_ip.to_string(),
+                    cs_method: borrowed.cs_method.to_string(),
+                    cs_host: borrowed.cs_host.to_string(),
+                    cs_uri_stem: borrowed.cs_uri_stem.to_string(),
+                    sc_status: borrowed.sc_status,
+                    cs_referer: borrowed.cs_referer.map(ToString::to_string),
+                    cs_user_agent: borrowed.cs_user_agent.to_string(),
+                    cs_uri_query: borrowed.cs_uri_query.map(ToString::to_string),
+                    cs_cookie: borrowed.cs_cookie.map(ToString::to_string),
+                    x_edge_result_type: borrowed.x_edge_result_type.to_string(),
+                    x_edge_request_id: borrowed.x_edge_request_id.to_string(),
+                    x_host_header: borrowed.x_host_header.to_string(),
+                    cs_protocol: borrowed.cs_protocol.to_string(),
+                    cs_bytes: borrowed.cs_bytes,
+                    $time_taken: borrowed.$time_taken,
+                    x_forwarded_for: borrowed.x_forwarded_for.map(ToString::to_string),
+                    ssl_protocol: borrowed.ssl_protocol.map(ToString::to_string),
+                    ssl_cipher: borrowed.ssl_cipher.map(ToString::to_string),
+                    x_edge_response_result_type: borrowed.x_edge_response_result_type.to_string(),
+                    cs_protocol_version: borrowed.cs_protocol_version.to_string(),
+                    fle_status: borrowed.fle_status.map(ToString::to_string),
+                    fle_encrypted_fields: borrowed.fle_encrypted_fields,
+                    c_port: borrowed.c_port,
+                    $time_to_first_byte: borrowed.$time_to_first_byte,
+                    x_edge_detailed_result_type: borrowed.x_edge_detailed_result_type.to_string(),
+                    sc_content_type: borrowed.sc_content_type.map(ToString::to_string),
+                    sc_content_len: borrowed.sc_content_len,
+                    sc_range_start: borrowed.sc_range_start,
+                    sc_range_end: borrowed.sc_range_end,
+                }
+            }
+        }
+
+        impl UnvalidatedLogline {
+            /// Returns a borrowed view of the line
+            pub fn as_borrowed(&self) -> crate::borrowed::$module::UnvalidatedLogline<'_> {
+                self.into()
+            }
+        }
+
+        impl From<crate::borrowed::$module::UnvalidatedLogline<'_>> for UnvalidatedLogline {
+            fn from(borrowed: crate::borrowed::$module::UnvalidatedLogline<'_>) -> Self {
+                Self {
+                    date: borrowed.date,
+                    time: borrowed.time.to_string(),
+                    datetime: borrowed.datetime,
+                    x_edge_location: borrowed.x_edge_location.to_string(),
+                    sc_bytes: borrowed.sc_bytes,
+                    c_ip: borrowed.c_ip.to_string(),
+                    cs_method: borrowed.cs_method.to_string(),
+                    cs_host: borrowed.cs_host.to_string(),
+                    cs_uri_stem: borrowed.cs_uri_stem.to_string(),
+                    sc_status: borrowed.sc_status,
+                    cs_referer: borrowed.cs_referer.map(ToString::to_string),
+                    cs_user_agent: borrowed.cs_user_agent.to_string(),
+                    cs_uri_query: borrowed.cs_uri_query.map(ToString::to_string),
+                    cs_cookie: borrowed.cs_cookie.map(ToString::to_string),
+                    x_edge_result_type: borrowed.x_edge_result_type.to_string(),
+                    x_edge_request_id: borrowed.x_edge_request_id.to_string(),
+                    x_host_header: borrowed.x_host_header.to_string(),
+                    cs_protocol: borrowed.cs_protocol.to_string(),
+                    cs_bytes: borrowed.cs_bytes,
+                    $time_taken: borrowed.$time_taken,
+                    x_forwarded_for: borrowed.x_forwarded_for.map(ToString::to_string),
+                    ssl_protocol: borrowed.ssl_protocol.map(ToString::to_string),
+                    ssl_cipher: borrowed.ssl_cipher.map(ToString::to_string),
+                    x_edge_response_result_type: borrowed.x_edge_response_result_type.to_string(),
+                    cs_protocol_version: borrowed.cs_protocol_version.to_string(),
+                    fle_status: borrowed.fle_status.map(ToString::to_string),
+                    fle_encrypted_fields: borrowed.fle_encrypted_fields,
+                    c_port: borrowed.c_port,
+                    $time_to_first_byte: borrowed.$time_to_first_byte,
+                    x_edge_detailed_result_type: borrowed.x_edge_detailed_result_type.to_string(),
+                    sc_content_type: borrowed.sc_content_type.map(ToString::to_string),
+                    sc_content_len: borrowed.sc_content_len,
+                    sc_range_start: borrowed.sc_range_start,
+                    sc_range_end: borrowed.sc_range_end,
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "parquet")]
+pub(crate) use impl_parquet_loglines;
@@ -1,6 +1,10 @@
 use crate::*;
 use ::parquet::{record::RecordWriter, schema::parser::parse_message_type};
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::{
+    borrow::Cow,
+    net::{Ipv4Addr, Ipv6Addr},
+    sync::Arc,
+};
 
 const AWS_DOCS_EXAMPLE: &str = r#"#Version: 1.0
 #Fields: date time x-edge-location sc-bytes c-ip cs-method cs(Host) cs-uri-stem sc-status cs(Referer) cs(User-Agent) cs-uri-query cs(Cookie) x-edge-result-type x-edge-request-id x-host-header cs-protocol cs-bytes time-taken x-forwarded-for ssl-protocol ssl-cipher x-edge-response-result-type cs-protocol-version fle-status fle-encrypted-fields c-port time-to-first-byte x-edge-detailed-result-type sc-content-type sc-content-len sc-range-start sc-range-end
@@ -88,6 +92,263 @@ fn owned_types() {
         item.x_forwarded_for,
         Some(String::from("1.2.3.4, 5.6.7.8, 9.10.11.12"))
     );
+
+    // a missing content type and length are `None`, like in the other parsed variants
+    let missing = SINGLE_LOG_LINE.replacen("\ttext/html\t78\t", "\t-\t-\t", 1);
+    let item = owned::ValidatedSimpleLogline::try_from(missing.as_str()).unwrap();
+    assert_eq!(item.sc_content_type, None);
+    assert_eq!(item.sc_content_len, None);
+    assert_eq!(item.as_borrowed().sc_content_len, None);
+}
+
+#[test]
+fn owned_typed_conversions() {
+    for line in AWS_DOCS_EXAMPLE.lines().skip(2) {
+        let parsed = OwnedValidatedChronoLogline::try_from(line).unwrap();
+        let borrowed = ValidatedChronoLogline::try_from(line).unwrap();
+        let simple = owned::ValidatedSimpleLogline::try_from(line).unwrap();
+
+        assert_eq!(OwnedValidatedChronoLogline::from(borrowed), parsed);
+        assert_eq!(
+            OwnedValidatedChronoLogline::try_from(simple.clone()),
+            Ok(parsed)
+        );
+
+        let parsed = OwnedValidatedTimeLogline::try_from(line).unwrap();
+        let borrowed = ValidatedTimeLogline::try_from(line).unwrap();
+
+        assert_eq!(OwnedValidatedTimeLogline::from(borrowed), parsed);
+        assert_eq!(OwnedValidatedTimeLogline::try_from(simple), Ok(parsed));
+    }
+
+    let mut simple = owned::ValidatedSimpleLogline::try_from(SINGLE_LOG_LINE).unwrap();
+    simple.date = String::from("2019-13-04");
+    assert_eq!(
+        OwnedValidatedChronoLogline::try_from(simple),
+        Err("date invalid")
+    );
+}
+
+#[test]
+fn parse_into_matches_try_from() {
+    let lines = AWS_DOCS_EXAMPLE.lines().skip(2).collect::<Vec<_>>();
+
+    let mut simple = owned::ValidatedSimpleLogline::try_from(SINGLE_LOG_LINE).unwrap();
+    let mut chrono = OwnedValidatedChronoLogline::try_from(SINGLE_LOG_LINE).unwrap();
+    let mut time = OwnedUnvalidatedTimeLogline::try_from(SINGLE_LOG_LINE).unwrap();
+    let mut raw = owned::ValidatedRawLogline::try_from(SINGLE_LOG_LINE).unwrap();
+    // forwards and backwards, so that the optional fields switch between Some and None
+    for line in lines.iter().chain(lines.iter().rev()) {
+        simple.parse_into(line).unwrap();
+        assert_eq!(
+            simple,
+            owned::ValidatedSimpleLogline::try_from(*line).unwrap()
+        );
+        chrono.parse_into(line).unwrap();
+        assert_eq!(
+            chrono,
+            OwnedValidatedChronoLogline::try_from(*line).unwrap()
+        );
+        time.parse_into(line).unwrap();
+        assert_eq!(time, OwnedUnvalidatedTimeLogline::try_from(*line).unwrap());
+        raw.parse_into(line).unwrap();
+        assert_eq!(raw, owned::ValidatedRawLogline::try_from(*line).unwrap());
+    }
+
+    assert_eq!(
+        simple.parse_into("#Version: 1.0"),
+        Err("Invalid log line (comment)")
+    );
+    assert_eq!(raw.parse_into(""), Err("Invalid log line (empty)"));
+}
+
+#[test]
+fn parse_into_reuses_buffers() {
+    let mut line = owned::ValidatedSimpleLogline::try_from(SINGLE_LOG_LINE).unwrap();
+    let user_agent = line.cs_user_agent.as_ptr();
+    let addresses = line.x_forwarded_for.as_ref().unwrap().0.as_ptr();
+
+    let shorter = SINGLE_LOG_LINE
+        .replace(
+            "Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)",
+            "curl/7.55.1",
+        )
+        .replace("1.2.3.4, 5.6.7.8,\x209.10.11.12", "1.2.3.4");
+    line.parse_into(&shorter).unwrap();
+    assert!(line.cs_user_agent.starts_with("curl/7.55.1"));
+    assert_eq!(line.cs_user_agent.as_ptr(), user_agent);
+    let forwarded = line.x_forwarded_for.as_ref().unwrap();
+    assert_eq!(forwarded.0.len(), 1);
+    assert_eq!(forwarded.0.as_ptr(), addresses);
+
+    // value -> `-` -> value: the optional fields follow the lines, the others keep their buffers
+    let missing = shorter
+        .replace("\tECDHE-RSA-AES128-GCM-SHA256\t", "\t-\t")
+        .replace("\t1.2.3.4\t", "\t-\t");
+    line.parse_into(&missing).unwrap();
+    assert_eq!(line.ssl_cipher, None);
+    assert_eq!(line.x_forwarded_for, None);
+    line.parse_into(SINGLE_LOG_LINE).unwrap();
+    assert_eq!(
+        line,
+        owned::ValidatedSimpleLogline::try_from(SINGLE_LOG_LINE).unwrap()
+    );
+    assert_eq!(line.cs_user_agent.as_ptr(), user_agent);
+
+    let mut result_type = DetailedEdgeResultType::from("SomethingNew").into_owned();
+    let buffer = result_type.as_str().as_ptr();
+    result_type.parse_into("SomethingOld");
+    assert_eq!(result_type.as_str(), "SomethingOld");
+    assert_eq!(result_type.as_str().as_ptr(), buffer);
+    result_type.parse_into("Hit");
+    assert_eq!(result_type, DetailedEdgeResultType::Hit);
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn jiff_loglines() {
+    for line in AWS_DOCS_EXAMPLE.lines().skip(2) {
+        let parsed = ValidatedJiffLogline::try_from(line).unwrap();
+        let raw = ValidatedRawLogline::try_from(line).unwrap();
+        let owning = OwningValidatedJiffLogline::try_from(line).unwrap();
+
+        assert_eq!(ValidatedJiffLogline::try_from(raw), Ok(parsed.clone()));
+        assert_eq!(owning.view(), &UnvalidatedJiffLogline::from(parsed.clone()));
+        assert_eq!(
+            parsed.datetime.as_second(),
+            ValidatedChronoLogline::try_from(line)
+                .unwrap()
+                .datetime
+                .and_utc()
+                .timestamp()
+        );
+    }
+
+    let item = UnvalidatedJiffLogline::try_from(SINGLE_LOG_LINE).unwrap();
+    assert_eq!(item.date, jiff::civil::date(2019, 12, 4));
+    assert_eq!(item.time, jiff::civil::time(21, 2, 31, 0));
+    assert_eq!(item.datetime.as_second(), 1_575_493_351);
+
+    let broken = SINGLE_LOG_LINE.replacen("21:02:31", "21:02", 1);
+    assert_eq!(
+        ValidatedJiffLogline::try_from(broken.as_str()),
+        Err("time invalid")
+    );
+
+    let item = ValidatedJiffLogline::try_from(SINGLE_LOG_LINE).unwrap();
+    assert_eq!(item.unix_timestamp(), 1_575_493_351);
+    assert_eq!(item.unix_timestamp_millis(), 1_575_493_351_000);
+    let cet = jiff::tz::TimeZone::fixed(jiff::tz::offset(1));
+    assert_eq!(
+        item.datetime_in(cet).datetime(),
+        jiff::civil::date(2019, 12, 4).at(22, 2, 31, 0)
+    );
+}
+
+#[test]
+fn utc_aware_datetimes() {
+    let line = SINGLE_LOG_LINE;
+    let secs = 1_575_493_351;
+    let chrono_line = ValidatedChronoLogline::try_from(line).unwrap();
+    let time_line = ValidatedTimeLogline::try_from(line).unwrap();
+    let parquet_line = OwnedValidatedParquetLogline::try_from(line).unwrap();
+
+    assert_eq!(
+        chrono_line.datetime_utc(),
+        Utc.timestamp_opt(secs, 0).unwrap()
+    );
+    assert_eq!(
+        time_line.datetime_utc(),
+        UtcDateTime::from_unix_timestamp(secs).unwrap()
+    );
+    assert_eq!(parquet_line.datetime_utc(), chrono_line.datetime_utc());
+
+    for (unix, millis) in [
+        (
+            chrono_line.unix_timestamp(),
+            chrono_line.unix_timestamp_millis(),
+        ),
+        (
+            time_line.unix_timestamp(),
+            time_line.unix_timestamp_millis(),
+        ),
+        (
+            parquet_line.unix_timestamp(),
+            parquet_line.unix_timestamp_millis(),
+        ),
+    ] {
+        assert_eq!(unix, secs);
+        assert_eq!(millis, secs * 1_000);
+    }
+
+    let cet = chrono::FixedOffset::east_opt(3_600).unwrap();
+    assert_eq!(
+        chrono_line.datetime_in(&cet).to_rfc3339(),
+        "2019-12-04T22:02:31+01:00"
+    );
+
+    let cet = UtcOffset::from_hms(1, 0, 0).unwrap();
+    let local = time_line.datetime_in(cet).unwrap();
+    assert_eq!((local.hour(), local.offset()), (22, cet));
+}
+
+#[test]
+fn conversions_between_families_keep_the_marker() {
+    let line = SINGLE_LOG_LINE;
+
+    let raw = ValidatedRawLogline::try_from(line).unwrap();
+    let owned: owned::ValidatedRawLogline = raw.into_owned();
+    assert_eq!(owned.as_borrowed(), raw);
+    assert_eq!(
+        OwningValidatedRawLogline::try_from(line)
+            .unwrap()
+            .into_owned(),
+        owned
+    );
+
+    let simple = UnvalidatedSimpleLogline::try_from(line).unwrap();
+    let owned: owned::UnvalidatedSimpleLogline = simple.to_owned_logline();
+    assert_eq!(owned.as_borrowed(), simple);
+    assert_eq!(
+        owned,
+        owned::UnvalidatedSimpleLogline::try_from(line).unwrap()
+    );
+    assert_eq!(
+        OwningUnvalidatedSimpleLogline::try_from(line)
+            .unwrap()
+            .into_owned(),
+        owned
+    );
+
+    let typed = ValidatedChronoLogline::try_from(line).unwrap();
+    let owned: OwnedValidatedChronoLogline = typed.to_owned_logline();
+    assert_eq!(owned.as_borrowed(), typed);
+    assert_eq!(
+        OwningValidatedChronoLogline::try_from(line)
+            .unwrap()
+            .to_owned_logline(),
+        owned
+    );
+
+    let typed = UnvalidatedTimeLogline::try_from(line).unwrap();
+    let owned: OwnedUnvalidatedTimeLogline = typed.clone().into_owned();
+    assert_eq!(owned.as_borrowed(), typed);
+    assert_eq!(
+        OwningUnvalidatedTimeLogline::try_from(line)
+            .unwrap()
+            .into_owned(),
+        owned
+    );
+
+    let parquet = ValidatedParquetLogline::try_from(line).unwrap();
+    let owned: OwnedValidatedParquetLogline = parquet.to_owned_logline();
+    assert_eq!(owned.as_borrowed(), parquet);
+    assert_eq!(
+        OwningValidatedParquetLogline::try_from(line)
+            .unwrap()
+            .into_owned(),
+        owned
+    );
 }
 
 #[test]
@@ -125,7 +386,49 @@ fn result_type_from_str_checks() {
     let input = "Unknown";
     assert_eq!(
         EdgeResultType::from(input),
-        EdgeResultType::Other(String::from(input))
+        EdgeResultType::Other(Cow::Borrowed(input))
+    );
+    assert_eq!(
+        input.parse::<EdgeResultType<'static>>(),
+        Ok(EdgeResultType::Other(Cow::Owned(String::from(input))))
+    );
+}
+
+#[test]
+fn result_types_are_parsed_without_allocation() {
+    for known in [
+        DetailedEdgeResultType::Hit,
+        DetailedEdgeResultType::LimitExceeded,
+        DetailedEdgeResultType::AbortedOrigin,
+        DetailedEdgeResultType::OriginShieldHit,
+        DetailedEdgeResultType::ClientCommError,
+        DetailedEdgeResultType::OriginCommError,
+        DetailedEdgeResultType::OriginReadError,
+        DetailedEdgeResultType::OriginContentRangeLengthError,
+    ] {
+        assert_eq!(DetailedEdgeResultType::from(known.as_str()), known);
+    }
+    // same length as a known type, but still unknown
+    assert_eq!(
+        DetailedEdgeResultType::from("OriginSlowError"),
+        DetailedEdgeResultType::Other(Cow::Borrowed("OriginSlowError"))
+    );
+
+    let line = SINGLE_LOG_LINE.replace("\tHit\ttext/html", "\tOriginTimeout\ttext/html");
+    let item = ValidatedChronoLogline::try_from(line.as_str()).unwrap();
+    assert!(matches!(
+        item.x_edge_detailed_result_type,
+        DetailedEdgeResultType::Other(Cow::Borrowed("OriginTimeout"))
+    ));
+
+    let owned = item.into_owned();
+    assert!(matches!(
+        owned.x_edge_detailed_result_type,
+        DetailedEdgeResultType::Other(Cow::Owned(ref other)) if other == "OriginTimeout"
+    ));
+    assert_eq!(
+        owned.as_borrowed().x_edge_detailed_result_type,
+        DetailedEdgeResultType::Other(Cow::Borrowed("OriginTimeout"))
     );
 }
 
@@ -134,7 +437,7 @@ fn result_type_to_str_checks() {
     let input = EdgeResultType::Hit;
     assert_eq!(input.to_string(), "Hit");
 
-    let input = EdgeResultType::Other(String::from("Unknown"));
+    let input = EdgeResultType::Other("Unknown".into());
     assert_eq!(input.to_string(), "Unknown");
 }
 
@@ -238,3 +541,1328 @@ fn x_forwarded_for_addrs_checks() {
         ))))
     );
 }
+
+#[test]
+fn logline_view_field_access() {
+    let view = LoglineView::try_from(SINGLE_LOG_LINE).unwrap();
+    let raw = ValidatedRawLogline::try_from(SINGLE_LOG_LINE).unwrap();
+
+    // out of order access must not affect the result
+    assert_eq!(view.sc_range_end(), raw.sc_range_end);
+    assert_eq!(view.date(), raw.date);
+    assert_eq!(view.x_forwarded_for(), raw.x_forwarded_for);
+    assert_eq!(view.cs_user_agent(), raw.cs_user_agent);
+    assert_eq!(view.get(Field::CIp), raw.c_ip);
+    assert_eq!(view.get_by_index(Field::ScContentLen.index()), Some("78"));
+    assert_eq!(view.get_by_index(33), None);
+
+    assert_eq!(view.sc_bytes_u64(), Ok(392));
+    assert_eq!(view.c_port_u16(), Ok(11040));
+    assert_eq!(view.time_taken_duration(), Ok(Duration::from_millis(1)));
+    assert_eq!(view.sc_range_start_i64(), Ok(None));
+    assert_eq!(
+        view.datetime(),
+        Ok(NaiveDate::from_ymd_opt(2019, 12, 4)
+            .unwrap()
+            .and_hms_opt(21, 2, 31)
+            .unwrap())
+    );
+}
+
+#[test]
+fn logline_view_validation() {
+    assert_eq!(LoglineView::try_from(""), Err("Invalid log line (empty)"));
+    assert_eq!(
+        LoglineView::try_from("#Version: 1.0"),
+        Err("Invalid log line (comment)")
+    );
+    assert_eq!(
+        LoglineView::try_from("2019-12-04\t21:02:31"),
+        Err("Invalid log line (field count)")
+    );
+    let too_many = format!("{SINGLE_LOG_LINE}\t-");
+    assert_eq!(
+        LoglineView::try_from(too_many.as_str()),
+        Err("Invalid log line (field count)")
+    );
+}
+
+#[test]
+fn field_names_match_fields_header() {
+    let header_names = FIELDS_COMMENT
+        .strip_prefix("#Fields: ")
+        .unwrap()
+        .split(' ')
+        .collect::<Vec<_>>();
+    assert_eq!(header_names.len(), Field::ALL.len());
+
+    for (index, (field, header_name)) in Field::ALL.into_iter().zip(header_names).enumerate() {
+        assert_eq!(field.index(), index);
+        assert_eq!(field.header_name(), header_name);
+        assert_eq!(field.to_string(), header_name);
+        assert_eq!(header_name.parse::<Field>(), Ok(field));
+        assert_eq!(field.name().parse::<Field>(), Ok(field));
+        assert_eq!(Field::from_name(field.name()), Some(field));
+        assert_eq!(Field::try_from(index), Ok(field));
+    }
+    assert!("cs-user-agent".parse::<Field>().is_err());
+    assert_eq!(Field::from_index(33), None);
+}
+
+#[test]
+fn raw_logline_generic_field_access() {
+    let item = ValidatedRawLogline::try_from(SINGLE_LOG_LINE).unwrap();
+    let owning = OwningValidatedRawLogline::try_from(SINGLE_LOG_LINE).unwrap();
+    let view = LoglineView::try_from(SINGLE_LOG_LINE).unwrap();
+
+    for field in Field::ALL {
+        assert_eq!(item.get(field), view.get(field));
+        assert_eq!(owning.get(field), view.get(field));
+    }
+    assert_eq!(item.get(Field::CsUserAgent), item.cs_user_agent);
+}
+
+#[test]
+fn projected_logline_parses_selected_fields_only() {
+    let mask = FieldMask::from([Field::Date, Field::Time, Field::CsHost, Field::ScStatus])
+        | Field::ScBytes;
+    let item = ValidatedProjectedLogline::parse(SINGLE_LOG_LINE, mask).unwrap();
+    let typed = ValidatedChronoLogline::try_from(SINGLE_LOG_LINE).unwrap();
+
+    assert_eq!(item.mask(), mask);
+    assert_eq!(item.datetime, Some(typed.datetime));
+    assert_eq!(item.cs_host, Some(typed.cs_host));
+    assert_eq!(item.sc_status, Some(typed.sc_status));
+    assert_eq!(item.sc_bytes, Some(typed.sc_bytes));
+    assert_eq!(item.c_ip, None);
+    assert_eq!(item.x_forwarded_for, None);
+
+    let all = UnvalidatedProjectedLogline::parse(SINGLE_LOG_LINE, FieldMask::ALL).unwrap();
+    assert_eq!(all.x_forwarded_for, typed.x_forwarded_for);
+    assert_eq!(all.sc_content_len, typed.sc_content_len);
+    assert_eq!(
+        all.x_edge_detailed_result_type,
+        Some(typed.x_edge_detailed_result_type)
+    );
+
+    // broken fields are only detected if selected
+    let broken = SINGLE_LOG_LINE.replace("192.0.2.100", "not-an-ip");
+    assert!(ValidatedProjectedLogline::parse(&broken, mask).is_ok());
+    assert_eq!(
+        ValidatedProjectedLogline::parse(&broken, mask | Field::CIp),
+        Err("c_ip invalid")
+    );
+
+    // the unvalidated variant stops after the last selected field
+    let truncated = "2019-12-04\t21:02:31\tLAX1";
+    let item = UnvalidatedProjectedLogline::parse(truncated, FieldMask::from(Field::Time)).unwrap();
+    assert_eq!(item.time, NaiveTime::from_hms_opt(21, 2, 31));
+    assert!(ValidatedProjectedLogline::parse(truncated, FieldMask::from(Field::Time)).is_err());
+
+    // but the fields up to the last selected one have to be present
+    assert_eq!(
+        UnvalidatedProjectedLogline::parse(truncated, FieldMask::ALL).map(|_line| ()),
+        Err("Invalid log line (field count)")
+    );
+    assert_eq!(
+        UnvalidatedProjectedLogline::parse(truncated, FieldMask::from(Field::ScBytes))
+            .map(|_line| ()),
+        Err("Invalid log line (field count)")
+    );
+}
+
+#[test]
+fn field_mask_operations() {
+    assert!(FieldMask::EMPTY.is_empty());
+    assert_eq!(FieldMask::ALL.len(), 33);
+    assert_eq!(FieldMask::ALL.last(), Some(Field::ScRangeEnd));
+    assert_eq!(FieldMask::EMPTY.last(), None);
+    assert_eq!(FieldMask::ALL.iter().collect::<Vec<_>>(), Field::ALL);
+
+    let mask = FieldMask::from([Field::CsHost, Field::Date]);
+    assert_eq!(
+        mask.iter().collect::<Vec<_>>(),
+        [Field::Date, Field::CsHost]
+    );
+    assert!(!mask.without(Field::Date).contains(Field::Date));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_into_user_defined_struct() {
+    use serde::Deserialize;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Deserialize)]
+    struct Request<'a> {
+        cs_host: &'a str,
+        #[serde(rename = "cs(User-Agent)")]
+        user_agent: &'a str,
+        sc_status: u16,
+        time_taken: f64,
+        cs_referer: Option<&'a str>,
+        x_forwarded_for: Option<ForwardedForAddrs>,
+        ssl_protocol: Option<SslProtocol>,
+        x_edge_detailed_result_type: DetailedEdgeResultType<'static>,
+        sc_range_end: Option<i64>,
+    }
+
+    let item: Request<'_> = de::from_str(SINGLE_LOG_LINE).unwrap();
+    assert_eq!(item.cs_host, "d111111abcdef8.cloudfront.net");
+    assert!(item.user_agent.starts_with("Mozilla/5.0"));
+    assert_eq!(item.sc_status, 200);
+    assert_eq!(item.time_taken, 0.001);
+    assert_eq!(item.cs_referer, None);
+    assert_eq!(item.x_forwarded_for.map(|addrs| addrs.0.len()), Some(3));
+    assert_eq!(item.ssl_protocol, Some(SslProtocol::TLSv1_2));
+    assert_eq!(
+        item.x_edge_detailed_result_type,
+        DetailedEdgeResultType::Hit
+    );
+    assert_eq!(item.sc_range_end, None);
+
+    let map: HashMap<&str, &str> = de::from_str(SINGLE_LOG_LINE).unwrap();
+    assert_eq!(map.len(), 33);
+    assert_eq!(map.get("c_port"), Some(&"11040"));
+
+    let broken = SINGLE_LOG_LINE.replace("\t200\t", "\tOK\t");
+    assert!(de::from_str::<Request<'_>>(&broken).is_err());
+    assert!(de::from_str::<Request<'_>>("#Version: 1.0").is_err());
+
+    // the crate types also deserialize from formats which cannot lend their strings
+    use serde::de::{IntoDeserializer, value::Error as ValueError};
+    let owned = |value: &str| IntoDeserializer::<ValueError>::into_deserializer(value.to_string());
+    assert_eq!(
+        CsProtocol::deserialize(owned("https")),
+        Ok(CsProtocol::Https)
+    );
+    assert_eq!(
+        EdgeResultType::deserialize(owned("Teleported")),
+        Ok(EdgeResultType::Other("Teleported".into()))
+    );
+    assert_eq!(
+        Field::deserialize(IntoDeserializer::<ValueError>::into_deserializer(
+            "cs(Host)"
+        )),
+        Ok(Field::CsHost)
+    );
+    assert!(SslProtocol::deserialize(owned("TLSv9")).is_err());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn parse_bytes_with_lazy_utf8_check() {
+    use borrowed::bytes::{ValidatedChronoBytesLogline, ValidatedRawBytesLogline};
+
+    let line = SINGLE_LOG_LINE.as_bytes();
+    let raw = ValidatedRawBytesLogline::try_from(line).unwrap();
+    let typed = ValidatedChronoBytesLogline::try_from(line).unwrap();
+    let expected = ValidatedChronoLogline::try_from(SINGLE_LOG_LINE).unwrap();
+
+    assert_eq!(raw.get_str(Field::CsHost), Ok(expected.cs_host));
+    assert_eq!(typed.datetime, expected.datetime);
+    assert_eq!(typed.c_ip, expected.c_ip);
+    assert_eq!(typed.x_forwarded_for, expected.x_forwarded_for);
+    assert_eq!(typed.cs_user_agent.to_str(), Ok(expected.cs_user_agent));
+    assert_eq!(typed.cs_referer, None);
+
+    // invalid UTF-8 in a free-text field is only detected on access
+    let mut corrupted = line.to_vec();
+    let at = SINGLE_LOG_LINE.find("Mozilla").unwrap();
+    corrupted.splice(at..at, [0xF0, 0x28]);
+    assert!(std::str::from_utf8(&corrupted).is_err());
+
+    let raw = ValidatedRawBytesLogline::try_from(corrupted.as_slice()).unwrap();
+    assert!(raw.get_str(Field::CsUserAgent).is_err());
+    assert!(raw.get_lossy(Field::CsUserAgent).starts_with("\u{FFFD}("));
+    let typed = ValidatedChronoBytesLogline::try_from(corrupted.as_slice()).unwrap();
+    assert!(typed.cs_user_agent.to_str().is_err());
+    assert_eq!(typed.sc_status, 200);
+
+    // but not in a structured field
+    let mut corrupted = line.to_vec();
+    let at = SINGLE_LOG_LINE.find("LAX1").unwrap();
+    corrupted.splice(at..at, [0xFF]);
+    assert!(ValidatedRawBytesLogline::try_from(corrupted.as_slice()).is_ok());
+    assert_eq!(
+        ValidatedChronoBytesLogline::try_from(corrupted.as_slice()),
+        Err("x_edge_location invalid")
+    );
+}
+
+// parsers must never panic, whatever the input is
+mod panic_free {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn parse_with_all_parsers(bytes: &[u8]) {
+        let _ = borrowed::ValidatedRawBytesLogline::try_from(bytes);
+        let _ = borrowed::UnvalidatedRawBytesLogline::from(bytes);
+        let _ = borrowed::bytes::ValidatedChronoBytesLogline::try_from(bytes);
+        let _ = borrowed::bytes::UnvalidatedChronoBytesLogline::try_from(bytes);
+        let _ = borrowed::bytes::ValidatedTimeBytesLogline::try_from(bytes);
+        let _ = borrowed::bytes::UnvalidatedTimeBytesLogline::try_from(bytes);
+
+        let line = String::from_utf8_lossy(bytes);
+        let line = line.as_ref();
+
+        let _ = ValidatedRawLogline::try_from(line);
+        let _ = UnvalidatedRawLogline::from(line);
+        let _ = ValidatedSimpleLogline::try_from(line);
+        let _ = UnvalidatedSimpleLogline::try_from(line);
+        let _ = ValidatedChronoLogline::try_from(line);
+        let _ = UnvalidatedChronoLogline::try_from(line);
+        let _ = ValidatedTimeLogline::try_from(line);
+        let _ = UnvalidatedTimeLogline::try_from(line);
+        let _ = ValidatedParquetLogline::try_from(line);
+        let _ = UnvalidatedParquetLogline::try_from(line);
+        #[cfg(feature = "jiff")]
+        {
+            let _ = ValidatedJiffLogline::try_from(line);
+            let _ = UnvalidatedJiffLogline::try_from(line);
+            let _ = chunked::parse_chunk::<chunked::ValidatedJiffLogline>(line).count();
+        }
+        let _ = ValidatedProjectedLogline::parse(line, FieldMask::ALL);
+        let _ = UnvalidatedProjectedLogline::parse(line, FieldMask::ALL);
+        if let Ok(view) = LoglineView::try_from(line) {
+            let _ = view.get(Field::ScRangeEnd);
+            let _ = view.time_taken_duration();
+        }
+
+        let _ = owned::ValidatedSimpleLogline::try_from(line);
+        let _ = owned::UnvalidatedSimpleLogline::try_from(line);
+        let _ = OwnedValidatedParquetLogline::try_from(line);
+        let _ = OwnedUnvalidatedParquetLogline::try_from(line);
+        let _ = OwnedValidatedParquetMillisLogline::try_from(line);
+        let _ = OwnedUnvalidatedParquetMillisLogline::try_from(line);
+        let _ = ValidatedParquetMillisLogline::try_from(line);
+        let _ = UnvalidatedParquetMillisLogline::try_from(line);
+
+        let _ = owned::ValidatedSimpleLogline::parse_lenient(line);
+        let _ = owned::UnvalidatedSimpleLogline::parse_lenient(line);
+        let _ = OwnedValidatedChronoLogline::parse_lenient(line);
+        let _ = OwnedUnvalidatedChronoLogline::parse_lenient(line);
+        let _ = OwnedValidatedTimeLogline::parse_lenient(line);
+        let _ = OwnedUnvalidatedTimeLogline::parse_lenient(line);
+        let _ = ValidatedSimpleLogline::parse_lenient(line);
+        let _ = UnvalidatedSimpleLogline::parse_lenient(line);
+        let _ = ValidatedChronoLogline::parse_lenient(line);
+        let _ = UnvalidatedChronoLogline::parse_lenient(line);
+        let _ = ValidatedTimeLogline::parse_lenient(line);
+        let _ = UnvalidatedTimeLogline::parse_lenient(line);
+        #[cfg(feature = "jiff")]
+        {
+            let _ = ValidatedJiffLogline::parse_lenient(line);
+            let _ = UnvalidatedJiffLogline::parse_lenient(line);
+        }
+
+        // twice, so the second call reuses the buffers of the first one
+        let mut raw = owned::UnvalidatedRawLogline::from(SINGLE_LOG_LINE);
+        let mut simple = owned::UnvalidatedSimpleLogline::try_from(SINGLE_LOG_LINE).unwrap();
+        let mut chrono = OwnedUnvalidatedChronoLogline::try_from(SINGLE_LOG_LINE).unwrap();
+        let mut time = OwnedUnvalidatedTimeLogline::try_from(SINGLE_LOG_LINE).unwrap();
+        for _ in 0..2 {
+            raw.parse_into(line);
+            let _ = simple.parse_into(line);
+            let _ = chrono.parse_into(line);
+            let _ = time.parse_into(line);
+        }
+        let mut validated = owned::ValidatedSimpleLogline::try_from(SINGLE_LOG_LINE).unwrap();
+        let _ = validated.parse_into(line);
+
+        let mut interner = owned::Interner::new();
+        for _ in 0..2 {
+            let _ = owned::ValidatedInternedLogline::parse(line, &mut interner);
+            let _ = owned::UnvalidatedInternedLogline::parse(line, &mut interner);
+        }
+
+        let mut columns = columns::LogColumns::new();
+        let _ = columns.push_line(line);
+        let _ = columns.push_line(SINGLE_LOG_LINE);
+        let _ = columns.push_line(line);
+
+        for result in chunked::parse_chunk::<chunked::ValidatedSimpleLogline>(line) {
+            let _ = result.map(|item| item.view().sc_range_end);
+        }
+        let _ = chunked::parse_chunk::<chunked::UnvalidatedRawLogline>(line).count();
+        let _ = chunked::parse_chunk::<chunked::ValidatedChronoLogline>(line).count();
+        let _ = chunked::parse_chunk::<chunked::ValidatedTimeLogline>(line).count();
+        let _ = chunked::parse_chunk::<chunked::UnvalidatedParquetLogline>(line).count();
+
+        #[cfg(feature = "bytes")]
+        {
+            let buffer = bytes::Bytes::copy_from_slice(bytes);
+            for result in
+                bytes_backed::parse_buffer::<bytes_backed::ValidatedSimpleLogline>(buffer.clone())
+            {
+                let _ = result.map(|item| item.view().sc_range_end);
+            }
+            let _ =
+                bytes_backed::parse_buffer::<bytes_backed::UnvalidatedRawLogline>(buffer.clone())
+                    .count();
+            let _ =
+                bytes_backed::parse_buffer::<bytes_backed::ValidatedChronoLogline>(buffer.clone())
+                    .count();
+            let _ =
+                bytes_backed::parse_buffer::<bytes_backed::ValidatedTimeLogline>(buffer.clone())
+                    .count();
+            #[cfg(feature = "jiff")]
+            let _ =
+                bytes_backed::parse_buffer::<bytes_backed::ValidatedJiffLogline>(buffer.clone())
+                    .count();
+            let _ = bytes_backed::parse_buffer::<bytes_backed::UnvalidatedParquetLogline>(buffer)
+                .count();
+        }
+
+        let _ = OwningValidatedRawLogline::try_from(line);
+        let _ = OwningUnvalidatedRawLogline::try_from(line);
+        let _ = OwningValidatedSimpleLogline::try_from(line);
+        let _ = OwningUnvalidatedSimpleLogline::try_from(line);
+        let _ = OwningValidatedChronoLogline::try_from(line);
+        let _ = OwningUnvalidatedChronoLogline::try_from(line);
+        let _ = OwningValidatedTimeLogline::try_from(line);
+        let _ = OwningUnvalidatedTimeLogline::try_from(line);
+        let _ = OwningValidatedParquetLogline::try_from(line);
+        let _ = OwningUnvalidatedParquetLogline::try_from(line);
+
+        let _ = CheckedRawLogLine::try_from(line);
+        let _ = SimpleLogLine::try_from(line);
+        let _ = TypedLogLine::try_from(line);
+        let _ = ParquetLogLine::try_from(line);
+        if let Ok(view) = CheckedRawLogLineView::new(line) {
+            let _ = view.sc_range_end();
+        }
+        if let Ok(view) = SmartRawLogLineView::new(line) {
+            let _ = view.sc_range_end();
+            let _ = view.date();
+        }
+
+        #[cfg(feature = "serde")]
+        let _ = de::from_str_unvalidated::<std::collections::HashMap<&str, &str>>(line);
+    }
+
+    // a valid log line with some of its fields replaced
+    fn mutated_line() -> impl Strategy<Value = Vec<u8>> {
+        let fields: Vec<Vec<u8>> = SINGLE_LOG_LINE
+            .split('\t')
+            .map(|field| field.as_bytes().to_vec())
+            .collect();
+        let replacement = prop_oneof![
+            Just(b"-".to_vec()),
+            Just(Vec::new()),
+            Just(b"-1".to_vec()),
+            Just(b"1e400".to_vec()),
+            Just(b"NaN".to_vec()),
+            "[0-9.:,\\- ]{0,12}".prop_map(String::into_bytes),
+            proptest::collection::vec(any::<u8>(), 0..16),
+        ];
+        (
+            proptest::collection::vec((0..fields.len(), replacement), 0..6),
+            0..=fields.len(),
+        )
+            .prop_map(move |(replacements, keep)| {
+                let mut fields = fields.clone();
+                for (index, value) in replacements {
+                    if let Some(field) = fields.get_mut(index) {
+                        *field = value;
+                    }
+                }
+                fields.truncate(keep);
+                fields.join(&b'\t')
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_bytes(bytes in proptest::collection::vec(any::<u8>(), 0..512)) {
+            parse_with_all_parsers(&bytes);
+        }
+
+        #[test]
+        fn arbitrary_tab_separated(line in "[\t#a-zA-Z0-9.:, -]{0,256}") {
+            parse_with_all_parsers(line.as_bytes());
+        }
+
+        #[test]
+        fn mutated_log_lines(bytes in mutated_line()) {
+            parse_with_all_parsers(&bytes);
+        }
+    }
+
+    #[test]
+    fn known_edge_cases() {
+        for line in [
+            "",
+            "\t",
+            "-",
+            "\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t\t",
+            "2019-12-04\t21:02:31",
+        ] {
+            parse_with_all_parsers(line.as_bytes());
+        }
+        let negative = SINGLE_LOG_LINE.replace("\t0.001\t", "\t-0.001\t");
+        parse_with_all_parsers(negative.as_bytes());
+        assert_eq!(
+            ValidatedSimpleLogline::try_from(negative.as_str()),
+            Err("time_taken invalid")
+        );
+        assert_eq!(
+            UnvalidatedSimpleLogline::try_from("2019-12-04\t21:02:31").map(|_line| ()),
+            Err("Invalid log line (field count)")
+        );
+        assert_eq!(
+            UnvalidatedProjectedLogline::parse("2019-12-04\t21:02:31\tLAX1", FieldMask::ALL)
+                .map(|_line| ()),
+            Err("Invalid log line (field count)")
+        );
+    }
+}
+
+// the fixed-width date/time parsing must agree with the format strings of the date/time crates
+mod fixed_width_date_time {
+    use super::*;
+    use crate::shared::*;
+    use proptest::prelude::*;
+
+    #[cfg(feature = "jiff")]
+    const JIFF_DATE_FMT: &str = "%Y-%m-%d";
+    #[cfg(feature = "jiff")]
+    const JIFF_TIME_FMT: &str = "%H:%M:%S";
+
+    proptest! {
+        #[test]
+        fn dates(date in "[0-9]{4}-[0-1][0-9]-[0-3][0-9]") {
+            let chrono_date = NaiveDate::parse_from_str(&date, CHRONO_DATE_FMT).ok();
+            prop_assert_eq!(parse_chrono_date(&date).ok(), chrono_date);
+            prop_assert_eq!(parse_time_date(&date).ok(), Date::parse(&date, TIME_DATE_FMT).ok());
+            #[cfg(feature = "jiff")]
+            prop_assert_eq!(
+                parse_jiff_date(&date).ok(),
+                jiff::civil::Date::strptime(JIFF_DATE_FMT, &date).ok()
+            );
+            prop_assert_eq!(parse_ymd(date.as_bytes()).is_ok(), chrono_date.is_some());
+        }
+
+        #[test]
+        fn times(time in "[0-2][0-9]:[0-6][0-9]:[0-5][0-9]") {
+            let chrono_time = NaiveTime::parse_from_str(&time, CHRONO_TIME_FMT).ok();
+            prop_assert_eq!(parse_chrono_time(&time).ok(), chrono_time);
+            prop_assert_eq!(parse_time_time(&time).ok(), Time::parse(&time, TIME_TIME_FMT).ok());
+            #[cfg(feature = "jiff")]
+            prop_assert_eq!(
+                parse_jiff_time(&time).ok(),
+                jiff::civil::Time::strptime(JIFF_TIME_FMT, &time).ok()
+            );
+        }
+
+        #[test]
+        fn malformed(input in "[0-9:-]{0,12}") {
+            if let Ok(date) = parse_chrono_date(&input) {
+                prop_assert_eq!(NaiveDate::parse_from_str(&input, CHRONO_DATE_FMT), Ok(date));
+            }
+            if let Ok(time) = parse_chrono_time(&input) {
+                prop_assert_eq!(NaiveTime::parse_from_str(&input, CHRONO_TIME_FMT), Ok(time));
+            }
+        }
+    }
+
+    #[test]
+    fn leap_years_and_month_ends() {
+        assert!(parse_chrono_date("2020-02-29").is_ok());
+        assert!(parse_chrono_date("2000-02-29").is_ok());
+        assert!(parse_chrono_date("1900-02-29").is_err());
+        assert!(parse_chrono_date("2019-04-31").is_err());
+        assert!(parse_chrono_date("2019-12-4").is_err());
+        assert!(parse_chrono_time("24:00:00").is_err());
+        assert!(parse_chrono_time("23:59:60").is_err());
+    }
+}
+
+// the durations are parsed as fixed-point decimals, without a detour via f64
+mod fixed_point_durations {
+    use super::*;
+    use crate::shared::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn millis(secs in 0u64..1_000_000, millis in 0u32..1_000) {
+            let input = format!("{secs}.{millis:03}");
+            let expected = Duration::new(secs, millis * 1_000_000);
+            prop_assert_eq!(parse_duration(&input), Ok(expected));
+            prop_assert_eq!(parse_millis(&input), Ok(secs * 1_000 + u64::from(millis)));
+        }
+
+        #[test]
+        fn nanos(secs in 0u64..1_000_000_000, nanos in 0u32..1_000_000_000) {
+            let input = format!("{secs}.{nanos:09}");
+            prop_assert_eq!(parse_duration(&input), Ok(Duration::new(secs, nanos)));
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        assert_eq!(parse_duration("0.102"), Ok(Duration::from_millis(102)));
+        assert_eq!(parse_duration("12"), Ok(Duration::from_secs(12)));
+        assert_eq!(parse_duration("12."), Ok(Duration::from_secs(12)));
+        assert_eq!(parse_duration(".5"), Ok(Duration::from_millis(500)));
+        assert_eq!(
+            parse_duration("18446744073709551615.999999999"),
+            Ok(Duration::MAX)
+        );
+        for input in [
+            "",
+            ".",
+            "-0.001",
+            "+0.001",
+            "1e3",
+            "0,001",
+            "0.0000000001",
+            "18446744073709551616",
+            "NaN",
+        ] {
+            assert_eq!(parse_duration(input), Err(()), "{input:?}");
+        }
+        assert_eq!(parse_millis("0.0005"), Err(()));
+        assert_eq!(parse_millis("0.100000"), Ok(100));
+    }
+
+    #[test]
+    fn parquet_millis_loglines() {
+        let simple = ValidatedSimpleLogline::try_from(SINGLE_LOG_LINE).unwrap();
+        let millis = ValidatedParquetMillisLogline::try_from(SINGLE_LOG_LINE).unwrap();
+        assert_eq!(millis.time_taken_ms, 1);
+        assert_eq!(millis.time_to_first_byte_ms, 1);
+        assert_eq!(CloudFrontRecord::time_taken(&millis), Ok(simple.time_taken));
+        assert_eq!(millis.to_owned_logline().as_borrowed(), millis);
+
+        let rows = AWS_DOCS_EXAMPLE
+            .lines()
+            .filter_map(|l| OwnedValidatedParquetMillisLogline::try_from(l).ok())
+            .collect::<Vec<_>>();
+        assert_eq!(rows.len(), 6);
+        let schema = (&rows.as_slice()).schema().unwrap();
+        let expected = parse_message_type(parquet_schemata::V1_MILLIS).unwrap();
+        let names = |t: &::parquet::schema::types::Type| {
+            t.get_fields()
+                .iter()
+                .map(|f| f.name().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&schema), names(&expected));
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn parallel_parsing_matches_sequential_parsing() {
+    use parallel::{ParParser, par_parse};
+
+    let input = std::iter::repeat_n(AWS_DOCS_EXAMPLE, 50)
+        .collect::<Vec<_>>()
+        .join("\nbroken line\n");
+    let sequential: Vec<_> = input
+        .lines()
+        .filter_map(|line| ValidatedSimpleLogline::try_from(line).ok())
+        .collect();
+
+    let report = par_parse::<ValidatedSimpleLogline<'_>>(&input);
+    assert_eq!(report.output, sequential);
+
+    let parser = ParParser::new(&input).chunk_size(1000);
+    let ordered = parser.parse::<ValidatedSimpleLogline<'_>>();
+    assert_eq!(ordered.output, sequential);
+    assert_eq!(ordered.errors.len(), 49);
+    assert_eq!(ordered.errors.first().map(|e| e.line), Some(9));
+    assert_eq!(ordered.errors.last().map(|e| e.line), Some(49 * 9));
+    assert_eq!(ordered.errors, report.errors);
+
+    let mut unordered = parser
+        .unordered()
+        .parse::<owned::ValidatedSimpleLogline>()
+        .output;
+    unordered.sort_by(|a, b| a.x_edge_request_id.cmp(&b.x_edge_request_id));
+    let mut expected: Vec<_> = sequential
+        .iter()
+        .map(|line| line.x_edge_request_id.to_string())
+        .collect();
+    expected.sort();
+    assert_eq!(
+        unordered
+            .iter()
+            .map(|line| line.x_edge_request_id.clone())
+            .collect::<Vec<_>>(),
+        expected
+    );
+
+    let folded = parser.fold(
+        || 0,
+        |sum, line: ValidatedSimpleLogline<'_>| sum + line.sc_bytes,
+        |a, b| a + b,
+    );
+    assert_eq!(
+        folded.output,
+        sequential.iter().map(|line| line.sc_bytes).sum::<u64>()
+    );
+    assert_eq!(folded.errors, report.errors);
+
+    let empty = par_parse::<ValidatedRawLogline<'_>>("");
+    assert!(empty.is_ok() && empty.output.is_empty());
+}
+
+#[test]
+fn log_reader_handles_headers_and_errors() {
+    use reader::{LogReader, ReadError};
+
+    let input = format!("{AWS_DOCS_EXAMPLE}\n\nbroken line\n#Fields: date time\n");
+    let mut reader = LogReader::<_, OwningValidatedSimpleLogline>::new(input.as_bytes());
+    let items: Vec<_> = reader.by_ref().collect();
+
+    assert_eq!(reader.version(), Some("1.0"));
+    assert_eq!(reader.lines_read(), 11);
+    assert_eq!(items.len(), 8);
+    assert!(items.iter().take(6).all(Result::is_ok));
+    assert!(matches!(
+        items.get(6),
+        Some(Err(ReadError::Parse { line: 10, .. }))
+    ));
+    assert!(matches!(
+        items.get(7),
+        Some(Err(ReadError::UnexpectedFields { line: 11 }))
+    ));
+}
+
+#[test]
+fn log_reader_counts_unreadable_lines() {
+    use reader::{LogReader, ReadError};
+
+    // the reader fails on the third line, which is not valid UTF-8
+    let mut input = Vec::new();
+    for line in [
+        AWS_DOCS_EXAMPLE.lines().next().unwrap().as_bytes(),
+        SINGLE_LOG_LINE.as_bytes(),
+        b"\xff\xfe",
+        b"broken line",
+        b"#Fields: date time",
+    ] {
+        input.extend_from_slice(line);
+        input.push(b'\n');
+    }
+
+    let mut reader = LogReader::<_, OwningValidatedSimpleLogline>::new(input.as_slice());
+    let items: Vec<_> = reader.by_ref().collect();
+    assert_eq!(reader.lines_read(), 5);
+    assert_eq!(items.len(), 4);
+    assert!(items.first().unwrap().is_ok());
+    assert!(matches!(items.get(1), Some(Err(ReadError::Io(_)))));
+    assert!(matches!(
+        items.get(2),
+        Some(Err(ReadError::Parse { line: 4, .. }))
+    ));
+    assert!(matches!(
+        items.get(3),
+        Some(Err(ReadError::UnexpectedFields { line: 5 }))
+    ));
+
+    #[cfg(feature = "tokio")]
+    {
+        use futures::{StreamExt, executor::block_on};
+        let mut stream =
+            reader::LogStream::<_, OwningValidatedSimpleLogline>::new(input.as_slice());
+        let items: Vec<_> = block_on(stream.by_ref().collect());
+        assert_eq!(stream.lines_read(), 5);
+        assert!(matches!(
+            items.get(3),
+            Some(Err(ReadError::UnexpectedFields { line: 5 }))
+        ));
+    }
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn log_stream_yields_same_items_as_log_reader() {
+    use futures::{StreamExt, executor::block_on};
+    use reader::{LogReader, LogStream};
+    use tokio::io::AsyncReadExt;
+
+    let input = format!("{AWS_DOCS_EXAMPLE}\r\nbroken line\n");
+    let expected: Vec<String> = LogReader::<_, OwningValidatedRawLogline>::new(input.as_bytes())
+        .map(|item| format!("{item:?}"))
+        .collect();
+    assert_eq!(expected.len(), 7);
+
+    block_on(async {
+        let items: Vec<String> = LogStream::<_, OwningValidatedRawLogline>::new(input.as_bytes())
+            .map(|item| format!("{item:?}"))
+            .collect()
+            .await;
+        assert_eq!(items, expected);
+
+        let mut compressed = Vec::new();
+        async_compression::tokio::bufread::GzipEncoder::new(input.as_bytes())
+            .read_to_end(&mut compressed)
+            .await
+            .unwrap();
+        let items: Vec<String> =
+            LogStream::<_, OwningValidatedRawLogline>::gzip(compressed.as_slice())
+                .map(|item| format!("{item:?}"))
+                .collect()
+                .await;
+        assert_eq!(items, expected);
+    });
+}
+
+#[test]
+fn cloudfront_record_is_consistent_across_variants() {
+    fn summary(r: &impl CloudFrontRecord) -> String {
+        format!(
+            "{:?} {} {:?} {:?} {} {} {:?} {:?} {:?} {:?} {:?} {:?}",
+            r.timestamp(),
+            r.edge_location(),
+            r.bytes_sent(),
+            r.client_ip(),
+            r.host(),
+            r.uri_stem(),
+            r.status(),
+            r.referer(),
+            r.result_type(),
+            r.time_taken(),
+            r.detailed_result_type(),
+            r.content_len(),
+        )
+    }
+
+    let expected = summary(&ValidatedRawLogline::try_from(SINGLE_LOG_LINE).unwrap());
+    assert!(expected.starts_with("Ok(1575493351) LAX1 Ok(392) Ok(192.0.2.100)"));
+
+    let line = SINGLE_LOG_LINE;
+    let mut columns = columns::LogColumns::new();
+    columns.push_line(line).unwrap();
+    let views = [
+        summary(&LoglineView::try_from(line).unwrap()),
+        summary(&UnvalidatedRawLogline::from(line)),
+        summary(&ValidatedSimpleLogline::try_from(line).unwrap()),
+        summary(&ValidatedChronoLogline::try_from(line).unwrap()),
+        summary(&ValidatedTimeLogline::try_from(line).unwrap()),
+        summary(&ValidatedParquetLogline::try_from(line).unwrap()),
+        summary(&owned::ValidatedRawLogline::try_from(line).unwrap()),
+        summary(&owned::ValidatedSimpleLogline::try_from(line).unwrap()),
+        summary(
+            &owned::ValidatedInternedLogline::parse(line, &mut owned::Interner::new()).unwrap(),
+        ),
+        summary(&OwnedValidatedTimeLogline::try_from(line).unwrap()),
+        summary(&OwnedValidatedParquetLogline::try_from(line).unwrap()),
+        summary(&OwningValidatedRawLogline::try_from(line).unwrap()),
+        summary(&OwningValidatedChronoLogline::try_from(line).unwrap()),
+        summary(&OwningValidatedParquetLogline::try_from(line).unwrap()),
+        summary(&columns.get(0).unwrap()),
+    ];
+    for view in views {
+        assert_eq!(view, expected);
+    }
+    #[cfg(feature = "jiff")]
+    for view in [
+        summary(&ValidatedJiffLogline::try_from(line).unwrap()),
+        summary(&OwningValidatedJiffLogline::try_from(line).unwrap()),
+    ] {
+        assert_eq!(view, expected);
+    }
+}
+
+#[test]
+fn chunked_loglines_share_the_chunk() {
+    fn assert_send_sync_static<T: Send + Sync + 'static>(_: &T) {}
+
+    let input = format!("{AWS_DOCS_EXAMPLE}\r\n\n{SINGLE_LOG_LINE}");
+    let chunk: chunked::Chunk = input.as_str().into();
+
+    let lines = chunked::parse_chunk::<chunked::ValidatedChronoLogline>(Arc::clone(&chunk))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(lines.len(), 7);
+    assert_send_sync_static(lines.first().unwrap());
+
+    let expected = input
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'));
+    for (line, raw) in lines.iter().zip(expected) {
+        assert!(Arc::ptr_eq(line.chunk(), &chunk));
+        assert_eq!(line.as_raw(), raw);
+        assert_eq!(
+            line.view(),
+            &UnvalidatedChronoLogline::try_from(raw).unwrap()
+        );
+        assert_eq!(
+            line.view(),
+            OwningValidatedChronoLogline::try_from(raw).unwrap().view()
+        );
+    }
+
+    let last = lines.last().unwrap();
+    let cloned = last.clone();
+    assert_eq!(&cloned, last);
+    assert!(Arc::ptr_eq(cloned.chunk(), &chunk));
+    assert_eq!(cloned.view().x_forwarded_for.as_ref().unwrap().0.len(), 3);
+
+    drop(input);
+    drop(lines);
+    let line = cloned.into_line();
+    assert_eq!(line.as_str(), SINGLE_LOG_LINE);
+    assert_eq!(Arc::strong_count(&chunk), 2);
+
+    assert!(chunked::LineRef::new(Arc::clone(&chunk), 0..usize::MAX).is_err());
+    let raw = chunked::ValidatedRawLogline::try_from(line).unwrap();
+    assert_eq!(raw.get(Field::XEdgeLocation), "LAX1");
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn bytes_backed_loglines_slice_the_buffer() {
+    use ::bytes::Bytes;
+
+    fn assert_send_sync_static<T: Send + Sync + 'static>(_: &T) {}
+
+    let buffer = Bytes::from(format!("{AWS_DOCS_EXAMPLE}\r\n\n{SINGLE_LOG_LINE}\n"));
+    let lines = bytes_backed::parse_buffer::<bytes_backed::ValidatedTimeLogline>(buffer.clone())
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(lines.len(), 7);
+    assert_send_sync_static(lines.first().unwrap());
+
+    let chunked = chunked::parse_chunk::<chunked::ValidatedTimeLogline>(
+        core::str::from_utf8(&buffer).unwrap(),
+    );
+    let range = buffer.as_ptr_range();
+    for (line, expected) in lines.iter().zip(chunked) {
+        let expected = expected.unwrap();
+        assert!(range.contains(&line.as_bytes().as_ptr()));
+        assert_eq!(line.as_raw(), expected.as_raw());
+        assert_eq!(line.view(), expected.view());
+    }
+
+    let last = lines.last().unwrap();
+    assert_eq!(last.as_raw(), SINGLE_LOG_LINE);
+    assert_eq!(&last.clone(), last);
+    assert_eq!(last.to_owned_logline(), last.clone().into_owned());
+
+    let mut invalid = SINGLE_LOG_LINE.as_bytes().to_vec();
+    *invalid.get_mut(40).unwrap() = 0xFF;
+    assert_eq!(
+        bytes_backed::UnvalidatedRawLogline::try_from(Bytes::from(invalid)),
+        Err("Invalid log line (not valid UTF-8)")
+    );
+    assert!(
+        bytes_backed::ValidatedRawLogline::try_from(Bytes::from_static(b"#Version: 1.0")).is_err()
+    );
+}
+
+#[test]
+fn interned_loglines_share_repeated_values() {
+    let mut interner = owned::Interner::new();
+    let lines = AWS_DOCS_EXAMPLE
+        .lines()
+        .skip(2)
+        .map(|line| owned::ValidatedInternedLogline::parse(line, &mut interner).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 6);
+
+    for (line, raw) in lines.iter().zip(AWS_DOCS_EXAMPLE.lines().skip(2)) {
+        let simple = owned::ValidatedSimpleLogline::try_from(raw).unwrap();
+        assert_eq!(&*line.x_edge_location, simple.x_edge_location);
+        assert_eq!(&*line.cs_host, simple.cs_host);
+        assert_eq!(&*line.cs_user_agent, simple.cs_user_agent);
+        assert_eq!(&*line.x_host_header, simple.x_host_header);
+        assert_eq!(
+            line.sc_content_type.as_deref(),
+            simple.sc_content_type.as_deref()
+        );
+        assert_eq!(line.x_edge_request_id, simple.x_edge_request_id);
+    }
+
+    let (first, last) = (lines.first().unwrap(), lines.last().unwrap());
+    assert!(Arc::ptr_eq(&first.cs_host, &last.cs_host));
+    assert!(Arc::ptr_eq(
+        first.sc_content_type.as_ref().unwrap(),
+        last.sc_content_type.as_ref().unwrap()
+    ));
+    assert!(!Arc::ptr_eq(&first.cs_user_agent, &last.cs_user_agent));
+    // 3 edge locations, 2 hosts (shared by cs_host and x_host_header), 2 user agents, 1 content type
+    assert_eq!(interner.len(), 8);
+
+    interner.clear();
+    assert!(interner.is_empty());
+    assert_eq!(&*first.x_edge_location, "LAX1");
+}
+
+#[test]
+fn log_columns_round_trip_rows() {
+    let lines = AWS_DOCS_EXAMPLE.lines().skip(2).collect::<Vec<_>>();
+    let mut columns = columns::LogColumns::new();
+    for line in &lines {
+        columns
+            .push(&OwnedValidatedTimeLogline::try_from(*line).unwrap())
+            .unwrap();
+    }
+    assert!(columns.push_line("#Version: 1.0").is_err());
+    assert_eq!(columns.len(), 6);
+    assert_eq!(columns.status().len(), 6);
+
+    for (row, line) in columns.iter().zip(&lines) {
+        let expected = ValidatedSimpleLogline::try_from(*line).unwrap();
+        assert_eq!(row, columns::Row::from_record(&expected).unwrap());
+    }
+
+    assert_eq!(columns.edge_location().values().len(), 3);
+    assert_eq!(columns.host().values().len(), 1);
+    assert_eq!(columns.referer().codes().iter().flatten().count(), 1);
+    assert_eq!(columns.uri_query().get(0), None);
+    assert_eq!(
+        columns.request_id().get(5),
+        Some("kBkDzGnceVtWHqSCqBUqtA_cEs2T3tFUBbnBNkB9El_uVRhHgcZfcw==")
+    );
+    assert_eq!(columns.request_id().get(6), None);
+
+    let errors = columns.filter(&columns.mask(|row| row.status >= 500));
+    assert_eq!(errors.len(), 3);
+    assert_eq!(errors.edge_location().values().len(), 2);
+    assert!(
+        errors
+            .iter()
+            .all(|row| row.result_type == EdgeResultType::Error)
+    );
+
+    let slice = columns.slice(4..10);
+    assert_eq!(slice.len(), 2);
+    assert_eq!(slice.get(0), columns.get(4));
+    assert_eq!(slice.get(1), columns.get(5));
+    assert_eq!(columns.slice(0..columns.len()), columns);
+    assert!(columns.slice(7..9).is_empty());
+}
+
+mod codec_round_trip {
+    use super::*;
+    use codec::{CodecError, Decoder, Encoder};
+
+    fn lines() -> Vec<String> {
+        let special = SINGLE_LOG_LINE
+            .replace(
+                "1.2.3.4, 5.6.7.8,\x209.10.11.12",
+                "1.2.3.4:6969, unknown, 2001:db8::1",
+            )
+            .replace("\tHit\ttext/html", "\tSomethingNew\ttext/html")
+            .replace("\t78\t-\t-", "\t78\t5\t-1");
+        AWS_DOCS_EXAMPLE
+            .lines()
+            .skip(2)
+            .chain([SINGLE_LOG_LINE, special.as_str()])
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn encode<T: codec::Encode>(lines: &[T]) -> Vec<u8> {
+        let mut encoder = Encoder::new(Vec::new()).unwrap();
+        for line in lines {
+            encoder.encode(line).unwrap();
+        }
+        encoder.into_inner()
+    }
+
+    fn decode<T: codec::Decode>(bytes: &[u8]) -> Vec<T> {
+        Decoder::<_, T>::new(bytes)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn all_variants_share_the_format() {
+        let simple = lines()
+            .iter()
+            .map(|line| owned::ValidatedSimpleLogline::try_from(line.as_str()).unwrap())
+            .collect::<Vec<_>>();
+        let chrono = lines()
+            .iter()
+            .map(|line| OwnedValidatedChronoLogline::try_from(line.as_str()).unwrap())
+            .collect::<Vec<_>>();
+        let time = lines()
+            .iter()
+            .map(|line| OwnedValidatedTimeLogline::try_from(line.as_str()).unwrap())
+            .collect::<Vec<_>>();
+
+        let bytes = encode(&simple);
+        assert_eq!(bytes.get(..5), Some(b"CFLB\x01".as_slice()));
+        assert_eq!(bytes, encode(&chrono));
+        assert_eq!(bytes, encode(&time));
+        // most of the size are the user agents and request IDs, all else is compacted
+        assert!(bytes.len() < lines().iter().map(String::len).sum());
+
+        assert_eq!(decode::<owned::ValidatedSimpleLogline>(&bytes), simple);
+        assert_eq!(decode::<OwnedValidatedChronoLogline>(&bytes), chrono);
+        assert_eq!(decode::<OwnedValidatedTimeLogline>(&bytes), time);
+
+        let special = simple.last().unwrap();
+        assert_eq!(special.x_forwarded_for.as_ref().unwrap().0.len(), 3);
+        assert_eq!(
+            special.x_edge_detailed_result_type,
+            DetailedEdgeResultType::Other(Cow::Borrowed("SomethingNew"))
+        );
+        assert_eq!(special.sc_range_end, Some(-1));
+    }
+
+    #[test]
+    fn invalid_streams_are_rejected() {
+        let line = owned::UnvalidatedSimpleLogline::try_from(SINGLE_LOG_LINE).unwrap();
+        let bytes = encode(&[line.clone(), line]);
+
+        assert!(matches!(
+            Decoder::<_, owned::UnvalidatedSimpleLogline>::new(b"CFLX\x01".as_slice()),
+            Err(CodecError::NotACodecStream)
+        ));
+        assert!(matches!(
+            Decoder::<_, owned::UnvalidatedSimpleLogline>::new(b"CFLB\x02".as_slice()),
+            Err(CodecError::UnsupportedVersion(2))
+        ));
+        assert_eq!(
+            decode::<owned::UnvalidatedSimpleLogline>(b"CFLB\x01").len(),
+            0
+        );
+
+        // the second record is cut off: the first one is returned, then the error, then nothing
+        let truncated = bytes.get(..bytes.len() - 10).unwrap();
+        let mut decoder = Decoder::<_, owned::UnvalidatedSimpleLogline>::new(truncated).unwrap();
+        assert!(decoder.next().unwrap().is_ok());
+        assert!(matches!(decoder.next(), Some(Err(CodecError::Io(_)))));
+        assert!(decoder.next().is_none());
+
+        // invalid detailed result type code in an otherwise intact record,
+        // followed by content type (11 bytes), content length (2 bytes) and ranges (1 byte each)
+        let mut corrupted = bytes.clone();
+        let position = corrupted.len() - 16;
+        assert_eq!(corrupted.get(position), Some(&0));
+        *corrupted.get_mut(position).unwrap() = 200;
+        let mut decoder =
+            Decoder::<_, owned::UnvalidatedSimpleLogline>::new(corrupted.as_slice()).unwrap();
+        assert!(decoder.next().unwrap().is_ok());
+        assert!(matches!(decoder.next(), Some(Err(CodecError::Invalid(_)))));
+
+        let mut unencodable = owned::UnvalidatedSimpleLogline::try_from(SINGLE_LOG_LINE).unwrap();
+        unencodable.date = "yesterday".to_string();
+        let mut encoder = Encoder::new(Vec::new()).unwrap();
+        assert!(matches!(
+            encoder.encode(&unencodable),
+            Err(CodecError::Invalid("date invalid"))
+        ));
+        assert_eq!(encoder.into_inner().len(), 5);
+    }
+
+    #[test]
+    fn invalid_date_and_time_are_rejected() {
+        let line = owned::ValidatedSimpleLogline::try_from(SINGLE_LOG_LINE).unwrap();
+        let bytes = encode(&[line]);
+        // 2019-12-04 21:02:31 as little-endian year, month, day, hour, minute, second
+        let civil = [0xE3, 0x07, 12, 4, 21, 2, 31];
+        let position = bytes
+            .windows(civil.len())
+            .position(|window| window == civil)
+            .unwrap();
+
+        for (offset, value, error) in [
+            (2, 99, "date invalid"),
+            (3, 32, "date invalid"),
+            (4, 24, "time invalid"),
+            (5, 60, "time invalid"),
+            (6, 60, "time invalid"),
+        ] {
+            let mut corrupted = bytes.clone();
+            *corrupted.get_mut(position + offset).unwrap() = value;
+            let mut decoder =
+                Decoder::<_, owned::ValidatedSimpleLogline>::new(corrupted.as_slice()).unwrap();
+            assert!(matches!(decoder.next(), Some(Err(CodecError::Invalid(e))) if e == error));
+        }
+    }
+
+    fn decode_all<T: codec::Decode>(bytes: &[u8]) -> Vec<T> {
+        match Decoder::<_, T>::new(bytes) {
+            Ok(decoder) => decoder.take(64).filter_map(Result::ok).collect(),
+            Err(_e) => Vec::new(),
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn arbitrary_bytes_do_not_panic(
+            bytes in proptest::collection::vec(proptest::prelude::any::<u8>(), 0..512)
+        ) {
+            // once as is, and once behind a valid header
+            let with_header = [b"CFLB\x01".as_slice(), &bytes].concat();
+            for input in [bytes.as_slice(), &with_header] {
+                // decoded dates and times are as valid as parsed ones
+                for line in decode_all::<owned::ValidatedSimpleLogline>(input) {
+                    proptest::prop_assert!(crate::shared::parse_ymd(line.date.as_bytes()).is_ok());
+                    proptest::prop_assert!(crate::shared::parse_hms(line.time.as_bytes()).is_ok());
+                }
+                decode_all::<OwnedValidatedChronoLogline>(input);
+                decode_all::<OwnedValidatedTimeLogline>(input);
+            }
+        }
+    }
+}
+
+#[test]
+fn lenient_parsing_collects_field_errors() {
+    use crate::types::{Field, FieldError};
+
+    // a clean line gives the same result as the strict parsers
+    let clean = owned::ValidatedSimpleLogline::parse_lenient(SINGLE_LOG_LINE).unwrap();
+    assert!(clean.is_complete());
+    assert_eq!(
+        clean.into_result(),
+        Ok(owned::ValidatedSimpleLogline::try_from(SINGLE_LOG_LINE).unwrap())
+    );
+
+    let corrupted = SINGLE_LOG_LINE
+        .replacen("2019-12-04", "2019-13-04", 1)
+        .replacen("192.0.2.100", "192.0.2.", 1)
+        .replacen("\thttps\t", "\tgopher\t", 1)
+        .replacen("\t11040\t", "\t-1\t", 1);
+    assert!(owned::UnvalidatedSimpleLogline::try_from(corrupted.as_str()).is_err());
+
+    let simple = owned::UnvalidatedSimpleLogline::parse_lenient(&corrupted).unwrap();
+    let fields = |errors: &[FieldError]| errors.iter().map(|e| e.field).collect::<Vec<_>>();
+    assert_eq!(
+        fields(&simple.errors),
+        [Field::CIp, Field::CsProtocol, Field::CPort]
+    );
+    assert_eq!(simple.errors.first().unwrap().value, "192.0.2.");
+    assert_eq!(simple.logline.c_ip, IpAddr::from([0, 0, 0, 0]));
+    assert_eq!(simple.logline.c_port, 0);
+    assert_eq!(simple.logline.sc_bytes, 392);
+    assert_eq!(simple.logline.sc_status, 200);
+
+    // the typed variants report the unparseable date first
+    let chrono = owned::UnvalidatedChronoLogline::parse_lenient(&corrupted).unwrap();
+    assert_eq!(
+        fields(&chrono.errors),
+        [Field::Date, Field::CIp, Field::CsProtocol, Field::CPort]
+    );
+    assert_eq!(chrono.logline.date, chrono::NaiveDate::default());
+    assert_eq!(chrono.logline.time.to_string(), "21:02:31");
+
+    let time = owned::UnvalidatedTimeLogline::parse_lenient(&corrupted).unwrap();
+    assert_eq!(fields(&time.errors), fields(&chrono.errors));
+    assert_eq!(
+        time.logline.datetime.unix_timestamp(),
+        21 * 3600 + 2 * 60 + 31
+    );
+
+    // the borrowed variants report the same errors
+    let simple = borrowed::UnvalidatedSimpleLogline::parse_lenient(&corrupted).unwrap();
+    assert_eq!(
+        fields(&simple.errors),
+        [Field::CIp, Field::CsProtocol, Field::CPort]
+    );
+    assert_eq!(simple.logline.c_ip, IpAddr::from([0, 0, 0, 0]));
+    assert_eq!(simple.logline.date, "2019-13-04");
+
+    let chrono = borrowed::UnvalidatedChronoLogline::parse_lenient(&corrupted).unwrap();
+    assert_eq!(
+        fields(&chrono.errors),
+        [Field::Date, Field::CIp, Field::CsProtocol, Field::CPort]
+    );
+    assert_eq!(chrono.logline.date, chrono::NaiveDate::default());
+    assert_eq!(chrono.logline.x_edge_location, "LAX1");
+
+    let time = borrowed::UnvalidatedTimeLogline::parse_lenient(&corrupted).unwrap();
+    assert_eq!(fields(&time.errors), fields(&chrono.errors));
+    assert_eq!(
+        time.logline.datetime.unix_timestamp(),
+        21 * 3600 + 2 * 60 + 31
+    );
+
+    #[cfg(feature = "jiff")]
+    {
+        let jiff = borrowed::UnvalidatedJiffLogline::parse_lenient(&corrupted).unwrap();
+        assert_eq!(fields(&jiff.errors), fields(&chrono.errors));
+        assert_eq!(jiff.logline.datetime.as_second(), 21 * 3600 + 2 * 60 + 31);
+    }
+
+    // the structure of the line is still checked
+    let truncated = corrupted.rsplit_once('\t').unwrap().0;
+    assert!(owned::ValidatedSimpleLogline::parse_lenient(truncated).is_err());
+    assert!(owned::UnvalidatedChronoLogline::parse_lenient(truncated).is_err());
+    assert!(borrowed::ValidatedSimpleLogline::parse_lenient(truncated).is_err());
+    assert!(borrowed::UnvalidatedTimeLogline::parse_lenient(truncated).is_err());
+}
+
+#[test]
+fn quality_report_counts_outcomes() {
+    use reader::{Issue, QualityReport};
+    use types::Field;
+
+    let unknown = SINGLE_LOG_LINE.replacen("\tHit\t", "\tWarped\t", 1);
+    let broken = SINGLE_LOG_LINE
+        .replacen("\t392\t", "\tmany\t", 1)
+        .replacen("TLSv1.2", "TLSv9", 1);
+    let mut input = Vec::new();
+    for line in [AWS_DOCS_EXAMPLE, "", &unknown, &broken, &broken, "a\tb"] {
+        input.extend_from_slice(line.trim_end().as_bytes());
+        input.extend_from_slice(b"\r\n");
+    }
+    input.extend_from_slice(b"\xff\xfe\n");
+
+    let mut report = QualityReport::with_max_samples(2);
+    report.read_from(input.as_slice()).unwrap();
+
+    assert_eq!(report.lines(), 14);
+    assert_eq!(report.comment, 2);
+    assert_eq!(report.empty, 1);
+    assert_eq!(report.valid, 6 + 1);
+    assert_eq!(report.with_field_errors, 2);
+    assert_eq!(report.wrong_field_count, 1);
+    assert_eq!(report.not_utf8, 1);
+    assert_eq!(report.bad_lines(), 4);
+    assert!(!report.is_clean());
+
+    assert_eq!(
+        report.field_errors.iter().collect::<Vec<_>>(),
+        [(&Field::ScBytes, &2), (&Field::SslProtocol, &2)]
+    );
+    assert_eq!(report.unknown_result_types.get("Warped"), Some(&1));
+    assert!(report.unknown_detailed_result_types.is_empty());
+
+    // only the first two bad lines are kept
+    assert_eq!(report.samples.len(), 2);
+    let sample = report.samples.first().unwrap();
+    assert_eq!(sample.line, 11);
+    assert_eq!(sample.content, broken);
+    assert!(matches!(&sample.issue, Issue::FieldErrors(errors) if errors.len() == 2));
+    assert!(report.to_string().contains("sc-bytes: 2"));
+
+    let clean = QualityReport::from_reader(SINGLE_LOG_LINE.as_bytes()).unwrap();
+    assert!(clean.is_clean());
+    assert_eq!(clean.valid, 1);
+}
+
+#[test]
+fn quality_report_checks_date_and_time() {
+    use reader::{Issue, QualityReport};
+    use types::Field;
+
+    let bad_date = SINGLE_LOG_LINE.replacen("2019-12-04", "2019-13-45", 1);
+    let bad_both = bad_date.replacen("21:02:31", "25:61:00", 1);
+    let mut report = QualityReport::new();
+    report.add_line(&bad_date);
+    report.add_line(&bad_both);
+
+    assert_eq!(report.valid, 0);
+    assert_eq!(report.with_field_errors, 2);
+    assert_eq!(
+        report.field_errors.iter().collect::<Vec<_>>(),
+        [(&Field::Date, &2), (&Field::Time, &1)]
+    );
+    let Issue::FieldErrors(errors) = &report.samples.last().unwrap().issue else {
+        panic!("expected field errors");
+    };
+    assert_eq!(errors.first().unwrap().value, "2019-13-45");
+    assert_eq!(errors.get(1).unwrap().field, Field::Time);
+}
@@ -22,7 +22,7 @@ pub struct LogLine {
     pub cs_user_agent: String,
     pub cs_uri_query: Option<String>, // *2
     pub cs_cookie: Option<String>,
-    pub x_edge_result_type: EdgeResultType,
+    pub x_edge_result_type: EdgeResultType<'static>,
     pub x_edge_request_id: String,
     pub x_host_header: String,
     pub cs_protocol: CsProtocol,
@@ -31,13 +31,13 @@ pub struct LogLine {
     pub x_forwarded_for: Option<ForwardedForAddrs>,
     pub ssl_protocol: Option<SslProtocol>,
     pub ssl_cipher: Option<String>, // *1
-    pub x_edge_response_result_type: EdgeResultType,
+    pub x_edge_response_result_type: EdgeResultType<'static>,
     pub cs_protocol_version: CsProtocolVersion,
     pub fle_status: Option<String>, // *1
     pub fle_encrypted_fields: Option<u64>,
     pub c_port: u16,
     pub time_to_first_byte: Duration,
-    pub x_edge_detailed_result_type: DetailedEdgeResultType,
+    pub x_edge_detailed_result_type: DetailedEdgeResultType<'static>,
     pub sc_content_type: String, // *2
     pub sc_content_len: u64,
     pub sc_range_start: Option<i64>,
@@ -57,115 +57,76 @@ impl<'a> TryFrom<&'a str> for LogLine {
 
         let mut iter = MemchrTabSplitter::new(line);
 
-        let date = Date::parse(iter.next().unwrap(), TIME_DATE_FMT).map_err(|_e| "date invalid")?;
-        let time = Time::parse(iter.next().unwrap(), TIME_TIME_FMT).map_err(|_e| "time invalid")?;
+        let date = Date::parse(iter.field()?, TIME_DATE_FMT).map_err(|_e| "date invalid")?;
+        let time = Time::parse(iter.field()?, TIME_TIME_FMT).map_err(|_e| "time invalid")?;
         let datetime = OffsetDateTime::new_utc(date, time);
 
         let line = Self {
             date,
             time,
             datetime,
-            x_edge_location: iter.next().unwrap().to_string(),
+            x_edge_location: iter.field()?.to_string(),
             sc_bytes: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse::<u64>()
                 .map_err(|_e| "sc_bytes invalid")?,
-            c_ip: iter.next().unwrap().parse().map_err(|_e| "c_ip invalid")?,
-            cs_method: iter.next().unwrap().to_string(),
-            cs_host: iter.next().unwrap().to_string(),
-            cs_uri_stem: iter.next().unwrap().to_string(),
+            c_ip: iter.field()?.parse().map_err(|_e| "c_ip invalid")?,
+            cs_method: iter.field()?.to_string(),
+            cs_host: iter.field()?.to_string(),
+            cs_uri_stem: iter.field()?.to_string(),
             sc_status: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse::<u16>()
                 .map_err(|_e| "sc_status invalid")?,
-            cs_referer: iter.next().unwrap().to_optional_string(),
-            cs_user_agent: iter.next().unwrap().to_string(),
-            cs_uri_query: iter.next().unwrap().to_optional_string(),
-            cs_cookie: iter.next().unwrap().to_optional_string(),
+            cs_referer: iter.field()?.to_optional_string(),
+            cs_user_agent: iter.field()?.to_string(),
+            cs_uri_query: iter.field()?.to_optional_string(),
+            cs_cookie: iter.field()?.to_optional_string(),
             x_edge_result_type: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse()
                 .map_err(|_e| "x_edge_result_type invalid")?,
-            x_edge_request_id: iter.next().unwrap().to_string(),
-            x_host_header: iter.next().unwrap().to_string(),
-            cs_protocol: iter
-                .next()
-                .unwrap()
-                .parse()
-                .map_err(|_e| "cs_protocol invalid")?,
+            x_edge_request_id: iter.field()?.to_string(),
+            x_host_header: iter.field()?.to_string(),
+            cs_protocol: iter.field()?.parse().map_err(|_e| "cs_protocol invalid")?,
             cs_bytes: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse::<u64>()
                 .map_err(|_e| "cs_bytes invalid")?,
-            time_taken: iter
-                .next()
-                .unwrap()
-                .parse::<f64>()
-                .map(Duration::from_secs_f64)
-                .map_err(|_e| "time_taken invalid")?,
-            x_forwarded_for: iter
-                .next()
-                .and_then(as_optional_t)
-                .transpose()
+            time_taken: parse_duration(iter.field()?).map_err(|_e| "time_taken invalid")?,
+            x_forwarded_for: parse_as_option(iter.field()?)
                 .map_err(|_e| "x_forwarded_for invalid")?,
-            ssl_protocol: iter
-                .next()
-                .and_then(as_optional_t)
-                .transpose()
-                .map_err(|_e| "ssl_protocol invalid")?,
-            ssl_cipher: iter.next().unwrap().to_optional_string(),
+            ssl_protocol: parse_as_option(iter.field()?).map_err(|_e| "ssl_protocol invalid")?,
+            ssl_cipher: iter.field()?.to_optional_string(),
             x_edge_response_result_type: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse()
                 .map_err(|_e| "x_edge_response_result_type invalid")?,
             cs_protocol_version: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse()
                 .map_err(|_e| "cs_protocol_version invalid")?,
-            fle_status: iter.next().unwrap().to_optional_string(),
-            fle_encrypted_fields: iter
-                .next()
-                .and_then(as_optional_t)
-                .transpose()
+            fle_status: iter.field()?.to_optional_string(),
+            fle_encrypted_fields: parse_as_option(iter.field()?)
                 .map_err(|_e| "fle_encrypted_fields invalid")?,
             c_port: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse::<u16>()
                 .map_err(|_e| "c_port invalid")?,
-            time_to_first_byte: iter
-                .next()
-                .unwrap()
-                .parse::<f64>()
-                .map(Duration::from_secs_f64)
+            time_to_first_byte: parse_duration(iter.field()?)
                 .map_err(|_e| "time_to_first_byte invalid")?,
             x_edge_detailed_result_type: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse()
                 .map_err(|_e| "x_edge_detailed_result_type invalid")?,
-            sc_content_type: iter.next().unwrap().to_string(),
+            sc_content_type: iter.field()?.to_string(),
             sc_content_len: iter
-                .next()
-                .unwrap()
+                .field()?
                 .parse::<u64>()
                 .map_err(|_e| "sc_content_len invalid")?,
-            sc_range_start: iter
-                .next()
-                .and_then(as_optional_t)
-                .transpose()
+            sc_range_start: parse_as_option(iter.field()?)
                 .map_err(|_e| "sc_range_start invalid")?,
-            sc_range_end: iter
-                .next()
-                .and_then(as_optional_t)
-                .transpose()
-                .map_err(|_e| "sc_range_end invalid")?,
+            sc_range_end: parse_as_option(iter.field()?).map_err(|_e| "sc_range_end invalid")?,
         };
         Ok(line)
     }
@@ -214,11 +175,7 @@ impl TryFrom<CheckedRawLogLine<'_>> for LogLine {
                 .cs_bytes
                 .parse::<u64>()
                 .map_err(|_e| "cs_bytes invalid")?,
-            time_taken: raw
-                .time_taken
-                .parse::<f64>()
-                .map(Duration::from_secs_f64)
-                .map_err(|_e| "time_taken invalid")?,
+            time_taken: parse_duration(raw.time_taken).map_err(|_e| "time_taken invalid")?,
             x_forwarded_for: parse_as_option(raw.x_forwarded_for)
                 .map_err(|_e| "x_forwarded_for invalid")?,
             ssl_protocol: parse_as_option(raw.ssl_protocol).map_err(|_e| "ssl_protocol invalid")?,
@@ -235,10 +192,7 @@ impl TryFrom<CheckedRawLogLine<'_>> for LogLine {
             fle_encrypted_fields: parse_as_option(raw.fle_encrypted_fields)
                 .map_err(|_e| "fle_encrypted_fields invalid")?,
             c_port: raw.c_port.parse::<u16>().map_err(|_e| "c_port invalid")?,
-            time_to_first_byte: raw
-                .time_to_first_byte
-                .parse::<f64>()
-                .map(Duration::from_secs_f64)
+            time_to_first_byte: parse_duration(raw.time_to_first_byte)
                 .map_err(|_e| "time_to_first_byte invalid")?,
             x_edge_detailed_result_type: raw
                 .x_edge_detailed_result_type
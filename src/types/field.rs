@@ -0,0 +1,364 @@
+/// The fields of a CloudFront log line, in the order they appear in the log file
+///
+/// The discriminant of each variant is the (zero-based) index of the field in the tab-separated log line.
+///
+/// Each field knows its W3C header name (as used in the `#Fields:` header line, like `cs(User-Agent)`)
+/// and its Rust name (as used for the struct fields of the logline types, like `cs_user_agent`).
+/// [`Display`](std::fmt::Display) and [`AsRef<str>`] return the header name,
+/// while [`FromStr`](std::str::FromStr) accepts both names.
+///
+/// # Examples
+///
+/// ```rust
+/// use cloudfront_logs::types::Field;
+///
+/// let field: Field = "cs(User-Agent)".parse().unwrap();
+/// assert_eq!(field, Field::CsUserAgent);
+/// assert_eq!(field.header_name(), "cs(User-Agent)");
+/// assert_eq!(field.name(), "cs_user_agent");
+/// assert_eq!(field.index(), 10);
+///
+/// assert_eq!("cs_user_agent".parse::<Field>(), Ok(Field::CsUserAgent));
+/// assert_eq!(Field::from_index(10), Some(Field::CsUserAgent));
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    strum::Display,
+    strum::AsRefStr,
+    strum::IntoStaticStr,
+    strum::EnumString,
+)]
+#[repr(u8)]
+pub enum Field {
+    #[strum(to_string = "date")]
+    Date = 0,
+
+    #[strum(to_string = "time")]
+    Time,
+
+    #[strum(to_string = "x-edge-location", serialize = "x_edge_location")]
+    XEdgeLocation,
+
+    #[strum(to_string = "sc-bytes", serialize = "sc_bytes")]
+    ScBytes,
+
+    #[strum(to_string = "c-ip", serialize = "c_ip")]
+    CIp,
+
+    #[strum(to_string = "cs-method", serialize = "cs_method")]
+    CsMethod,
+
+    #[strum(to_string = "cs(Host)", serialize = "cs_host")]
+    CsHost,
+
+    #[strum(to_string = "cs-uri-stem", serialize = "cs_uri_stem")]
+    CsUriStem,
+
+    #[strum(to_string = "sc-status", serialize = "sc_status")]
+    ScStatus,
+
+    #[strum(to_string = "cs(Referer)", serialize = "cs_referer")]
+    CsReferer,
+
+    #[strum(to_string = "cs(User-Agent)", serialize = "cs_user_agent")]
+    CsUserAgent,
+
+    #[strum(to_string = "cs-uri-query", serialize = "cs_uri_query")]
+    CsUriQuery,
+
+    #[strum(to_string = "cs(Cookie)", serialize = "cs_cookie")]
+    CsCookie,
+
+    #[strum(to_string = "x-edge-result-type", serialize = "x_edge_result_type")]
+    XEdgeResultType,
+
+    #[strum(to_string = "x-edge-request-id", serialize = "x_edge_request_id")]
+    XEdgeRequestId,
+
+    #[strum(to_string = "x-host-header", serialize = "x_host_header")]
+    XHostHeader,
+
+    #[strum(to_string = "cs-protocol", serialize = "cs_protocol")]
+    CsProtocol,
+
+    #[strum(to_string = "cs-bytes", serialize = "cs_bytes")]
+    CsBytes,
+
+    #[strum(to_string = "time-taken", serialize = "time_taken")]
+    TimeTaken,
+
+    #[strum(to_string = "x-forwarded-for", serialize = "x_forwarded_for")]
+    XForwardedFor,
+
+    #[strum(to_string = "ssl-protocol", serialize = "ssl_protocol")]
+    SslProtocol,
+
+    #[strum(to_string = "ssl-cipher", serialize = "ssl_cipher")]
+    SslCipher,
+
+    #[strum(
+        to_string = "x-edge-response-result-type",
+        serialize = "x_edge_response_result_type"
+    )]
+    XEdgeResponseResultType,
+
+    #[strum(to_string = "cs-protocol-version", serialize = "cs_protocol_version")]
+    CsProtocolVersion,
+
+    #[strum(to_string = "fle-status", serialize = "fle_status")]
+    FleStatus,
+
+    #[strum(to_string = "fle-encrypted-fields", serialize = "fle_encrypted_fields")]
+    FleEncryptedFields,
+
+    #[strum(to_string = "c-port", serialize = "c_port")]
+    CPort,
+
+    #[strum(to_string = "time-to-first-byte", serialize = "time_to_first_byte")]
+    TimeToFirstByte,
+
+    #[strum(
+        to_string = "x-edge-detailed-result-type",
+        serialize = "x_edge_detailed_result_type"
+    )]
+    XEdgeDetailedResultType,
+
+    #[strum(to_string = "sc-content-type", serialize = "sc_content_type")]
+    ScContentType,
+
+    #[strum(to_string = "sc-content-len", serialize = "sc_content_len")]
+    ScContentLen,
+
+    #[strum(to_string = "sc-range-start", serialize = "sc_range_start")]
+    ScRangeStart,
+
+    #[strum(to_string = "sc-range-end", serialize = "sc_range_end")]
+    ScRangeEnd,
+}
+
+impl Field {
+    /// All fields in log line order
+    pub const ALL: [Field; crate::consts::FIELDS] = [
+        Field::Date,
+        Field::Time,
+        Field::XEdgeLocation,
+        Field::ScBytes,
+        Field::CIp,
+        Field::CsMethod,
+        Field::CsHost,
+        Field::CsUriStem,
+        Field::ScStatus,
+        Field::CsReferer,
+        Field::CsUserAgent,
+        Field::CsUriQuery,
+        Field::CsCookie,
+        Field::XEdgeResultType,
+        Field::XEdgeRequestId,
+        Field::XHostHeader,
+        Field::CsProtocol,
+        Field::CsBytes,
+        Field::TimeTaken,
+        Field::XForwardedFor,
+        Field::SslProtocol,
+        Field::SslCipher,
+        Field::XEdgeResponseResultType,
+        Field::CsProtocolVersion,
+        Field::FleStatus,
+        Field::FleEncryptedFields,
+        Field::CPort,
+        Field::TimeToFirstByte,
+        Field::XEdgeDetailedResultType,
+        Field::ScContentType,
+        Field::ScContentLen,
+        Field::ScRangeStart,
+        Field::ScRangeEnd,
+    ];
+
+    /// Returns the (zero-based) index of the field in the log line
+    #[inline]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the W3C header name of the field, like `cs(User-Agent)`
+    #[inline]
+    pub fn header_name(self) -> &'static str {
+        self.into()
+    }
+
+    /// Returns the Rust name of the field, like `cs_user_agent`
+    pub const fn name(self) -> &'static str {
+        match self {
+            Field::Date => "date",
+            Field::Time => "time",
+            Field::XEdgeLocation => "x_edge_location",
+            Field::ScBytes => "sc_bytes",
+            Field::CIp => "c_ip",
+            Field::CsMethod => "cs_method",
+            Field::CsHost => "cs_host",
+            Field::CsUriStem => "cs_uri_stem",
+            Field::ScStatus => "sc_status",
+            Field::CsReferer => "cs_referer",
+            Field::CsUserAgent => "cs_user_agent",
+            Field::CsUriQuery => "cs_uri_query",
+            Field::CsCookie => "cs_cookie",
+            Field::XEdgeResultType => "x_edge_result_type",
+            Field::XEdgeRequestId => "x_edge_request_id",
+            Field::XHostHeader => "x_host_header",
+            Field::CsProtocol => "cs_protocol",
+            Field::CsBytes => "cs_bytes",
+            Field::TimeTaken => "time_taken",
+            Field::XForwardedFor => "x_forwarded_for",
+            Field::SslProtocol => "ssl_protocol",
+            Field::SslCipher => "ssl_cipher",
+            Field::XEdgeResponseResultType => "x_edge_response_result_type",
+            Field::CsProtocolVersion => "cs_protocol_version",
+            Field::FleStatus => "fle_status",
+            Field::FleEncryptedFields => "fle_encrypted_fields",
+            Field::CPort => "c_port",
+            Field::TimeToFirstByte => "time_to_first_byte",
+            Field::XEdgeDetailedResultType => "x_edge_detailed_result_type",
+            Field::ScContentType => "sc_content_type",
+            Field::ScContentLen => "sc_content_len",
+            Field::ScRangeStart => "sc_range_start",
+            Field::ScRangeEnd => "sc_range_end",
+        }
+    }
+
+    /// Returns the field for the given Rust name (like `cs_user_agent`), if any
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|field| field.name() == name)
+    }
+
+    /// Returns the field for the given W3C header name (like `cs(User-Agent)`), if any
+    pub fn from_header_name(header_name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|field| field.header_name() == header_name)
+    }
+
+    /// Returns the field at the given (zero-based) index, if any
+    #[inline]
+    pub fn from_index(index: usize) -> Option<Self> {
+        Self::ALL.get(index).copied()
+    }
+}
+
+impl TryFrom<usize> for Field {
+    type Error = &'static str;
+
+    fn try_from(index: usize) -> Result<Self, Self::Error> {
+        Self::from_index(index).ok_or("field index out of bounds")
+    }
+}
+
+/// A set of [`Field`]s, used to select the fields to parse
+///
+/// # Examples
+///
+/// ```rust
+/// use cloudfront_logs::types::{Field, FieldMask};
+///
+/// let mask = FieldMask::from([Field::Date, Field::Time, Field::CsHost]) | Field::ScStatus;
+///
+/// assert!(mask.contains(Field::CsHost));
+/// assert!(!mask.contains(Field::CIp));
+/// assert_eq!(mask.len(), 4);
+/// assert_eq!(mask.last(), Some(Field::ScStatus));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FieldMask(u64);
+
+impl FieldMask {
+    /// The mask without any fields
+    pub const EMPTY: Self = Self(0);
+
+    /// The mask with all fields
+    pub const ALL: Self = Self((1 << crate::consts::FIELDS) - 1);
+
+    /// Returns the mask with the given field added
+    #[inline]
+    pub const fn with(self, field: Field) -> Self {
+        Self(self.0 | 1 << field.index())
+    }
+
+    /// Returns the mask with the given field removed
+    #[inline]
+    pub const fn without(self, field: Field) -> Self {
+        Self(self.0 & !(1 << field.index()))
+    }
+
+    #[inline]
+    pub const fn contains(self, field: Field) -> bool {
+        self.0 & (1 << field.index()) != 0
+    }
+
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the number of fields in the mask
+    #[inline]
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    /// Returns the field with the highest index in the mask
+    #[inline]
+    pub fn last(self) -> Option<Field> {
+        if self.is_empty() {
+            None
+        } else {
+            Field::from_index(63 - self.0.leading_zeros() as usize)
+        }
+    }
+
+    /// Returns the fields of the mask in log line order
+    pub fn iter(self) -> impl Iterator<Item = Field> {
+        Field::ALL
+            .into_iter()
+            .filter(move |field| self.contains(*field))
+    }
+}
+
+impl From<Field> for FieldMask {
+    fn from(field: Field) -> Self {
+        Self::EMPTY.with(field)
+    }
+}
+
+impl<const N: usize> From<[Field; N]> for FieldMask {
+    fn from(fields: [Field; N]) -> Self {
+        fields.into_iter().collect()
+    }
+}
+
+impl FromIterator<Field> for FieldMask {
+    fn from_iter<I: IntoIterator<Item = Field>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::EMPTY, Self::with)
+    }
+}
+
+impl core::ops::BitOr<Field> for FieldMask {
+    type Output = Self;
+
+    fn bitor(self, field: Field) -> Self::Output {
+        self.with(field)
+    }
+}
+
+impl core::ops::BitOr for FieldMask {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self::Output {
+        Self(self.0 | other.0)
+    }
+}
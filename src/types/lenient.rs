@@ -0,0 +1,109 @@
+use super::{Field, String, ToString, Vec};
+use core::fmt;
+
+/// A field which could not be parsed by a lenient parser, together with its raw value
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FieldError {
+    pub field: Field,
+    pub value: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} invalid: {:?}", self.field.name(), self.value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldError {}
+
+/// The result of a lenient parser: the logline and the fields which could not be parsed
+///
+/// Unparseable optional fields are set to `None`.
+/// All other unparseable fields are set to a placeholder instead:
+/// numbers and durations to zero, addresses to `0.0.0.0`, dates and times to the Unix epoch,
+/// `cs_protocol` to [`Http`](super::CsProtocol::Http)
+/// and `cs_protocol_version` to [`HTTP1_1`](super::CsProtocolVersion::HTTP1_1).
+/// Check [`errors`](Self::errors) to tell placeholders from real values.
+///
+/// # Examples
+///
+/// ```rust
+/// use cloudfront_logs::{owned::simple::ValidatedLogline, types::*};
+///
+/// // the c-ip field is corrupted
+/// let line = "2019-12-04	21:02:31	LAX1	392	192.0.2.???	GET	d111111abcdef8.cloudfront.net	/index.html	200	-	Mozilla/5.0%20(Windows%20NT%2010.0;%20Win64;%20x64)%20AppleWebKit/537.36%20(KHTML,%20like%20Gecko)%20Chrome/78.0.3904.108%20Safari/537.36	-	-	Hit	SOX4xwn4XV6Q4rgb7XiVGOHms_BGlTAC4KyHmureZmBNrjGdRLiNIQ==	d111111abcdef8.cloudfront.net	https	23	0.001	-	TLSv1.2	ECDHE-RSA-AES128-GCM-SHA256	Hit	HTTP/2.0	-	-	11040	0.001	Hit	text/html	78	-	-";
+/// assert!(ValidatedLogline::try_from(line).is_err());
+///
+/// let lenient = ValidatedLogline::parse_lenient(line).unwrap();
+/// assert!(!lenient.is_complete());
+/// assert!(lenient.has_error(Field::CIp));
+/// assert_eq!(lenient.errors[0].to_string(), r#"c_ip invalid: "192.0.2.???""#);
+///
+/// // the other fields are still usable
+/// assert_eq!(lenient.logline.sc_bytes, 392);
+/// assert_eq!(lenient.logline.sc_status, 200);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lenient<T> {
+    pub logline: T,
+    pub errors: Vec<FieldError>,
+}
+
+impl<T> Lenient<T> {
+    /// Returns true if all fields were parsed successfully
+    pub fn is_complete(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns true if the given field could not be parsed
+    pub fn has_error(&self, field: Field) -> bool {
+        self.errors.iter().any(|error| error.field == field)
+    }
+
+    /// Returns the logline only if all fields were parsed successfully
+    pub fn into_result(self) -> Result<T, Vec<FieldError>> {
+        if self.errors.is_empty() {
+            Ok(self.logline)
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    /// Applies the function to the logline, keeping the errors
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Lenient<U> {
+        Lenient {
+            logline: f(self.logline),
+            errors: self.errors,
+        }
+    }
+}
+
+// collects the errors while a lenient parser falls back to placeholders
+#[derive(Debug, Default)]
+pub(crate) struct FieldErrors(pub(crate) Vec<FieldError>);
+
+impl FieldErrors {
+    pub(crate) fn parse<T, E>(
+        &mut self,
+        field: Field,
+        value: &str,
+        parse: impl FnOnce(&str) -> Result<T, E>,
+        fallback: T,
+    ) -> T {
+        parse(value).unwrap_or_else(|_e| {
+            self.0.push(FieldError {
+                field,
+                value: value.to_string(),
+            });
+            fallback
+        })
+    }
+
+    pub(crate) fn finish<T>(self, logline: T) -> Lenient<T> {
+        Lenient {
+            logline,
+            errors: self.0,
+        }
+    }
+}
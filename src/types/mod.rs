@@ -1,9 +1,29 @@
-pub(crate) use std::{marker::PhantomData, net::IpAddr, sync::Arc, time::Duration};
-use std::{
+pub(crate) use core::{marker::PhantomData, net::IpAddr, time::Duration};
+use core::{
     net::{Ipv4Addr, SocketAddr},
     str::FromStr,
 };
 
+// the parts of the std prelude which are needed in `no_std` + `alloc` builds
+#[cfg(feature = "alloc")]
+pub(crate) use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+
+mod field;
+#[cfg(feature = "alloc")]
+mod lenient;
+
+pub use field::{Field, FieldMask};
+#[cfg(feature = "alloc")]
+pub(crate) use lenient::FieldErrors;
+#[cfg(feature = "alloc")]
+pub use lenient::{FieldError, Lenient};
+
 /// Marker for which validate the log line before parsing
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Validated;
@@ -13,13 +33,115 @@ pub struct Validated;
 pub struct Unvalidated;
 
 #[cfg(feature = "chrono")]
-pub use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+pub use chrono::{
+    DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc,
+};
 
 #[cfg(feature = "time")]
-pub use time::{Date, OffsetDateTime, Time, UtcOffset};
+pub use time::{Date, OffsetDateTime, Time, UtcDateTime, UtcOffset};
+
+// the result types are parsed from the log line in the hot path;
+// instead of comparing against every known name, dispatch on the length first,
+// which leaves at most four candidates to compare against
+#[cfg(feature = "alloc")]
+macro_rules! impl_result_type {
+    ($ty:ident { $($len:literal => [$($variant:ident),+ $(,)?]),+ $(,)? }) => {
+        // the lengths are part of the dispatch, so they must be correct
+        const _: () = {
+            $($(assert!(stringify!($variant).len() == $len);)+)+
+        };
+
+        impl<'a> $ty<'a> {
+            /// Returns the type as written in the log line
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($(Self::$variant => stringify!($variant),)+)+
+                    Self::Other(other) => other,
+                }
+            }
+
+            /// Borrows the name of an unknown type instead of cloning it
+            pub fn as_borrowed(&self) -> $ty<'_> {
+                match self {
+                    $($(Self::$variant => $ty::$variant,)+)+
+                    Self::Other(other) => $ty::Other(Cow::Borrowed(other)),
+                }
+            }
+
+            /// Converts into a type owning the name of an unknown type
+            pub fn into_owned(self) -> $ty<'static> {
+                match self {
+                    $($(Self::$variant => $ty::$variant,)+)+
+                    Self::Other(other) => $ty::Other(Cow::Owned(other.into_owned())),
+                }
+            }
+
+            fn known(input: &str) -> Option<Self> {
+                let known = match input.len() {
+                    $($len => match input {
+                        $(stringify!($variant) => Self::$variant,)+
+                        _ => return None,
+                    },)+
+                    _ => return None,
+                };
+                Some(known)
+            }
+        }
+
+        impl $ty<'static> {
+            /// Parses the input in place
+            ///
+            /// Unlike [`FromStr`], this reuses the buffer of an unknown type, if the current value is one.
+            #[inline]
+            pub fn parse_into(&mut self, input: &str) {
+                match (Self::known(input), self) {
+                    (Some(known), this) => *this = known,
+                    (None, Self::Other(Cow::Owned(other))) => {
+                        other.clear();
+                        other.push_str(input);
+                    }
+                    (None, this) => *this = Self::Other(Cow::Owned(input.to_string())),
+                }
+            }
+        }
+
+        impl<'a> From<&'a str> for $ty<'a> {
+            #[inline]
+            fn from(input: &'a str) -> Self {
+                Self::known(input).unwrap_or(Self::Other(Cow::Borrowed(input)))
+            }
+        }
+
+        impl FromStr for $ty<'static> {
+            type Err = core::convert::Infallible;
+
+            fn from_str(input: &str) -> Result<Self, Self::Err> {
+                Ok($ty::from(input).into_owned())
+            }
+        }
+
+        impl AsRef<str> for $ty<'_> {
+            fn as_ref(&self) -> &str {
+                self.as_str()
+            }
+        }
+
+        impl core::fmt::Display for $ty<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
 
-#[derive(Debug, Clone, PartialEq, strum::Display, strum::AsRefStr, strum::EnumString)]
-pub enum EdgeResultType {
+/// The `x-edge-result-type` and `x-edge-response-result-type` fields
+///
+/// Unknown types are kept in [`Other`](Self::Other), which borrows from the log line
+/// for the borrowed logline variants, so parsing never allocates.
+/// The owned variants use `EdgeResultType<'static>`, see [`into_owned`](Self::into_owned).
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum EdgeResultType<'a> {
     Hit,
     RefreshHit,
     Miss,
@@ -32,12 +154,27 @@ pub enum EdgeResultType {
     LambdaGeneratedResponse,
 
     // catch-all in case AWS' docs forgot something to mention
-    #[strum(default)]
-    Other(String),
+    Other(Cow<'a, str>),
 }
 
-#[derive(Debug, Clone, PartialEq, strum::Display, strum::AsRefStr, strum::EnumString)]
-pub enum DetailedEdgeResultType {
+#[cfg(feature = "alloc")]
+impl_result_type!(EdgeResultType {
+    3 => [Hit],
+    4 => [Miss],
+    5 => [Error],
+    8 => [Redirect],
+    10 => [RefreshHit],
+    13 => [LimitExceeded],
+    16 => [CapacityExceeded],
+    23 => [LambdaGeneratedResponse],
+});
+
+/// The `x-edge-detailed-result-type` field
+///
+/// Unknown types are kept in [`Other`](Self::Other), see [`EdgeResultType`] for details.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub enum DetailedEdgeResultType<'a> {
     // same as EdgeResultType
     Hit,
     RefreshHit,
@@ -79,10 +216,31 @@ pub enum DetailedEdgeResultType {
     SlowReaderOriginError,
 
     // catch-all in case AWS' docs forgot something to mention
-    #[strum(default)]
-    Other(String),
+    Other(Cow<'a, str>),
 }
 
+#[cfg(feature = "alloc")]
+impl_result_type!(DetailedEdgeResultType {
+    3 => [Hit],
+    4 => [Miss],
+    5 => [Error],
+    8 => [Redirect],
+    10 => [RefreshHit],
+    11 => [OriginError],
+    13 => [LimitExceeded, AbortedOrigin],
+    14 => [InvalidRequest, OriginDnsError],
+    15 => [OriginShieldHit, ClientCommError, OriginCommError, OriginReadError],
+    16 => [CapacityExceeded, ClientGeoBlocked, OriginWriteError],
+    18 => [OriginConnectError],
+    19 => [ClientHungUpRequest],
+    20 => [InvalidRequestHeader, InvalidRequestMethod],
+    21 => [MissGeneratedResponse, InvalidRequestBlocked, SlowReaderOriginError],
+    23 => [LambdaGeneratedResponse, OriginHeaderTooBigError],
+    25 => [InvalidRequestCertificate, OriginZeroSizeObjectError],
+    26 => [OriginInvalidResponseError],
+    29 => [OriginContentRangeLengthError],
+});
+
 #[derive(Debug, Clone, Copy, PartialEq, strum::Display, strum::AsRefStr, strum::EnumString)]
 pub enum CsProtocol {
     #[strum(serialize = "http")]
@@ -161,17 +319,11 @@ impl TryFrom<&str> for Addressable {
         } else {
             // special case: leading zeros (0123.045.067.089)
             if input.starts_with('0') && input.contains('.') {
-                let octets = input
-                    .splitn(4, '.')
-                    .filter_map(|s| s.parse::<u8>().ok())
-                    .collect::<Vec<u8>>();
-                if octets.len() == 4 {
-                    return Ok(Self::IpAddr(IpAddr::V4(Ipv4Addr::new(
-                        octets.first().copied().unwrap_or(0),
-                        octets.get(1).copied().unwrap_or(0),
-                        octets.get(2).copied().unwrap_or(0),
-                        octets.get(3).copied().unwrap_or(0),
-                    ))));
+                let mut octets = input.splitn(4, '.').map(str::parse::<u8>);
+                if let (Some(Ok(a)), Some(Ok(b)), Some(Ok(c)), Some(Ok(d))) =
+                    (octets.next(), octets.next(), octets.next(), octets.next())
+                {
+                    return Ok(Self::IpAddr(IpAddr::V4(Ipv4Addr::new(a, b, c, d))));
                 }
             }
         }
@@ -193,36 +345,46 @@ impl FromStr for Addressable {
 /// A list of [`Addressable`] items used in the `x-forwarded-for` header field
 ///
 /// See [`Addressable`] for more details, especially why we cannot simply use IPv4/IPv6 only.
+#[cfg(feature = "alloc")]
 #[derive(Debug, Clone, PartialEq)]
 pub struct ForwardedForAddrs(pub Vec<Addressable>);
 
+#[cfg(feature = "alloc")]
 impl TryFrom<&str> for ForwardedForAddrs {
     type Error = &'static str;
 
     #[inline]
     fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut addresses = Self(Vec::new());
+        addresses.parse_into(input)?;
+        Ok(addresses)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ForwardedForAddrs {
+    /// Parses the input in place, reusing the capacity of the inner [`Vec`]
+    ///
+    /// On error, the list holds the addresses parsed so far.
+    pub fn parse_into(&mut self, input: &str) -> Result<(), &'static str> {
         const ESCAPED_SPACE: &str = "\\x20";
-        const ESCAPED_SPACE_LEN: usize = ESCAPED_SPACE.len();
-
-        let addresses: Vec<Addressable> = input
-            .split(',')
-            .map(|address| {
-                // Note: CloudFront logs use escaped strings for X-Forwarded-For IP lists
-                let trimmed = address.trim();
-                if trimmed.starts_with(ESCAPED_SPACE) {
-                    &trimmed[ESCAPED_SPACE_LEN..]
-                } else {
-                    trimmed
-                }
-            })
-            // .filter(|address| !address.is_empty())
-            .map(str::parse)
-            .collect::<Result<Vec<Addressable>, _>>()
-            .map_err(|_e| "invalid X-Forwarded-For IP(s)")?;
-        Ok(Self(addresses))
+
+        self.0.clear();
+        for address in input.split(',') {
+            // Note: CloudFront logs use escaped strings for X-Forwarded-For IP lists
+            let trimmed = address.trim();
+            let address = trimmed.strip_prefix(ESCAPED_SPACE).unwrap_or(trimmed);
+            self.0.push(
+                address
+                    .parse()
+                    .map_err(|_e| "invalid X-Forwarded-For IP(s)")?,
+            );
+        }
+        Ok(())
     }
 }
 
+#[cfg(feature = "alloc")]
 impl FromStr for ForwardedForAddrs {
     type Err = &'static str;
 